@@ -33,6 +33,8 @@ export class EntryOptionsDto {
   set runtime(chunkLoading: boolean | string | undefined)
   get chunkLoading(): string | undefined
   set chunkLoading(chunkLoading: string | undefined)
+  get wasmLoading(): string | undefined
+  set wasmLoading(wasmLoading: string | undefined)
   get asyncChunks(): boolean | undefined
   set asyncChunks(asyncChunks: boolean | undefined)
   get baseUri(): string | undefined
@@ -53,6 +55,11 @@ export class JsCompilation {
   getAssetSource(name: string): JsCompatSource | null
   get modules(): Array<JsModule>
   get builtModules(): Array<JsModule>
+  /**
+   * The tap calls of the hooks in the Chrome trace event format, only recorded when `profile` is
+   * enabled.
+   */
+  getHookProfileTrace(): string
   getOptimizationBailout(): Array<JsStatsOptimizationBailout>
   getChunks(): Array<JsChunk>
   getNamedChunkKeys(): Array<string>
@@ -163,6 +170,13 @@ export class JsEntries {
   values(): Array<EntryDataDto>
 }
 
+export class JsHash {
+  constructor(hashFunction: string)
+  update(data: string | Buffer): void
+  /** Digest of the hash in hex. */
+  digest(): string
+}
+
 export class JsModule {
   get context(): string | undefined
   get originalSource(): JsCompatSource | undefined
@@ -198,6 +212,8 @@ export class JsStats {
   hasWarnings(): boolean
   hasErrors(): boolean
   getLogging(acceptedTypes: number): Array<JsStatsLogging>
+  getSnapshot(): string
+  diff(baseline: string): string
 }
 
 export class Rspack {
@@ -271,6 +287,7 @@ export enum BuiltinPluginName {
   ProvideSharedPlugin = 'ProvideSharedPlugin',
   ConsumeSharedPlugin = 'ConsumeSharedPlugin',
   ModuleFederationRuntimePlugin = 'ModuleFederationRuntimePlugin',
+  ModuleFederationManifestPlugin = 'ModuleFederationManifestPlugin',
   NamedModuleIdsPlugin = 'NamedModuleIdsPlugin',
   NaturalModuleIdsPlugin = 'NaturalModuleIdsPlugin',
   DeterministicModuleIdsPlugin = 'DeterministicModuleIdsPlugin',
@@ -288,6 +305,7 @@ export enum BuiltinPluginName {
   InferAsyncModulesPlugin = 'InferAsyncModulesPlugin',
   JavascriptModulesPlugin = 'JavascriptModulesPlugin',
   AsyncWebAssemblyModulesPlugin = 'AsyncWebAssemblyModulesPlugin',
+  WebAssemblyModulesPlugin = 'WebAssemblyModulesPlugin',
   AssetModulesPlugin = 'AssetModulesPlugin',
   SourceMapDevToolPlugin = 'SourceMapDevToolPlugin',
   EvalSourceMapDevToolPlugin = 'EvalSourceMapDevToolPlugin',
@@ -303,6 +321,8 @@ export enum BuiltinPluginName {
   SizeLimitsPlugin = 'SizeLimitsPlugin',
   NoEmitOnErrorsPlugin = 'NoEmitOnErrorsPlugin',
   ContextReplacementPlugin = 'ContextReplacementPlugin',
+  CustomChunkLoadingPlugin = 'CustomChunkLoadingPlugin',
+  CustomWasmLoadingPlugin = 'CustomWasmLoadingPlugin',
   HttpExternalsRspackPlugin = 'HttpExternalsRspackPlugin',
   CopyRspackPlugin = 'CopyRspackPlugin',
   HtmlRspackPlugin = 'HtmlRspackPlugin',
@@ -310,6 +330,8 @@ export enum BuiltinPluginName {
   LightningCssMinimizerRspackPlugin = 'LightningCssMinimizerRspackPlugin',
   BundlerInfoRspackPlugin = 'BundlerInfoRspackPlugin',
   CssExtractRspackPlugin = 'CssExtractRspackPlugin',
+  SubresourceIntegrityRspackPlugin = 'SubresourceIntegrityRspackPlugin',
+  CompressionRspackPlugin = 'CompressionRspackPlugin',
   JsLoaderRspackPlugin = 'JsLoaderRspackPlugin',
   LazyCompilationPlugin = 'LazyCompilationPlugin'
 }
@@ -424,10 +446,17 @@ export interface JsAssetInfo {
   extras: Record<string, any>
   /** whether this asset is over the size limit */
   isOverSizeLimit?: boolean
+  /** the gzip compressed size of this asset, computed when the size limits are checked with gzip */
+  gzipSize?: number
+  /** the brotli compressed size of this asset, computed when the size limits are checked with brotli */
+  brotliSize?: number
 }
 
 export interface JsAssetInfoRelated {
   sourceMap?: string
+  gzipped?: string
+  brotliCompressed?: string
+  zstdCompressed?: string
 }
 
 export interface JsBeforeAssetTagGenerationData {
@@ -549,6 +578,7 @@ export interface JsEntryOptions {
   name?: string
   runtime?: false | string
   chunkLoading?: string
+  wasmLoading?: string
   asyncChunks?: boolean
   publicPath?: "auto" | JsFilename
   baseUri?: string
@@ -746,7 +776,8 @@ export interface JsRspackError {
 
 export enum JsRspackSeverity {
   Error = 'Error',
-  Warn = 'Warn'
+  Warn = 'Warn',
+  Advice = 'Advice'
 }
 
 export interface JsRuntimeGlobals {
@@ -786,6 +817,8 @@ export interface JsStatsAsset {
   auxiliaryChunkNames: Array<string>
   auxiliaryChunkIdHints: Array<string>
   auxiliaryChunks: Array<string | undefined | null>
+  gzipSize?: number
+  brotliSize?: number
 }
 
 export interface JsStatsAssetInfo {
@@ -864,11 +897,18 @@ export interface JsStatsChunkGroupChildren {
 }
 
 export interface JsStatsCompilation {
+  advices: Array<JsStatsWarning>
   assets?: Array<JsStatsAsset>
   assetsByChunkName?: Array<JsStatsAssetsByChunkName>
   chunks?: Array<JsStatsChunk>
   entrypoints?: Array<JsStatsChunkGroup>
   errors: Array<JsStatsError>
+  /** The assets hidden by the native `excludeAssets` filters. */
+  excludedAssets?: JsStatsExcluded
+  /** The modules hidden by the native `excludeModules` filters. */
+  excludedModules?: JsStatsExcluded
+  /** The number of modules left out by `modulesSpace`. */
+  filteredModules?: number
   hash?: string
   modules?: Array<JsStatsModule>
   namedChunkGroups?: Array<JsStatsChunkGroup>
@@ -889,9 +929,16 @@ export interface JsStatsError {
   moduleTrace: Array<JsStatsModuleTrace>
 }
 
+export interface JsStatsExcluded {
+  count: number
+  size: number
+  sizes: Array<JsStatsSize>
+}
+
 export interface JsStatsGetAssets {
   assets: Array<JsStatsAsset>
   assetsByChunkName: Array<JsStatsAssetsByChunkName>
+  excluded?: JsStatsExcluded
 }
 
 export interface JsStatsLogging {
@@ -976,28 +1023,39 @@ export interface JsStatsOptimizationBailout {
 }
 
 export interface JsStatsOptions {
-  assets: boolean
-  cachedModules: boolean
-  chunks: boolean
-  chunkGroupAuxiliary: boolean
-  chunkGroupChildren: boolean
-  chunkGroups: boolean
-  chunkModules: boolean
-  chunkRelations: boolean
-  depth: boolean
-  entrypoints: boolean | string
-  errors: boolean
-  hash: boolean
-  ids: boolean
-  modules: boolean
-  moduleAssets: boolean
-  nestedModules: boolean
-  optimizationBailout: boolean
-  providedExports: boolean
-  reasons: boolean
-  source: boolean
-  usedExports: boolean
-  warnings: boolean
+  /** The preset the options are resolved from, the options which are not given fall back to it. */
+  preset?: boolean | string
+  forToString?: boolean
+  assets?: boolean
+  cachedModules?: boolean
+  chunks?: boolean
+  chunkGroupAuxiliary?: boolean
+  chunkGroupChildren?: boolean
+  chunkGroups?: boolean
+  chunkModules?: boolean
+  chunkRelations?: boolean
+  depth?: boolean
+  entrypoints?: boolean | string
+  errors?: boolean
+  hash?: boolean
+  ids?: boolean
+  modules?: boolean
+  moduleAssets?: boolean
+  nestedModules?: boolean
+  optimizationBailout?: boolean
+  providedExports?: boolean
+  reasons?: boolean
+  source?: boolean
+  usedExports?: boolean
+  warnings?: boolean
+  /**
+   * The filters of `excludeModules` which can be applied natively, the others are applied by
+   * the stats factory.
+   */
+  excludeModules?: (boolean | string | RegExp)[]
+  excludeAssets?: (boolean | string | RegExp)[]
+  /** Only given when the modules are not grouped by the stats factory. */
+  modulesSpace?: number
 }
 
 export interface JsStatsSize {
@@ -1021,6 +1079,7 @@ export interface JsStatsWarning {
 export interface JsTap {
   function: (...args: any[]) => any
   stage: number
+  name: string
 }
 
 export interface NodeFS {
@@ -1155,6 +1214,15 @@ export interface RawChunkOptionNameCtx {
   cacheGroupKey: string
 }
 
+export interface RawCompressionRspackPluginOptions {
+  test?: string | RegExp | (string | RegExp)[]
+  include?: string | RegExp | (string | RegExp)[]
+  exclude?: string | RegExp | (string | RegExp)[]
+  algorithms: Array<"gzip" | "brotliCompress" | "zstdCompress">
+  threshold: number
+  minRatio: number
+}
+
 export interface RawConsumeOptions {
   key: string
   import?: string
@@ -1269,6 +1337,17 @@ export interface RawCssParserOptions {
   namedExports?: boolean
 }
 
+export interface RawCustomChunkLoadingPluginOptions {
+  type: string
+  loadChunk: string
+}
+
+export interface RawCustomWasmLoadingPluginOptions {
+  type: string
+  generateLoadBinaryCode: string
+  supportsStreaming?: boolean
+}
+
 export interface RawDraft {
   customMedia: boolean
 }
@@ -1525,6 +1604,12 @@ export interface RawModuleArg {
   path: string
 }
 
+export interface RawModuleFederationManifestPluginOptions {
+  name: string
+  filePath?: string
+  fileName?: string
+}
+
 export interface RawModuleFilenameTemplateFnCtx {
   identifier: string
   shortIdentifier: string
@@ -1640,6 +1725,8 @@ export interface RawOptions {
   node?: RawNodeOption
   profile: boolean
   bail: boolean
+  recordsInputPath?: string
+  recordsOutputPath?: string
   __references: Record<string, any>
 }
 
@@ -1826,23 +1913,43 @@ export interface RawRuntimeChunkOptions {
   name: string | ((entrypoint: { name: string }) => string)
 }
 
+export interface RawSizeLimitsBudget {
+  asset?: string
+  entrypoint?: string
+  maxSize: number
+}
+
 export interface RawSizeLimitsPluginOptions {
   assetFilter?: (assetFilename: string) => boolean
   hints?: "error" | "warning"
   maxAssetSize?: number
   maxEntrypointSize?: number
+  baseline?: string
+  compression?: "gzip" | "brotli"
+  budgets?: Array<RawSizeLimitsBudget>
 }
 
 export interface RawSnapshotOptions {
+  managedPaths?: (string | RegExp)[]
+  immutablePaths?: (string | RegExp)[]
+  buildDependencies?: RawSnapshotStrategy
+  module?: RawSnapshotStrategy
+  resolve?: RawSnapshotStrategy
+}
 
+export interface RawSnapshotStrategy {
+  hash?: boolean
+  timestamp?: boolean
 }
 
 export interface RawSourceMapDevToolPluginOptions {
   append?: (false | null) | string | Function
   columns?: boolean
+  debugIds?: boolean
   fallbackModuleFilenameTemplate?: string | ((info: RawModuleFilenameTemplateFnCtx) => string)
   fileContext?: string
   filename?: (false | null) | string
+  ignoreList?: (text: string) => boolean
   module?: boolean
   moduleFilenameTemplate?: string | ((info: RawModuleFilenameTemplateFnCtx) => string)
   namespace?: string
@@ -1881,6 +1988,10 @@ export interface RawStatsOptions {
   colors: boolean
 }
 
+export interface RawSubresourceIntegrityRspackPluginOptions {
+  hashFuncNames: Array<"sha256" | "sha384" | "sha512">
+}
+
 export interface RawSwcJsMinimizerOptions {
   compress: any
   mangle: any
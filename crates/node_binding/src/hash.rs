@@ -0,0 +1,46 @@
+use std::hash::Hasher;
+
+use napi::bindgen_prelude::{Buffer, Either};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+
+/// Hash of `rspack_hash` for the JS side, for the hash functions Node.js doesn't support,
+/// e.g. `xxhash3-128`.
+#[napi]
+pub struct JsHash {
+  hash: Option<RspackHash>,
+}
+
+#[napi]
+impl JsHash {
+  #[napi(constructor)]
+  pub fn new(hash_function: String) -> napi::Result<Self> {
+    let hash_function = HashFunction::try_from(hash_function.as_str())
+      .map_err(|e| napi::Error::from_reason(format!("{e}")))?;
+    Ok(Self {
+      hash: Some(RspackHash::new(&hash_function)),
+    })
+  }
+
+  #[napi]
+  pub fn update(&mut self, data: Either<String, Buffer>) -> napi::Result<()> {
+    let hash = self
+      .hash
+      .as_mut()
+      .ok_or_else(|| napi::Error::from_reason("Hash has already been digested"))?;
+    match &data {
+      Either::A(data) => hash.write(data.as_bytes()),
+      Either::B(data) => hash.write(data),
+    }
+    Ok(())
+  }
+
+  /// Digest of the hash in hex.
+  #[napi]
+  pub fn digest(&mut self) -> napi::Result<String> {
+    let hash = self
+      .hash
+      .take()
+      .ok_or_else(|| napi::Error::from_reason("Hash has already been digested"))?;
+    Ok(hash.digest(&HashDigest::Hex).encoded().to_string())
+  }
+}
@@ -17,11 +17,13 @@ use rspack_fs_node::{AsyncNodeWritableFileSystem, ThreadsafeNodeFS};
 
 mod compiler;
 mod diagnostic;
+mod hash;
 mod panic;
 mod plugins;
 mod resolver_factory;
 
 pub use diagnostic::*;
+pub use hash::*;
 use plugins::*;
 use resolver_factory::*;
 use rspack_binding_options::*;
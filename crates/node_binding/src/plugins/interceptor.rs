@@ -73,11 +73,15 @@ use rspack_plugin_javascript::{JavascriptModulesChunkHash, JavascriptModulesChun
 pub struct JsTap {
   pub function: JsFunction,
   pub stage: i32,
+  /// The names of the plugins of the JavaScript taps called by this tap, there is one tap for
+  /// every plugin when `profile` is enabled
+  pub name: String,
 }
 
 pub struct ThreadsafeJsTap<T: 'static, R> {
   pub function: ThreadsafeFunction<T, R>,
   pub stage: i32,
+  pub name: String,
 }
 
 impl<T: 'static, R> Clone for ThreadsafeJsTap<T, R> {
@@ -85,6 +89,7 @@ impl<T: 'static, R> Clone for ThreadsafeJsTap<T, R> {
     Self {
       function: self.function.clone(),
       stage: self.stage,
+      name: self.name.clone(),
     }
   }
 }
@@ -96,6 +101,7 @@ impl<T: 'static + ToNapiValue, R> ThreadsafeJsTap<T, R> {
     Ok(Self {
       function,
       stage: js_tap.stage,
+      name: js_tap.name,
     })
   }
 }
@@ -243,6 +249,7 @@ macro_rules! define_register {
     struct $tap_name {
       function: ThreadsafeFunction<$arg, $ret>,
       stage: i32,
+      name: String,
     }
 
     impl $tap_name {
@@ -250,6 +257,7 @@ macro_rules! define_register {
         Self {
           function: tap.function,
           stage: tap.stage,
+          name: tap.name,
         }
       }
     }
@@ -910,6 +918,10 @@ impl CompilerThisCompilation for CompilerThisCompilationTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -926,6 +938,10 @@ impl CompilerCompilation for CompilerCompilationTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -938,6 +954,10 @@ impl CompilerMake for CompilerMakeTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -950,6 +970,10 @@ impl CompilerFinishMake for CompilerFinishMakeTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -962,6 +986,10 @@ impl CompilerShouldEmit for CompilerShouldEmitTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -973,6 +1001,10 @@ impl CompilerEmit for CompilerEmitTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -984,6 +1016,10 @@ impl CompilerAfterEmit for CompilerAfterEmitTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1007,6 +1043,10 @@ impl CompilerAssetEmitted for CompilerAssetEmittedTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1021,6 +1061,10 @@ impl CompilationBuildModule for CompilationBuildModuleTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1035,6 +1079,10 @@ impl CompilationStillValidModule for CompilationStillValidModuleTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1049,6 +1097,10 @@ impl CompilationSucceedModule for CompilationSucceedModuleTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1071,6 +1123,10 @@ impl CompilationExecuteModule for CompilationExecuteModuleTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1083,6 +1139,10 @@ impl CompilationFinishModules for CompilationFinishModulesTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1094,6 +1154,10 @@ impl CompilationOptimizeModules for CompilationOptimizeModulesTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1105,6 +1169,10 @@ impl CompilationAfterOptimizeModules for CompilationAfterOptimizeModulesTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1116,6 +1184,10 @@ impl CompilationOptimizeTree for CompilationOptimizeTreeTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1127,6 +1199,10 @@ impl CompilationOptimizeChunkModules for CompilationOptimizeChunkModulesTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1154,6 +1230,10 @@ impl CompilationAdditionalTreeRuntimeRequirements
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1185,6 +1265,10 @@ impl CompilationRuntimeRequirementInTree for CompilationRuntimeRequirementInTree
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1232,6 +1316,10 @@ impl CompilationRuntimeModule for CompilationRuntimeModuleTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1251,6 +1339,10 @@ impl CompilationChunkHash for CompilationChunkHashTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1268,6 +1360,10 @@ impl CompilationChunkAsset for CompilationChunkAssetTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1280,6 +1376,10 @@ impl CompilationProcessAssets for CompilationProcessAssetsTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1292,6 +1392,10 @@ impl CompilationAfterProcessAssets for CompilationAfterProcessAssetsTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1303,6 +1407,10 @@ impl CompilationSeal for CompilationSealTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1314,6 +1422,10 @@ impl CompilationAfterSeal for CompilationAfterSealTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1347,6 +1459,10 @@ impl NormalModuleFactoryBeforeResolve for NormalModuleFactoryBeforeResolveTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1384,6 +1500,10 @@ impl NormalModuleFactoryFactorize for NormalModuleFactoryFactorizeTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1421,6 +1541,10 @@ impl NormalModuleFactoryResolve for NormalModuleFactoryResolveTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1448,6 +1572,10 @@ impl NormalModuleFactoryResolveForScheme for NormalModuleFactoryResolveForScheme
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1523,6 +1651,10 @@ impl NormalModuleFactoryAfterResolve for NormalModuleFactoryAfterResolveTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1548,6 +1680,10 @@ impl NormalModuleFactoryCreateModule for NormalModuleFactoryCreateModuleTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1571,6 +1707,10 @@ impl ContextModuleFactoryBeforeResolve for ContextModuleFactoryBeforeResolveTap
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1591,6 +1731,10 @@ impl ContextModuleFactoryAfterResolve for ContextModuleFactoryAfterResolveTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1610,6 +1754,10 @@ impl JavascriptModulesChunkHash for JavascriptModulesChunkHashTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1628,6 +1776,10 @@ impl HtmlPluginBeforeAssetTagGeneration for HtmlPluginBeforeAssetTagGenerationTa
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1643,6 +1795,10 @@ impl HtmlPluginAlterAssetTags for HtmlPluginAlterAssetTagsTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1661,6 +1817,10 @@ impl HtmlPluginAlterAssetTagGroups for HtmlPluginAlterAssetTagGroupsTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1679,6 +1839,10 @@ impl HtmlPluginAfterTemplateExecution for HtmlPluginAfterTemplateExecutionTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1694,6 +1858,10 @@ impl HtmlPluginBeforeEmit for HtmlPluginBeforeEmitTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
 
 #[async_trait]
@@ -1709,4 +1877,8 @@ impl HtmlPluginAfterEmit for HtmlPluginAfterEmitTap {
   fn stage(&self) -> i32 {
     self.stage
   }
+
+  fn plugin_name(&self) -> &str {
+    &self.name
+  }
 }
@@ -55,6 +55,8 @@ pub struct RawOptions {
   pub node: Option<RawNodeOption>,
   pub profile: bool,
   pub bail: bool,
+  pub records_input_path: Option<String>,
+  pub records_output_path: Option<String>,
   #[napi(js_name = "__references", ts_type = "Record<string, any>")]
   pub __references: References,
 }
@@ -129,6 +131,8 @@ impl TryFrom<RawOptions> for CompilerOptions {
       node,
       profile: value.profile,
       bail: value.bail,
+      records_input_path: value.records_input_path.map(Into::into),
+      records_output_path: value.records_output_path.map(Into::into),
       __references: value.__references,
     })
   }
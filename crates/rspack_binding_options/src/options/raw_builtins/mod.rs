@@ -1,7 +1,10 @@
 mod raw_banner;
 mod raw_bundle_info;
+mod raw_compression;
 mod raw_copy;
 mod raw_css_extract;
+mod raw_custom_chunk_loading;
+mod raw_custom_wasm_loading;
 mod raw_html;
 mod raw_ignore;
 mod raw_lazy_compilation;
@@ -11,6 +14,7 @@ mod raw_mf;
 mod raw_progress;
 mod raw_runtime_chunk;
 mod raw_size_limits;
+mod raw_sri;
 mod raw_swc_js_minimizer;
 
 use napi::{bindgen_prelude::FromNapiValue, Env, JsUnknown};
@@ -26,6 +30,7 @@ use rspack_ids::{
 use rspack_napi::NapiResultExt;
 use rspack_plugin_asset::AssetPlugin;
 use rspack_plugin_banner::BannerPlugin;
+use rspack_plugin_compression::CompressionRspackPlugin;
 use rspack_plugin_context_replacement::ContextReplacementPlugin;
 use rspack_plugin_copy::{CopyRspackPlugin, CopyRspackPluginOptions};
 use rspack_plugin_css::CssPlugin;
@@ -54,8 +59,8 @@ use rspack_plugin_lightning_css_minimizer::LightningCssMinimizerRspackPlugin;
 use rspack_plugin_limit_chunk_count::LimitChunkCountPlugin;
 use rspack_plugin_merge_duplicate_chunks::MergeDuplicateChunksPlugin;
 use rspack_plugin_mf::{
-  ConsumeSharedPlugin, ContainerPlugin, ContainerReferencePlugin, ModuleFederationRuntimePlugin,
-  ProvideSharedPlugin, ShareRuntimePlugin,
+  ConsumeSharedPlugin, ContainerPlugin, ContainerReferencePlugin, ModuleFederationManifestPlugin,
+  ModuleFederationRuntimePlugin, ProvideSharedPlugin, ShareRuntimePlugin,
 };
 use rspack_plugin_no_emit_on_errors::NoEmitOnErrorsPlugin;
 use rspack_plugin_progress::ProgressPlugin;
@@ -64,15 +69,18 @@ use rspack_plugin_remove_duplicate_modules::RemoveDuplicateModulesPlugin;
 use rspack_plugin_remove_empty_chunks::RemoveEmptyChunksPlugin;
 use rspack_plugin_runtime::{
   enable_chunk_loading_plugin, ArrayPushCallbackChunkFormatPlugin, BundlerInfoPlugin,
-  ChunkPrefetchPreloadPlugin, CommonJsChunkFormatPlugin, ModuleChunkFormatPlugin, RuntimePlugin,
+  ChunkPrefetchPreloadPlugin, CommonJsChunkFormatPlugin, CustomChunkLoadingPlugin,
+  ModuleChunkFormatPlugin, RuntimePlugin,
 };
 use rspack_plugin_runtime_chunk::RuntimeChunkPlugin;
 use rspack_plugin_schemes::{DataUriPlugin, FileUriPlugin};
 use rspack_plugin_size_limits::SizeLimitsPlugin;
+use rspack_plugin_sri::SubresourceIntegrityRspackPlugin;
 use rspack_plugin_swc_js_minimizer::SwcJsMinimizerRspackPlugin;
 use rspack_plugin_warn_sensitive_module::WarnCaseSensitiveModulesPlugin;
 use rspack_plugin_wasm::{
-  enable_wasm_loading_plugin, AsyncWasmPlugin, FetchCompileAsyncWasmPlugin,
+  enable_wasm_loading_plugin, AsyncWasmPlugin, CustomWasmLoadingPlugin,
+  FetchCompileAsyncWasmPlugin, SyncWasmPlugin,
 };
 use rspack_plugin_web_worker_template::web_worker_template_plugin;
 use rspack_plugin_worker::WorkerPlugin;
@@ -86,11 +94,18 @@ pub use self::{
 };
 use self::{
   raw_bundle_info::{RawBundlerInfoModeWrapper, RawBundlerInfoPluginOptions},
+  raw_compression::RawCompressionRspackPluginOptions,
   raw_css_extract::RawCssExtractPluginOption,
+  raw_custom_chunk_loading::RawCustomChunkLoadingPluginOptions,
+  raw_custom_wasm_loading::RawCustomWasmLoadingPluginOptions,
   raw_lazy_compilation::{JsBackend, RawLazyCompilationOption},
-  raw_mf::{RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions, RawProvideOptions},
+  raw_mf::{
+    RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions,
+    RawModuleFederationManifestPluginOptions, RawProvideOptions,
+  },
   raw_runtime_chunk::RawRuntimeChunkOptions,
   raw_size_limits::RawSizeLimitsPluginOptions,
+  raw_sri::RawSubresourceIntegrityRspackPluginOptions,
 };
 use crate::{
   plugins::JsLoaderRspackPlugin, JsLoaderRunner, RawContextReplacementPluginOptions,
@@ -134,6 +149,7 @@ pub enum BuiltinPluginName {
   ProvideSharedPlugin,
   ConsumeSharedPlugin,
   ModuleFederationRuntimePlugin,
+  ModuleFederationManifestPlugin,
   NamedModuleIdsPlugin,
   NaturalModuleIdsPlugin,
   DeterministicModuleIdsPlugin,
@@ -151,6 +167,7 @@ pub enum BuiltinPluginName {
   InferAsyncModulesPlugin,
   JavascriptModulesPlugin,
   AsyncWebAssemblyModulesPlugin,
+  WebAssemblyModulesPlugin,
   AssetModulesPlugin,
   SourceMapDevToolPlugin,
   EvalSourceMapDevToolPlugin,
@@ -166,6 +183,8 @@ pub enum BuiltinPluginName {
   SizeLimitsPlugin,
   NoEmitOnErrorsPlugin,
   ContextReplacementPlugin,
+  CustomChunkLoadingPlugin,
+  CustomWasmLoadingPlugin,
 
   // rspack specific plugins
   // naming format follow XxxRspackPlugin
@@ -176,6 +195,8 @@ pub enum BuiltinPluginName {
   LightningCssMinimizerRspackPlugin,
   BundlerInfoRspackPlugin,
   CssExtractRspackPlugin,
+  SubresourceIntegrityRspackPlugin,
+  CompressionRspackPlugin,
 
   // rspack js adapter plugins
   // naming format follow XxxRspackPlugin
@@ -259,9 +280,7 @@ impl BuiltinPlugin {
       }
       BuiltinPluginName::EnableWasmLoadingPlugin => {
         let wasm_loading_type = downcast_into::<String>(self.options)?;
-        plugins.push(enable_wasm_loading_plugin(
-          wasm_loading_type.as_str().into(),
-        ));
+        enable_wasm_loading_plugin(wasm_loading_type.as_str().into(), plugins);
       }
       BuiltinPluginName::FetchCompileAsyncWasmPlugin => {
         plugins.push(FetchCompileAsyncWasmPlugin::default().boxed())
@@ -339,6 +358,12 @@ impl BuiltinPlugin {
       BuiltinPluginName::ModuleFederationRuntimePlugin => {
         plugins.push(ModuleFederationRuntimePlugin::default().boxed())
       }
+      BuiltinPluginName::ModuleFederationManifestPlugin => plugins.push(
+        ModuleFederationManifestPlugin::new(
+          downcast_into::<RawModuleFederationManifestPluginOptions>(self.options)?.into(),
+        )
+        .boxed(),
+      ),
       BuiltinPluginName::NamedModuleIdsPlugin => {
         plugins.push(NamedModuleIdsPlugin::default().boxed())
       }
@@ -380,6 +405,9 @@ impl BuiltinPlugin {
       BuiltinPluginName::AsyncWebAssemblyModulesPlugin => {
         plugins.push(AsyncWasmPlugin::default().boxed())
       }
+      BuiltinPluginName::WebAssemblyModulesPlugin => {
+        plugins.push(SyncWasmPlugin::default().boxed())
+      }
       BuiltinPluginName::AssetModulesPlugin => plugins.push(AssetPlugin::default().boxed()),
       BuiltinPluginName::SourceMapDevToolPlugin => {
         let options: SourceMapDevToolPluginOptions =
@@ -435,9 +463,10 @@ impl BuiltinPlugin {
           .boxed(),
       ),
       BuiltinPluginName::SizeLimitsPlugin => {
-        let plugin =
-          SizeLimitsPlugin::new(downcast_into::<RawSizeLimitsPluginOptions>(self.options)?.into())
-            .boxed();
+        let plugin = SizeLimitsPlugin::new(
+          downcast_into::<RawSizeLimitsPluginOptions>(self.options)?.try_into()?,
+        )
+        .boxed();
         plugins.push(plugin)
       }
 
@@ -492,6 +521,20 @@ impl BuiltinPlugin {
         .boxed();
         plugins.push(plugin);
       }
+      BuiltinPluginName::SubresourceIntegrityRspackPlugin => {
+        let plugin = SubresourceIntegrityRspackPlugin::new(
+          downcast_into::<RawSubresourceIntegrityRspackPluginOptions>(self.options)?.try_into()?,
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
+      BuiltinPluginName::CompressionRspackPlugin => {
+        let plugin = CompressionRspackPlugin::new(
+          downcast_into::<RawCompressionRspackPluginOptions>(self.options)?.try_into()?,
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
       BuiltinPluginName::JsLoaderRspackPlugin => {
         plugins
           .push(JsLoaderRspackPlugin::new(downcast_into::<JsLoaderRunner>(self.options)?).boxed());
@@ -517,6 +560,16 @@ impl BuiltinPlugin {
         let options = raw_options.try_into()?;
         plugins.push(ContextReplacementPlugin::new(options).boxed());
       }
+      BuiltinPluginName::CustomChunkLoadingPlugin => {
+        let plugin: CustomChunkLoadingPlugin =
+          downcast_into::<RawCustomChunkLoadingPluginOptions>(self.options)?.into();
+        plugins.push(plugin.boxed());
+      }
+      BuiltinPluginName::CustomWasmLoadingPlugin => {
+        let plugin: CustomWasmLoadingPlugin =
+          downcast_into::<RawCustomWasmLoadingPluginOptions>(self.options)?.into();
+        plugins.push(plugin.boxed());
+      }
     }
     Ok(())
   }
@@ -0,0 +1,38 @@
+use napi_derive::napi;
+use rspack_binding_values::{into_asset_conditions, RawAssetConditions};
+use rspack_error::Result;
+use rspack_plugin_compression::CompressionRspackPluginOptions;
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawCompressionRspackPluginOptions {
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub test: Option<RawAssetConditions>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub include: Option<RawAssetConditions>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub exclude: Option<RawAssetConditions>,
+  #[napi(ts_type = "Array<\"gzip\" | \"brotliCompress\" | \"zstdCompress\">")]
+  pub algorithms: Vec<String>,
+  pub threshold: f64,
+  pub min_ratio: f64,
+}
+
+impl TryFrom<RawCompressionRspackPluginOptions> for CompressionRspackPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawCompressionRspackPluginOptions) -> Result<Self> {
+    Ok(Self {
+      test: value.test.map(into_asset_conditions),
+      include: value.include.map(into_asset_conditions),
+      exclude: value.exclude.map(into_asset_conditions),
+      algorithms: value
+        .algorithms
+        .iter()
+        .map(|algorithm| algorithm.parse())
+        .collect::<Result<_>>()?,
+      threshold: value.threshold,
+      min_ratio: value.min_ratio,
+    })
+  }
+}
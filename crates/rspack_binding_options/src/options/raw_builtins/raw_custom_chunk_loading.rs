@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use napi_derive::napi;
+use rspack_core::RuntimeModuleExt;
+use rspack_plugin_runtime::{CustomChunkLoadingPlugin, CustomChunkLoadingRuntimeModule};
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawCustomChunkLoadingPluginOptions {
+  pub r#type: String,
+  pub load_chunk: String,
+}
+
+impl From<RawCustomChunkLoadingPluginOptions> for CustomChunkLoadingPlugin {
+  fn from(value: RawCustomChunkLoadingPluginOptions) -> Self {
+    let RawCustomChunkLoadingPluginOptions { r#type, load_chunk } = value;
+    let chunk_loading = r#type.clone();
+    CustomChunkLoadingPlugin::new(
+      r#type,
+      Arc::new(move |_, _, _| {
+        Ok(CustomChunkLoadingRuntimeModule::new(chunk_loading.clone(), load_chunk.clone()).boxed())
+      }),
+    )
+  }
+}
@@ -0,0 +1,20 @@
+use napi_derive::napi;
+use rspack_plugin_wasm::CustomWasmLoadingPlugin;
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawCustomWasmLoadingPluginOptions {
+  pub r#type: String,
+  pub generate_load_binary_code: String,
+  pub supports_streaming: Option<bool>,
+}
+
+impl From<RawCustomWasmLoadingPluginOptions> for CustomWasmLoadingPlugin {
+  fn from(value: RawCustomWasmLoadingPluginOptions) -> Self {
+    CustomWasmLoadingPlugin::new(
+      value.r#type,
+      value.generate_load_binary_code,
+      value.supports_streaming.unwrap_or(false),
+    )
+  }
+}
@@ -8,7 +8,8 @@ use rspack_binding_values::{
 };
 use rspack_plugin_mf::{
   ConsumeOptions, ConsumeSharedPluginOptions, ConsumeVersion, ContainerPluginOptions,
-  ContainerReferencePluginOptions, ExposeOptions, ProvideOptions, ProvideVersion, RemoteOptions,
+  ContainerReferencePluginOptions, ExposeOptions, ModuleFederationManifestPluginOptions,
+  ProvideOptions, ProvideVersion, RemoteOptions,
 };
 
 #[derive(Debug)]
@@ -207,3 +208,21 @@ impl From<RawVersionWrapper> for ConsumeVersion {
     }
   }
 }
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawModuleFederationManifestPluginOptions {
+  pub name: String,
+  pub file_path: Option<String>,
+  pub file_name: Option<String>,
+}
+
+impl From<RawModuleFederationManifestPluginOptions> for ModuleFederationManifestPluginOptions {
+  fn from(value: RawModuleFederationManifestPluginOptions) -> Self {
+    Self {
+      name: value.name,
+      file_path: value.file_path,
+      file_name: value.file_name,
+    }
+  }
+}
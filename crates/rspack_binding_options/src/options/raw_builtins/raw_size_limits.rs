@@ -1,7 +1,39 @@
 use derivative::Derivative;
 use napi_derive::napi;
+use rspack_error::{error, Result};
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
-use rspack_plugin_size_limits::{AssetFilterFn, SizeLimitsPluginOptions};
+use rspack_plugin_size_limits::{
+  AssetFilterFn, SizeLimitsBudget, SizeLimitsBudgetTarget, SizeLimitsCompression,
+  SizeLimitsPluginOptions,
+};
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawSizeLimitsBudget {
+  pub asset: Option<String>,
+  pub entrypoint: Option<String>,
+  pub max_size: f64,
+}
+
+impl TryFrom<RawSizeLimitsBudget> for SizeLimitsBudget {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawSizeLimitsBudget) -> Result<Self> {
+    let target = match (value.asset, value.entrypoint) {
+      (Some(asset), None) => SizeLimitsBudgetTarget::Asset(asset),
+      (None, Some(entrypoint)) => SizeLimitsBudgetTarget::Entrypoint(entrypoint),
+      _ => {
+        return Err(error!(
+          "Expect a performance budget to have either asset or entrypoint"
+        ))
+      }
+    };
+    Ok(SizeLimitsBudget {
+      target,
+      max_size: value.max_size,
+    })
+  }
+}
 
 #[derive(Derivative)]
 #[derivative(Debug)]
@@ -14,11 +46,27 @@ pub struct RawSizeLimitsPluginOptions {
   pub hints: Option<String>,
   pub max_asset_size: Option<f64>,
   pub max_entrypoint_size: Option<f64>,
+  pub baseline: Option<String>,
+  #[napi(ts_type = "\"gzip\" | \"brotli\"")]
+  pub compression: Option<String>,
+  pub budgets: Option<Vec<RawSizeLimitsBudget>>,
 }
 
-impl From<RawSizeLimitsPluginOptions> for SizeLimitsPluginOptions {
-  fn from(value: RawSizeLimitsPluginOptions) -> Self {
-    SizeLimitsPluginOptions {
+impl TryFrom<RawSizeLimitsPluginOptions> for SizeLimitsPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawSizeLimitsPluginOptions) -> Result<Self> {
+    let compression = value
+      .compression
+      .map(|compression| match compression.as_str() {
+        "gzip" => Ok(SizeLimitsCompression::Gzip),
+        "brotli" => Ok(SizeLimitsCompression::Brotli),
+        _ => Err(error!(
+          "Expect performance.compression to be 'gzip' or 'brotli', but got '{compression}'"
+        )),
+      })
+      .transpose()?;
+    Ok(SizeLimitsPluginOptions {
       asset_filter: value.asset_filter.map(|asset_filter| {
         let asset_filter_fn: AssetFilterFn = Box::new(move |name| {
           let f = asset_filter.clone();
@@ -30,6 +78,14 @@ impl From<RawSizeLimitsPluginOptions> for SizeLimitsPluginOptions {
       hints: value.hints,
       max_asset_size: value.max_asset_size,
       max_entrypoint_size: value.max_entrypoint_size,
-    }
+      baseline: value.baseline,
+      compression,
+      budgets: value
+        .budgets
+        .unwrap_or_default()
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<Result<_>>()?,
+    })
   }
 }
@@ -0,0 +1,26 @@
+use napi_derive::napi;
+use rspack_error::Result;
+use rspack_plugin_sri::SubresourceIntegrityRspackPluginOptions;
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawSubresourceIntegrityRspackPluginOptions {
+  #[napi(ts_type = "Array<\"sha256\" | \"sha384\" | \"sha512\">")]
+  pub hash_func_names: Vec<String>,
+}
+
+impl TryFrom<RawSubresourceIntegrityRspackPluginOptions>
+  for SubresourceIntegrityRspackPluginOptions
+{
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawSubresourceIntegrityRspackPluginOptions) -> Result<Self> {
+    Ok(Self {
+      hash_func_names: value
+        .hash_func_names
+        .iter()
+        .map(|name| name.parse())
+        .collect::<Result<_>>()?,
+    })
+  }
+}
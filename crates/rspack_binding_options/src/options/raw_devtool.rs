@@ -105,11 +105,14 @@ pub struct RawSourceMapDevToolPluginOptions {
   #[napi(ts_type = "(false | null) | string | Function")]
   pub append: Option<RawAppend>,
   pub columns: Option<bool>,
+  pub debug_ids: Option<bool>,
   #[napi(ts_type = "string | ((info: RawModuleFilenameTemplateFnCtx) => string)")]
   pub fallback_module_filename_template: Option<RawModuleFilenameTemplate>,
   pub file_context: Option<String>,
   #[napi(ts_type = "(false | null) | string")]
   pub filename: Option<RawFilename>,
+  #[napi(ts_type = "(text: string) => boolean")]
+  pub ignore_list: Option<ThreadsafeFunction<String, bool>>,
   pub module: Option<bool>,
   #[napi(ts_type = "string | ((info: RawModuleFilenameTemplateFnCtx) => string)")]
   pub module_filename_template: Option<RawModuleFilenameTemplate>,
@@ -125,6 +128,7 @@ impl From<RawSourceMapDevToolPluginOptions> for SourceMapDevToolPluginOptions {
   fn from(opts: RawSourceMapDevToolPluginOptions) -> Self {
     let append = opts.append.map(normalize_raw_append);
     let test = opts.test.map(normalize_raw_test);
+    let ignore_list = opts.ignore_list.map(normalize_raw_test);
     let filename = opts.filename.and_then(|raw| match raw {
       Either3::A(_) | Either3::B(_) => None,
       Either3::C(s) => Some(s),
@@ -143,6 +147,7 @@ impl From<RawSourceMapDevToolPluginOptions> for SourceMapDevToolPluginOptions {
     Self {
       append,
       columns,
+      debug_ids: opts.debug_ids.unwrap_or(false),
       fallback_module_filename_template,
       file_context: opts.file_context,
       filename,
@@ -153,6 +158,7 @@ impl From<RawSourceMapDevToolPluginOptions> for SourceMapDevToolPluginOptions {
       module: opts.module.unwrap_or(true),
       source_root: opts.source_root,
       test,
+      ignore_list,
     }
   }
 }
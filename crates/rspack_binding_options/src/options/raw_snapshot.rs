@@ -1,12 +1,67 @@
+use napi::Either;
 use napi_derive::napi;
-use rspack_core::SnapshotOptions;
+use rspack_core::{PathMatcher, SnapshotOptions, SnapshotStrategy};
+use rspack_regex::RspackRegex;
+
+type RawPathMatcher = Either<String, RspackRegex>;
 
 #[derive(Debug, Default)]
 #[napi(object)]
-pub struct RawSnapshotOptions;
+pub struct RawSnapshotStrategy {
+  pub hash: Option<bool>,
+  pub timestamp: Option<bool>,
+}
+
+#[derive(Debug, Default)]
+#[napi(object, object_to_js = false)]
+pub struct RawSnapshotOptions {
+  #[napi(ts_type = "(string | RegExp)[]")]
+  pub managed_paths: Option<Vec<RawPathMatcher>>,
+  #[napi(ts_type = "(string | RegExp)[]")]
+  pub immutable_paths: Option<Vec<RawPathMatcher>>,
+  pub build_dependencies: Option<RawSnapshotStrategy>,
+  pub module: Option<RawSnapshotStrategy>,
+  pub resolve: Option<RawSnapshotStrategy>,
+}
+
+fn normalize_strategy(
+  value: Option<RawSnapshotStrategy>,
+  default: SnapshotStrategy,
+) -> SnapshotStrategy {
+  match value {
+    Some(RawSnapshotStrategy { hash, timestamp }) => SnapshotStrategy {
+      hash: hash.unwrap_or(default.hash),
+      timestamp: timestamp.unwrap_or(default.timestamp),
+    },
+    None => default,
+  }
+}
+
+fn normalize_paths(value: Vec<RawPathMatcher>) -> Vec<PathMatcher> {
+  value
+    .into_iter()
+    .map(|item| match item {
+      Either::A(s) => PathMatcher::String(s),
+      Either::B(r) => PathMatcher::Regexp(r),
+    })
+    .collect()
+}
 
 impl From<RawSnapshotOptions> for SnapshotOptions {
-  fn from(_value: RawSnapshotOptions) -> Self {
-    SnapshotOptions
+  fn from(value: RawSnapshotOptions) -> Self {
+    let default = SnapshotOptions::default();
+    SnapshotOptions {
+      managed_paths: value
+        .managed_paths
+        .map(normalize_paths)
+        .unwrap_or(default.managed_paths),
+      immutable_paths: value
+        .immutable_paths
+        .map(normalize_paths)
+        .unwrap_or(default.immutable_paths),
+      build_dependencies: normalize_strategy(value.build_dependencies, default.build_dependencies),
+      module: normalize_strategy(value.module, default.module),
+      resolve: normalize_strategy(value.resolve, default.resolve),
+    }
   }
 }
@@ -3,12 +3,18 @@ use napi_derive::napi;
 #[napi(object)]
 pub struct JsAssetInfoRelated {
   pub source_map: Option<String>,
+  pub gzipped: Option<String>,
+  pub brotli_compressed: Option<String>,
+  pub zstd_compressed: Option<String>,
 }
 
 impl From<JsAssetInfoRelated> for rspack_core::AssetInfoRelated {
   fn from(i: JsAssetInfoRelated) -> Self {
     Self {
       source_map: i.source_map,
+      gzipped: i.gzipped,
+      brotli_compressed: i.brotli_compressed,
+      zstd_compressed: i.zstd_compressed,
     }
   }
 }
@@ -50,6 +56,10 @@ pub struct JsAssetInfo {
   pub extras: serde_json::Map<String, serde_json::Value>,
   /// whether this asset is over the size limit
   pub is_over_size_limit: Option<bool>,
+  /// the gzip compressed size of this asset, computed when the size limits are checked with gzip
+  pub gzip_size: Option<f64>,
+  /// the brotli compressed size of this asset, computed when the size limits are checked with brotli
+  pub brotli_size: Option<f64>,
 }
 
 impl From<JsAssetInfo> for rspack_core::AssetInfo {
@@ -70,6 +80,8 @@ impl From<JsAssetInfo> for rspack_core::AssetInfo {
       css_unused_idents: i.css_unused_idents.map(|i| i.into_iter().collect()),
       extras: i.extras,
       is_over_size_limit: i.is_over_size_limit,
+      gzip_size: i.gzip_size,
+      brotli_size: i.brotli_size,
     }
   }
 }
@@ -84,6 +96,9 @@ impl From<rspack_core::AssetInfoRelated> for JsAssetInfoRelated {
   fn from(related: rspack_core::AssetInfoRelated) -> Self {
     Self {
       source_map: related.source_map,
+      gzipped: related.gzipped,
+      brotli_compressed: related.brotli_compressed,
+      zstd_compressed: related.zstd_compressed,
     }
   }
 }
@@ -105,6 +120,8 @@ impl From<rspack_core::AssetInfo> for JsAssetInfo {
       css_unused_idents: info.css_unused_idents.map(|i| i.into_iter().collect()),
       extras: info.extras,
       is_over_size_limit: info.is_over_size_limit,
+      gzip_size: info.gzip_size,
+      brotli_size: info.brotli_size,
     }
   }
 }
@@ -64,6 +64,22 @@ impl EntryOptionsDTO {
     };
   }
 
+  #[napi(getter)]
+  pub fn wasm_loading(&self) -> Either<&str, ()> {
+    match &self.0.wasm_loading {
+      Some(w) => Either::A(w.into()),
+      None => Either::B(()),
+    }
+  }
+
+  #[napi(setter)]
+  pub fn set_wasm_loading(&mut self, wasm_loading: Either<String, ()>) {
+    self.0.wasm_loading = match wasm_loading {
+      Either::A(s) => Some(Into::into(s.as_str())),
+      Either::B(_) => None,
+    };
+  }
+
   #[napi(getter)]
   pub fn async_chunks(&self) -> Either<bool, ()> {
     self.0.async_chunks.into()
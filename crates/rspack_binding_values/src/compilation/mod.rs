@@ -193,6 +193,15 @@ impl JsCompilation {
     )
   }
 
+  /// The tap calls of the hooks in the Chrome trace event format, only recorded when `profile` is
+  /// enabled.
+  #[napi]
+  pub fn get_hook_profile_trace(&self) -> Result<String> {
+    let compilation = self.as_ref()?;
+
+    Ok(compilation.hook_profile.to_chrome_trace())
+  }
+
   #[napi]
   pub fn get_optimization_bailout(&self) -> Result<Vec<JsStatsOptimizationBailout>> {
     let compilation = self.as_ref()?;
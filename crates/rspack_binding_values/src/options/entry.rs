@@ -35,6 +35,7 @@ pub struct JsEntryOptions {
   #[napi(ts_type = "false | string")]
   pub runtime: Option<JsEntryRuntime>,
   pub chunk_loading: Option<String>,
+  pub wasm_loading: Option<String>,
   pub async_chunks: Option<bool>,
   #[napi(ts_type = "\"auto\" | JsFilename")]
   pub public_path: Option<JsFilename>,
@@ -51,6 +52,7 @@ impl From<JsEntryOptions> for EntryOptions {
       name: value.name,
       runtime: value.runtime.map(|r| JsEntryRuntimeWrapper(r).into()),
       chunk_loading: value.chunk_loading.as_deref().map(Into::into),
+      wasm_loading: value.wasm_loading.as_deref().map(Into::into),
       async_chunks: value.async_chunks,
       public_path: value.public_path.map(Into::into),
       base_uri: value.base_uri,
@@ -17,6 +17,7 @@ impl From<JsRspackDiagnostic> for Diagnostic {
 pub enum JsRspackSeverity {
   Error,
   Warn,
+  Advice,
 }
 
 impl From<JsRspackSeverity> for RspackSeverity {
@@ -24,6 +25,7 @@ impl From<JsRspackSeverity> for RspackSeverity {
     match value {
       JsRspackSeverity::Error => RspackSeverity::Error,
       JsRspackSeverity::Warn => RspackSeverity::Warn,
+      JsRspackSeverity::Advice => RspackSeverity::Advice,
     }
   }
 }
@@ -33,6 +35,7 @@ impl From<JsRspackSeverity> for miette::Severity {
     match value {
       JsRspackSeverity::Error => miette::Severity::Error,
       JsRspackSeverity::Warn => miette::Severity::Warning,
+      JsRspackSeverity::Advice => miette::Severity::Advice,
     }
   }
 }
@@ -56,6 +59,7 @@ impl JsRspackError {
         match diagnostic.severity() {
           rspack_error::RspackSeverity::Error => "Error".to_string(),
           rspack_error::RspackSeverity::Warn => "Warn".to_string(),
+          rspack_error::RspackSeverity::Advice => "Advice".to_string(),
         }
       }),
       message: diagnostic.render_report(colored)?,
@@ -71,6 +75,7 @@ impl JsRspackError {
     (match severity {
       RspackSeverity::Error => Diagnostic::error,
       RspackSeverity::Warn => Diagnostic::warn,
+      RspackSeverity::Advice => Diagnostic::advice,
     })(self.name, self.message)
     .with_file(self.file.map(Into::into))
     .with_module_identifier(self.module_identifier.map(Into::into))
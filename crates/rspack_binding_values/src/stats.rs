@@ -4,15 +4,17 @@ use napi_derive::napi;
 use rspack_collections::Identifier;
 use rspack_core::{
   rspack_sources::{RawSource, Source},
-  EntrypointsStatsOption, ExtendedStatsOptions, Stats, StatsChunk, StatsModule, StatsUsedExports,
+  EntrypointsStatsOption, ExtendedStatsOptions, Stats, StatsChunk, StatsExcluded, StatsFilter,
+  StatsModule, StatsPreset, StatsSnapshot, StatsUsedExports,
 };
 use rspack_napi::{
   napi::{
-    bindgen_prelude::{Buffer, FromNapiValue, Result, SharedReference, ToNapiValue},
+    bindgen_prelude::{Buffer, Either3, FromNapiValue, Result, SharedReference, ToNapiValue},
     Either,
   },
   OneShotRef,
 };
+use rspack_regex::RspackRegex;
 use rspack_util::itoa;
 use rustc_hash::FxHashMap as HashMap;
 
@@ -377,6 +379,8 @@ pub struct JsStatsAsset {
   pub auxiliary_chunk_names: Vec<String>,
   pub auxiliary_chunk_id_hints: Vec<String>,
   pub auxiliary_chunks: Vec<Option<String>>,
+  pub gzip_size: Option<f64>,
+  pub brotli_size: Option<f64>,
 }
 
 impl FromNapiValue for JsStatsAsset {
@@ -402,6 +406,8 @@ impl From<rspack_core::StatsAsset> for JsStatsAsset {
       auxiliary_chunk_id_hints: stats.auxiliary_chunk_id_hints,
       auxiliary_chunks: stats.auxiliary_chunks,
       auxiliary_chunk_names: stats.auxiliary_chunk_names,
+      gzip_size: stats.gzip_size,
+      brotli_size: stats.brotli_size,
     }
   }
 }
@@ -671,7 +677,7 @@ impl TryFrom<StatsModule<'_>> for JsStatsModule {
       provided_exports: stats
         .provided_exports
         .map(|exports| exports.into_iter().map(|i| i.to_string()).collect()),
-      optimization_bailout: stats.optimization_bailout.map(|bailout| bailout.to_vec()),
+      optimization_bailout: stats.optimization_bailout,
       pre_order_index: stats.pre_order_index,
       post_order_index: stats.post_order_index,
       cached: stats.cached,
@@ -1124,61 +1130,117 @@ impl From<rspack_core::StatsAssetsByChunkName> for JsStatsAssetsByChunkName {
 
 #[napi(object, object_to_js = false)]
 pub struct JsStatsOptions {
-  pub assets: bool,
-  pub cached_modules: bool,
-  pub chunks: bool,
-  pub chunk_group_auxiliary: bool,
-  pub chunk_group_children: bool,
-  pub chunk_groups: bool,
-  pub chunk_modules: bool,
-  pub chunk_relations: bool,
-  pub depth: bool,
-  pub entrypoints: Either<bool, String>,
-  pub errors: bool,
-  pub hash: bool,
-  pub ids: bool,
-  pub modules: bool,
-  pub module_assets: bool,
-  pub nested_modules: bool,
-  pub optimization_bailout: bool,
-  pub provided_exports: bool,
-  pub reasons: bool,
-  pub source: bool,
-  pub used_exports: bool,
-  pub warnings: bool,
+  /// The preset the options are resolved from, the options which are not given fall back to it.
+  #[napi(ts_type = "boolean | string")]
+  pub preset: Option<Either<bool, String>>,
+  pub for_to_string: Option<bool>,
+  pub assets: Option<bool>,
+  pub cached_modules: Option<bool>,
+  pub chunks: Option<bool>,
+  pub chunk_group_auxiliary: Option<bool>,
+  pub chunk_group_children: Option<bool>,
+  pub chunk_groups: Option<bool>,
+  pub chunk_modules: Option<bool>,
+  pub chunk_relations: Option<bool>,
+  pub depth: Option<bool>,
+  pub entrypoints: Option<Either<bool, String>>,
+  pub errors: Option<bool>,
+  pub hash: Option<bool>,
+  pub ids: Option<bool>,
+  pub modules: Option<bool>,
+  pub module_assets: Option<bool>,
+  pub nested_modules: Option<bool>,
+  pub optimization_bailout: Option<bool>,
+  pub provided_exports: Option<bool>,
+  pub reasons: Option<bool>,
+  pub source: Option<bool>,
+  pub used_exports: Option<bool>,
+  pub warnings: Option<bool>,
+  /// The filters of `excludeModules` which can be applied natively, the others are applied by
+  /// the stats factory.
+  #[napi(ts_type = "(boolean | string | RegExp)[]")]
+  pub exclude_modules: Option<Vec<JsStatsFilter>>,
+  #[napi(ts_type = "(boolean | string | RegExp)[]")]
+  pub exclude_assets: Option<Vec<JsStatsFilter>>,
+  /// Only given when the modules are not grouped by the stats factory.
+  pub modules_space: Option<u32>,
+}
+
+type JsStatsFilter = Either3<bool, String, RspackRegex>;
+
+fn to_stats_filter(filter: JsStatsFilter) -> StatsFilter {
+  match filter {
+    Either3::A(b) => StatsFilter::Bool(b),
+    Either3::B(s) => StatsFilter::String(s),
+    Either3::C(r) => StatsFilter::Regex(r),
+  }
 }
 
 impl From<JsStatsOptions> for ExtendedStatsOptions {
   fn from(value: JsStatsOptions) -> Self {
-    let entrypoints = match value.entrypoints {
-      Either::A(b) => EntrypointsStatsOption::Bool(b),
-      Either::B(s) => EntrypointsStatsOption::String(s),
+    let preset = match &value.preset {
+      Some(Either::A(true)) | None => StatsPreset::Normal,
+      Some(Either::A(false)) => StatsPreset::None,
+      Some(Either::B(s)) => StatsPreset::from(s.as_str()),
     };
-
-    Self {
-      assets: value.assets,
-      cached_modules: value.cached_modules,
-      chunks: value.chunks,
-      chunk_group_auxiliary: value.chunk_group_auxiliary,
-      chunk_group_children: value.chunk_group_children,
-      chunk_groups: value.chunk_groups,
-      chunk_modules: value.chunk_modules,
-      chunk_relations: value.chunk_relations,
-      depth: value.depth,
-      entrypoints,
-      errors: value.errors,
-      hash: value.hash,
-      ids: value.ids,
-      modules: value.modules,
-      module_assets: value.module_assets,
-      nested_modules: value.nested_modules,
-      optimization_bailout: value.optimization_bailout,
-      provided_exports: value.provided_exports,
-      reasons: value.reasons,
-      source: value.source,
-      used_exports: value.used_exports,
-      warnings: value.warnings,
+    let mut options =
+      ExtendedStatsOptions::from_preset(preset, value.for_to_string.unwrap_or_default());
+
+    macro_rules! override_options {
+      ($($field:ident),*) => {
+        $(
+          if let Some($field) = value.$field {
+            options.$field = $field;
+          }
+        )*
+      };
+    }
+    override_options!(
+      assets,
+      cached_modules,
+      chunks,
+      chunk_group_auxiliary,
+      chunk_group_children,
+      chunk_groups,
+      chunk_modules,
+      chunk_relations,
+      depth,
+      errors,
+      hash,
+      ids,
+      modules,
+      module_assets,
+      nested_modules,
+      optimization_bailout,
+      provided_exports,
+      reasons,
+      source,
+      used_exports,
+      warnings
+    );
+    if let Some(entrypoints) = value.entrypoints {
+      options.entrypoints = match entrypoints {
+        Either::A(b) => EntrypointsStatsOption::Bool(b),
+        Either::B(s) => EntrypointsStatsOption::String(s),
+      };
     }
+    // excluding and space limiting are always given by the js side, as it only passes the ones
+    // which do not change the grouping of its stats factory
+    options.exclude_modules = value
+      .exclude_modules
+      .unwrap_or_default()
+      .into_iter()
+      .map(to_stats_filter)
+      .collect();
+    options.exclude_assets = value
+      .exclude_assets
+      .unwrap_or_default()
+      .into_iter()
+      .map(to_stats_filter)
+      .collect();
+    options.modules_space = value.modules_space.map(|space| space as usize);
+
+    options
   }
 }
 
@@ -1186,6 +1248,46 @@ impl From<JsStatsOptions> for ExtendedStatsOptions {
 pub struct JsStatsGetAssets {
   pub assets: Vec<JsStatsAsset>,
   pub assets_by_chunk_name: Vec<JsStatsAssetsByChunkName>,
+  pub excluded: Option<JsStatsExcluded>,
+}
+
+#[napi(object, object_from_js = false)]
+pub struct JsStatsExcluded {
+  pub count: u32,
+  pub size: f64,
+  pub sizes: Vec<JsStatsSize>,
+}
+
+impl FromNapiValue for JsStatsExcluded {
+  unsafe fn from_napi_value(
+    _env: napi::sys::napi_env,
+    _napi_val: napi::sys::napi_value,
+  ) -> Result<Self> {
+    unreachable!()
+  }
+}
+
+impl From<StatsExcluded> for JsStatsExcluded {
+  fn from(excluded: StatsExcluded) -> Self {
+    Self {
+      count: excluded.count as u32,
+      size: excluded.size,
+      sizes: excluded
+        .sizes
+        .into_iter()
+        .map(|(source_type, size)| JsStatsSize {
+          source_type: source_type.to_string(),
+          size,
+        })
+        .collect(),
+    }
+  }
+}
+
+pub struct JsStatsGetModules {
+  pub modules: Vec<JsStatsModule>,
+  pub excluded: Option<JsStatsExcluded>,
+  pub filtered: Option<u32>,
 }
 
 impl FromNapiValue for JsStatsGetAssets {
@@ -1199,11 +1301,18 @@ impl FromNapiValue for JsStatsGetAssets {
 
 #[napi(object, object_from_js = false)]
 pub struct JsStatsCompilation {
+  pub advices: Vec<JsStatsWarning>,
   pub assets: Option<Vec<JsStatsAsset>>,
   pub assets_by_chunk_name: Option<Vec<JsStatsAssetsByChunkName>>,
   pub chunks: Option<Vec<JsStatsChunk>>,
   pub entrypoints: Option<Vec<JsStatsChunkGroup>>,
   pub errors: Vec<JsStatsError>,
+  /// The assets hidden by the native `excludeAssets` filters.
+  pub excluded_assets: Option<JsStatsExcluded>,
+  /// The modules hidden by the native `excludeModules` filters.
+  pub excluded_modules: Option<JsStatsExcluded>,
+  /// The number of modules left out by `modulesSpace`.
+  pub filtered_modules: Option<u32>,
   pub hash: Option<String>,
   pub modules: Option<Vec<JsStatsModule>>,
   pub named_chunk_groups: Option<Vec<JsStatsChunkGroup>>,
@@ -1258,18 +1367,22 @@ impl JsStats {
 
     let hash = options.hash.then(|| self.hash()).flatten();
 
-    let (assets, assets_by_chunk_name) = if options.assets {
-      let asts = self.assets();
-      (Some(asts.assets), Some(asts.assets_by_chunk_name))
+    let (assets, assets_by_chunk_name, excluded_assets) = if options.assets {
+      let asts = self.assets(&options);
+      (
+        Some(asts.assets),
+        Some(asts.assets_by_chunk_name),
+        asts.excluded,
+      )
     } else {
-      (None, None)
+      (None, None, None)
     };
 
-    let modules = if options.modules {
+    let (modules, excluded_modules, filtered_modules) = if options.modules {
       let mds = self.modules(&options)?;
-      Some(mds)
+      (Some(mds.modules), mds.excluded, mds.filtered)
     } else {
-      None
+      (None, None, None)
     };
 
     let chunks = if options.chunks {
@@ -1294,12 +1407,18 @@ impl JsStats {
 
     let warnings = self.warnings();
 
+    let advices = self.advices();
+
     Ok(JsStatsCompilationWrapper(JsStatsCompilation {
+      advices,
       assets,
       assets_by_chunk_name,
       chunks,
       entrypoints,
       errors,
+      excluded_assets,
+      excluded_modules,
+      filtered_modules,
       hash,
       modules,
       named_chunk_groups,
@@ -1307,23 +1426,32 @@ impl JsStats {
     }))
   }
 
-  fn assets(&self) -> JsStatsGetAssets {
-    let (assets, assets_by_chunk_name) = self.inner.get_assets();
+  fn assets(&self, options: &ExtendedStatsOptions) -> JsStatsGetAssets {
+    let (assets, assets_by_chunk_name, excluded) = self.inner.get_assets(options);
     let assets = assets.into_iter().map(Into::into).collect();
     let assets_by_chunk_name = assets_by_chunk_name.into_iter().map(Into::into).collect();
     JsStatsGetAssets {
       assets,
       assets_by_chunk_name,
+      excluded: excluded.map(Into::into),
     }
   }
 
-  fn modules(&self, options: &ExtendedStatsOptions) -> Result<Vec<JsStatsModule>> {
-    self
+  fn modules(&self, options: &ExtendedStatsOptions) -> Result<JsStatsGetModules> {
+    let (modules, filtered) = self
       .inner
       .get_modules(options, |res| {
-        res.into_iter().map(JsStatsModule::try_from).collect()
+        res
+          .into_iter()
+          .map(JsStatsModule::try_from)
+          .collect::<Result<Vec<_>>>()
       })
-      .map_err(|e| napi::Error::from_reason(e.to_string()))?
+      .map_err(|e| napi::Error::from_reason(e.to_string()))?;
+    Ok(JsStatsGetModules {
+      modules: modules?,
+      excluded: filtered.excluded.map(Into::into),
+      filtered: filtered.filtered.map(|count| count as u32),
+    })
   }
 
   fn chunks(&self, options: &ExtendedStatsOptions) -> Result<Vec<JsStatsChunk>> {
@@ -1379,6 +1507,15 @@ impl JsStats {
       .collect()
   }
 
+  fn advices(&self) -> Vec<JsStatsWarning> {
+    self
+      .inner
+      .get_advices()
+      .into_iter()
+      .map(Into::into)
+      .collect()
+  }
+
   #[napi]
   pub fn has_warnings(&self) -> bool {
     !self.inner.get_warnings().is_empty()
@@ -1403,6 +1540,26 @@ impl JsStats {
       .collect()
   }
 
+  #[napi]
+  pub fn get_snapshot(&self) -> Result<String> {
+    self
+      .inner
+      .get_snapshot()
+      .to_json()
+      .map_err(|e| napi::Error::from_reason(e.to_string()))
+  }
+
+  #[napi]
+  pub fn diff(&self, baseline: String) -> Result<String> {
+    let baseline =
+      StatsSnapshot::from_json(&baseline).map_err(|e| napi::Error::from_reason(e.to_string()))?;
+    self
+      .inner
+      .diff(&baseline)
+      .to_json()
+      .map_err(|e| napi::Error::from_reason(e.to_string()))
+  }
+
   fn hash(&self) -> Option<String> {
     self.inner.get_hash().map(|hash| hash.to_string())
   }
@@ -1547,9 +1547,8 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
       .block_modules_runtime_map
       .entry(runtime.cloned().into())
       .or_default();
-    let block = module.into();
-    map.insert(block, Vec::new());
-    for b in block.get_blocks(compilation) {
+    map.insert(module.into(), Vec::new());
+    for b in module_graph.get_all_blocks(&module) {
       map.insert(b.into(), Vec::new());
     }
 
@@ -457,8 +457,14 @@ impl CodeSplitter {
     let dirty_blocks = self.collect_dirty_caches(compilation, modules.iter().copied());
 
     for m in modules {
+      // the modules of all blocks, including the nested ones like an `import()` inside
+      // `require.ensure`, are extracted with their root module, so invalidate them together
+      let blocks = compilation.get_module_graph().get_all_blocks(&m);
       for module_map in self.block_modules_runtime_map.values_mut() {
         module_map.swap_remove(&DependenciesBlockIdentifier::Module(m));
+        for block in &blocks {
+          module_map.swap_remove(&DependenciesBlockIdentifier::AsyncDependenciesBlock(*block));
+        }
       }
 
       let more_edges = self.invalidate_from_module(m, compilation)?;
@@ -12,7 +12,7 @@ use crate::{
   ChunkGroupByUkey, ChunkGroupUkey, DependencyLocation, DynamicImportFetchPriority, Filename,
   ModuleLayer,
 };
-use crate::{ChunkLoading, ChunkUkey, Compilation};
+use crate::{ChunkLoading, ChunkUkey, Compilation, WasmLoading};
 use crate::{LibraryOptions, ModuleIdentifier, PublicPath};
 
 #[derive(Debug, Clone)]
@@ -435,6 +435,7 @@ pub struct EntryOptions {
   pub name: Option<String>,
   pub runtime: Option<EntryRuntime>,
   pub chunk_loading: Option<ChunkLoading>,
+  pub wasm_loading: Option<WasmLoading>,
   pub async_chunks: Option<bool>,
   pub public_path: Option<PublicPath>,
   pub base_uri: Option<String>,
@@ -460,6 +461,7 @@ impl EntryOptions {
     merge_field!(name);
     merge_field!(runtime);
     merge_field!(chunk_loading);
+    merge_field!(wasm_loading);
     merge_field!(async_chunks);
     merge_field!(public_path);
     merge_field!(base_uri);
@@ -15,7 +15,7 @@ use rspack_error::{error, miette::diagnostic, Diagnostic, DiagnosticExt, Result,
 use rspack_fs::ReadableFileSystem;
 use rspack_futures::FuturesResults;
 use rspack_hash::{RspackHash, RspackHashDigest};
-use rspack_hook::define_hook;
+use rspack_hook::{define_hook, Hook, HookProfiler};
 use rspack_sources::{BoxSource, CachedSource, SourceExt};
 use rspack_util::itoa;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
@@ -23,8 +23,10 @@ use tracing::instrument;
 
 use super::{
   hmr::CompilationRecords,
+  hook_profile::HookProfile,
   make::{make_module_graph, update_module_graph, MakeArtifact, MakeParam},
   module_executor::ModuleExecutor,
+  records::IdRecords,
 };
 use crate::{
   build_chunk_graph::build_chunk_graph,
@@ -53,6 +55,7 @@ define_hook!(CompilationAddEntry: AsyncSeries(compilation: &mut Compilation, ent
 define_hook!(CompilationBuildModule: AsyncSeries(module: &mut BoxModule));
 define_hook!(CompilationStillValidModule: AsyncSeries(module: &mut BoxModule));
 define_hook!(CompilationSucceedModule: AsyncSeries(module: &mut BoxModule));
+define_hook!(CompilationFailedModule: AsyncSeries(module: &mut BoxModule, diagnostic: &Diagnostic));
 define_hook!(CompilationExecuteModule:
   SyncSeries(module: &ModuleIdentifier, runtime_modules: &IdentifierSet, codegen_results: &CodeGenerationResults, execute_module_id: &ExecuteModuleId));
 define_hook!(CompilationFinishModules: AsyncSeries(compilation: &mut Compilation));
@@ -61,8 +64,10 @@ define_hook!(CompilationOptimizeDependencies: SyncSeriesBail(compilation: &mut C
 define_hook!(CompilationOptimizeModules: AsyncSeriesBail(compilation: &mut Compilation) -> bool);
 define_hook!(CompilationAfterOptimizeModules: AsyncSeries(compilation: &mut Compilation));
 define_hook!(CompilationOptimizeChunks: SyncSeriesBail(compilation: &mut Compilation) -> bool);
+define_hook!(CompilationAfterOptimizeChunks: AsyncSeries(compilation: &mut Compilation));
 define_hook!(CompilationOptimizeTree: AsyncSeries(compilation: &mut Compilation));
 define_hook!(CompilationOptimizeChunkModules: AsyncSeriesBail(compilation: &mut Compilation) -> bool);
+define_hook!(CompilationBeforeModuleIds: SyncSeries(compilation: &mut Compilation));
 define_hook!(CompilationModuleIds: SyncSeries(compilation: &mut Compilation));
 define_hook!(CompilationChunkIds: SyncSeries(compilation: &mut Compilation));
 define_hook!(CompilationRuntimeModule: AsyncSeries(compilation: &mut Compilation, module: &ModuleIdentifier, chunk: &ChunkUkey));
@@ -71,11 +76,13 @@ define_hook!(CompilationAdditionalChunkRuntimeRequirements: SyncSeries(compilati
 define_hook!(CompilationAdditionalTreeRuntimeRequirements: AsyncSeries(compilation: &mut Compilation, chunk_ukey: &ChunkUkey, runtime_requirements: &mut RuntimeGlobals));
 define_hook!(CompilationRuntimeRequirementInTree: SyncSeriesBail(compilation: &mut Compilation, chunk_ukey: &ChunkUkey, all_runtime_requirements: &RuntimeGlobals, runtime_requirements: &RuntimeGlobals, runtime_requirements_mut: &mut RuntimeGlobals));
 define_hook!(CompilationOptimizeCodeGeneration: SyncSeries(compilation: &mut Compilation));
+define_hook!(CompilationAfterCodeGeneration: AsyncSeries(compilation: &mut Compilation));
 define_hook!(CompilationChunkHash: AsyncSeries(compilation: &Compilation, chunk_ukey: &ChunkUkey, hasher: &mut RspackHash));
 define_hook!(CompilationContentHash: AsyncSeries(compilation: &Compilation, chunk_ukey: &ChunkUkey, hashes: &mut HashMap<SourceType, RspackHash>));
 define_hook!(CompilationRenderManifest: AsyncSeries(compilation: &Compilation, chunk_ukey: &ChunkUkey, manifest: &mut Vec<RenderManifestEntry>, diagnostics: &mut Vec<Diagnostic>));
 define_hook!(CompilationChunkAsset: AsyncSeries(chunk: &mut Chunk, filename: &str));
 define_hook!(CompilationProcessAssets: AsyncSeries(compilation: &mut Compilation));
+define_hook!(CompilationOptimizeAssets: AsyncSeries(compilation: &mut Compilation));
 define_hook!(CompilationAfterProcessAssets: AsyncSeries(compilation: &mut Compilation));
 define_hook!(CompilationAfterSeal: AsyncSeries(compilation: &mut Compilation));
 
@@ -85,6 +92,7 @@ pub struct CompilationHooks {
   pub build_module: CompilationBuildModuleHook,
   pub still_valid_module: CompilationStillValidModuleHook,
   pub succeed_module: CompilationSucceedModuleHook,
+  pub failed_module: CompilationFailedModuleHook,
   pub execute_module: CompilationExecuteModuleHook,
   pub finish_modules: CompilationFinishModulesHook,
   pub seal: CompilationSealHook,
@@ -92,8 +100,10 @@ pub struct CompilationHooks {
   pub optimize_modules: CompilationOptimizeModulesHook,
   pub after_optimize_modules: CompilationAfterOptimizeModulesHook,
   pub optimize_chunks: CompilationOptimizeChunksHook,
+  pub after_optimize_chunks: CompilationAfterOptimizeChunksHook,
   pub optimize_tree: CompilationOptimizeTreeHook,
   pub optimize_chunk_modules: CompilationOptimizeChunkModulesHook,
+  pub before_module_ids: CompilationBeforeModuleIdsHook,
   pub module_ids: CompilationModuleIdsHook,
   pub chunk_ids: CompilationChunkIdsHook,
   pub runtime_module: CompilationRuntimeModuleHook,
@@ -102,15 +112,87 @@ pub struct CompilationHooks {
   pub additional_tree_runtime_requirements: CompilationAdditionalTreeRuntimeRequirementsHook,
   pub runtime_requirement_in_tree: CompilationRuntimeRequirementInTreeHook,
   pub optimize_code_generation: CompilationOptimizeCodeGenerationHook,
+  pub after_code_generation: CompilationAfterCodeGenerationHook,
   pub chunk_hash: CompilationChunkHashHook,
   pub content_hash: CompilationContentHashHook,
   pub render_manifest: CompilationRenderManifestHook,
   pub chunk_asset: CompilationChunkAssetHook,
   pub process_assets: CompilationProcessAssetsHook,
+  pub optimize_assets: CompilationOptimizeAssetsHook,
   pub after_process_assets: CompilationAfterProcessAssetsHook,
   pub after_seal: CompilationAfterSealHook,
 }
 
+impl CompilationHooks {
+  pub(crate) fn set_profiler(&mut self, profiler: &HookProfiler) {
+    self.add_entry.set_profiler(profiler.clone());
+    self.build_module.set_profiler(profiler.clone());
+    self.still_valid_module.set_profiler(profiler.clone());
+    self.succeed_module.set_profiler(profiler.clone());
+    self.failed_module.set_profiler(profiler.clone());
+    self.execute_module.set_profiler(profiler.clone());
+    self.finish_modules.set_profiler(profiler.clone());
+    self.seal.set_profiler(profiler.clone());
+    self.optimize_dependencies.set_profiler(profiler.clone());
+    self.optimize_modules.set_profiler(profiler.clone());
+    self.after_optimize_modules.set_profiler(profiler.clone());
+    self.optimize_chunks.set_profiler(profiler.clone());
+    self.after_optimize_chunks.set_profiler(profiler.clone());
+    self.optimize_tree.set_profiler(profiler.clone());
+    self.optimize_chunk_modules.set_profiler(profiler.clone());
+    self.before_module_ids.set_profiler(profiler.clone());
+    self.module_ids.set_profiler(profiler.clone());
+    self.chunk_ids.set_profiler(profiler.clone());
+    self.runtime_module.set_profiler(profiler.clone());
+    self
+      .runtime_requirement_in_module
+      .set_profiler(profiler.clone());
+    self
+      .additional_chunk_runtime_requirements
+      .set_profiler(profiler.clone());
+    self
+      .additional_tree_runtime_requirements
+      .set_profiler(profiler.clone());
+    self
+      .runtime_requirement_in_tree
+      .set_profiler(profiler.clone());
+    self.optimize_code_generation.set_profiler(profiler.clone());
+    self.after_code_generation.set_profiler(profiler.clone());
+    self.chunk_hash.set_profiler(profiler.clone());
+    self.content_hash.set_profiler(profiler.clone());
+    self.render_manifest.set_profiler(profiler.clone());
+    self.chunk_asset.set_profiler(profiler.clone());
+    self.process_assets.set_profiler(profiler.clone());
+    self.optimize_assets.set_profiler(profiler.clone());
+    self.after_process_assets.set_profiler(profiler.clone());
+    self.after_seal.set_profiler(profiler.clone());
+  }
+}
+
+/// Calls `optimize_assets` as the `PROCESS_ASSETS_STAGE_OPTIMIZE` stage of `process_assets`,
+/// like webpack does.
+pub(crate) struct OptimizeAssetsStage;
+
+#[async_trait::async_trait]
+impl CompilationProcessAssets for OptimizeAssetsStage {
+  async fn run(&self, compilation: &mut Compilation) -> Result<()> {
+    let plugin_driver = compilation.plugin_driver.clone();
+    plugin_driver
+      .compilation_hooks
+      .optimize_assets
+      .call(compilation)
+      .await
+  }
+
+  fn stage(&self) -> i32 {
+    Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE
+  }
+
+  fn plugin_name(&self) -> &str {
+    "rspack.Compilation"
+  }
+}
+
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct CompilationId(u32);
 
@@ -179,6 +261,11 @@ pub struct Compilation {
 
   pub hash: Option<RspackHashDigest>,
   pub used_chunk_ids: HashSet<String>,
+  pub used_module_ids: HashSet<String>,
+  /// the module and chunk ids of `recordsInputPath`, recorded again after the ids are assigned
+  pub id_records: Option<IdRecords>,
+  /// the tap calls of the hooks, only recorded when `profile` is enabled
+  pub hook_profile: HookProfile,
 
   pub file_dependencies: IndexSet<PathBuf, BuildHasherDefault<FxHasher>>,
   pub context_dependencies: IndexSet<PathBuf, BuildHasherDefault<FxHasher>>,
@@ -275,6 +362,9 @@ impl Compilation {
       code_splitting_cache: Default::default(),
       hash: None,
       used_chunk_ids: Default::default(),
+      used_module_ids: Default::default(),
+      id_records: None,
+      hook_profile: Default::default(),
 
       file_dependencies: Default::default(),
       context_dependencies: Default::default(),
@@ -591,8 +681,8 @@ impl Compilation {
 
   pub fn delete_asset(&mut self, filename: &str) {
     if let Some(asset) = self.assets.remove(filename) {
-      if let Some(source_map) = asset.info.related.source_map {
-        self.delete_asset(&source_map);
+      for (_, related) in asset.info.related.iter() {
+        self.delete_asset(related);
       }
       self.chunk_by_ukey.iter_mut().for_each(|(_, chunk)| {
         chunk.files.remove(filename);
@@ -679,6 +769,13 @@ impl Compilation {
       .filter(|d| matches!(d.severity(), Severity::Warn))
   }
 
+  pub fn get_advices(&self) -> impl Iterator<Item = &Diagnostic> {
+    self
+      .diagnostics
+      .iter()
+      .filter(|d| matches!(d.severity(), Severity::Advice))
+  }
+
   /// Get sorted warnings based on the factors as follows in order:
   /// - module identifier
   /// - error offset
@@ -937,16 +1034,25 @@ impl Compilation {
 
   #[instrument(skip_all)]
   async fn create_chunk_assets(&mut self, plugin_driver: SharedPluginDriver) -> Result<()> {
-    let results = self
+    let compilation = &*self;
+    let plugin_driver = &plugin_driver;
+    let results = compilation
       .chunk_by_ukey
       .values()
-      .map(|chunk| async {
-        let mut manifest = Vec::new();
-        let mut diagnostics = Vec::new();
-        plugin_driver
-          .compilation_hooks
-          .render_manifest
-          .call(self, &chunk.ukey, &mut manifest, &mut diagnostics)
+      .map(|chunk| async move {
+        let (manifest, diagnostics) = compilation
+          .old_cache
+          .create_chunk_assets_occasion
+          .use_cache(compilation, chunk, || async move {
+            let mut manifest = Vec::new();
+            let mut diagnostics = Vec::new();
+            plugin_driver
+              .compilation_hooks
+              .render_manifest
+              .call(compilation, &chunk.ukey, &mut manifest, &mut diagnostics)
+              .await?;
+            Ok((manifest, diagnostics))
+          })
           .await?;
 
         Ok((chunk.ukey, manifest, diagnostics))
@@ -1090,6 +1196,12 @@ impl Compilation {
           .map(|&module| Mutation::ModuleBuild { module }),
       );
     }
+    // assets emitted by loaders are not part of the cached loader results
+    for module in &built_modules {
+      if self.module_assets.contains_key(module) {
+        self.old_cache.build_module_occasion.remove(module);
+      }
+    }
     self.built_modules.extend(built_modules);
 
     let start = logger.time("finish modules");
@@ -1199,6 +1311,11 @@ impl Compilation {
       plugin_driver.compilation_hooks.optimize_chunks.call(self)?,
       Some(true)
     ) {}
+    plugin_driver
+      .compilation_hooks
+      .after_optimize_chunks
+      .call(self)
+      .await?;
 
     logger.time_end(start);
 
@@ -1217,11 +1334,18 @@ impl Compilation {
     logger.time_end(start);
 
     let start = logger.time("module ids");
+    self.revive_module_ids();
+    plugin_driver
+      .compilation_hooks
+      .before_module_ids
+      .call(self)?;
     plugin_driver.compilation_hooks.module_ids.call(self)?;
     logger.time_end(start);
 
     let start = logger.time("chunk ids");
+    self.revive_chunk_ids();
     plugin_driver.compilation_hooks.chunk_ids.call(self)?;
+    self.record_ids();
 
     logger.time_end(start);
 
@@ -1268,6 +1392,11 @@ impl Compilation {
       self.get_module_graph().modules().keys().copied().collect()
     };
     self.code_generation(code_generation_modules)?;
+    plugin_driver
+      .compilation_hooks
+      .after_code_generation
+      .call(self)
+      .await?;
     logger.time_end(start);
 
     let start = logger.time("runtime requirements");
@@ -2082,6 +2211,10 @@ pub struct AssetInfo {
   pub extras: serde_json::Map<String, serde_json::Value>,
   /// whether this asset is over the size limit
   pub is_over_size_limit: Option<bool>,
+  /// the gzip compressed size of this asset, computed when the size limits are checked with gzip
+  pub gzip_size: Option<f64>,
+  /// the brotli compressed size of this asset, computed when the size limits are checked with brotli
+  pub brotli_size: Option<f64>,
 }
 
 impl AssetInfo {
@@ -2174,12 +2307,18 @@ impl AssetInfo {
       .hot_module_replacement
       .or(self.hot_module_replacement);
     self.is_over_size_limit = another.is_over_size_limit.or(self.is_over_size_limit);
+    self.gzip_size = another.gzip_size.or(self.gzip_size);
+    self.brotli_size = another.brotli_size.or(self.brotli_size);
   }
 }
 
 #[derive(Debug, Default, Clone)]
 pub struct AssetInfoRelated {
   pub source_map: Option<String>,
+  /// the precompressed siblings of the asset
+  pub gzipped: Option<String>,
+  pub brotli_compressed: Option<String>,
+  pub zstd_compressed: Option<String>,
 }
 
 impl AssetInfoRelated {
@@ -2187,6 +2326,27 @@ impl AssetInfoRelated {
     if let Some(source_map) = another.source_map {
       self.source_map = Some(source_map);
     }
+    if let Some(gzipped) = another.gzipped {
+      self.gzipped = Some(gzipped);
+    }
+    if let Some(brotli_compressed) = another.brotli_compressed {
+      self.brotli_compressed = Some(brotli_compressed);
+    }
+    if let Some(zstd_compressed) = another.zstd_compressed {
+      self.zstd_compressed = Some(zstd_compressed);
+    }
+  }
+
+  /// Returns the related assets keyed by the type of relation, in the same order as webpack.
+  pub fn iter(&self) -> impl Iterator<Item = (&'static str, &String)> {
+    [
+      ("sourceMap", &self.source_map),
+      ("gzipped", &self.gzipped),
+      ("brotliCompressed", &self.brotli_compressed),
+      ("zstdCompressed", &self.zstd_compressed),
+    ]
+    .into_iter()
+    .filter_map(|(name, value)| Some((name, value.as_ref()?)))
   }
 }
 
@@ -18,6 +18,30 @@ impl Compiler {
     changed_files: std::collections::HashSet<String>,
     deleted_files: std::collections::HashSet<String>,
   ) -> Result<()> {
+    let result = self.rebuild_inner(changed_files, deleted_files).await;
+    self.finish_build(result).await
+  }
+
+  async fn rebuild_inner(
+    &mut self,
+    changed_files: std::collections::HashSet<String>,
+    deleted_files: std::collections::HashSet<String>,
+  ) -> Result<()> {
+    // every rebuild is triggered by changes, either from the javascript watcher or from
+    // [crate::Watching], so `invalid` is called here for both of them
+    let file_name = changed_files.iter().chain(deleted_files.iter()).min();
+    self
+      .plugin_driver
+      .compiler_hooks
+      .invalid
+      .call(file_name.map(|f| f.as_str()))?;
+    self
+      .plugin_driver
+      .compiler_hooks
+      .watch_run
+      .call(&changed_files, &deleted_files)
+      .await?;
+
     let old = self.compilation.get_stats();
     let old_hash = self.compilation.hash.clone();
 
@@ -54,6 +78,13 @@ impl Compiler {
       let mut removed_files = HashSet::default();
       removed_files.extend(deleted_files.iter().map(PathBuf::from));
 
+      // the caller may not watch every dependency, also take changes found by the snapshot
+      let (snapshot_modified_files, snapshot_removed_files) = self
+        .snapshot
+        .check(&self.options.snapshot, self.input_filesystem.as_ref());
+      modified_files.extend(snapshot_modified_files);
+      removed_files.extend(snapshot_removed_files);
+
       let mut all_files = modified_files.clone();
       all_files.extend(removed_files.clone());
 
@@ -133,8 +164,11 @@ impl Compiler {
       // FOR BINDING SAFETY:
       // Update `compilation` for each rebuild.
       // Make sure `thisCompilation` hook was called before any other hooks that leverage `JsCompilation`.
+      let id_records = self.compilation.id_records.take();
       fast_set(&mut self.compilation, new_compilation);
+      self.compilation.id_records = self.read_records(id_records)?;
       self.compile().await?;
+      self.take_snapshot();
 
       self.old_cache.begin_idle();
     }
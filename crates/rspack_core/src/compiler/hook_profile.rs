@@ -0,0 +1,207 @@
+use std::time::Duration;
+
+use rspack_hook::TapCall;
+use rspack_tracing::chrome::{to_trace_json, TraceEvent};
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::{Compilation, Logger};
+
+/// The calls of the taps during a compilation, which are recorded when `profile` is enabled.
+#[derive(Debug, Default)]
+pub struct HookProfile {
+  calls: Vec<TapCall>,
+}
+
+/// The calls of a tap aggregated by plugin, hook and stage.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TapProfile {
+  pub plugin: String,
+  pub hook: &'static str,
+  pub stage: i32,
+  pub duration: Duration,
+  pub calls: usize,
+}
+
+impl HookProfile {
+  pub fn new(calls: Vec<TapCall>) -> Self {
+    Self { calls }
+  }
+
+  pub fn calls(&self) -> &[TapCall] {
+    &self.calls
+  }
+
+  /// Returns the taps sorted by their total duration, the slowest first.
+  pub fn taps(&self) -> Vec<TapProfile> {
+    let mut taps: HashMap<(&str, &'static str, i32), TapProfile> = HashMap::default();
+    for call in &self.calls {
+      let tap = taps
+        .entry((call.plugin.as_str(), call.hook, call.stage))
+        .or_insert_with(|| TapProfile {
+          plugin: call.plugin.clone(),
+          hook: call.hook,
+          stage: call.stage,
+          duration: Duration::ZERO,
+          calls: 0,
+        });
+      tap.duration += call.duration;
+      tap.calls += 1;
+    }
+    let mut taps = taps.into_values().collect::<Vec<_>>();
+    taps.sort_by(|a, b| {
+      b.duration
+        .cmp(&a.duration)
+        .then_with(|| (&a.plugin, a.hook, a.stage).cmp(&(&b.plugin, b.hook, b.stage)))
+    });
+    taps
+  }
+
+  /// Exports the calls as Chrome trace events, the timestamps are relative to the first call.
+  ///
+  /// Taps of different tasks run concurrently, so each call is an async event of its own.
+  pub fn to_chrome_trace(&self) -> String {
+    let Some(origin) = self.calls.iter().map(|call| call.start).min() else {
+      return to_trace_json(&[]);
+    };
+    let events = self
+      .calls
+      .iter()
+      .enumerate()
+      .map(|(id, call)| {
+        let mut args = serde_json::Map::new();
+        args.insert("hook".into(), call.hook.into());
+        args.insert("stage".into(), call.stage.into());
+        TraceEvent {
+          id: id as u64,
+          name: format!("{}::{}", call.plugin, call.hook),
+          category: String::from("hook"),
+          ts: (call.start - origin).as_nanos() as f64 / 1000.0,
+          dur: call.duration.as_nanos() as f64 / 1000.0,
+          args,
+        }
+      })
+      .collect::<Vec<_>>();
+    to_trace_json(&events)
+  }
+
+  /// Logs the taps to the stats logging of the compilation, the slowest first.
+  pub(crate) fn log_summary(&self, compilation: &Compilation) {
+    let taps = self.taps();
+    if taps.is_empty() {
+      return;
+    }
+    let logger = compilation.get_logger("rspack.HookProfile");
+    logger.group(format!("{} taps", taps.len()));
+    for tap in taps {
+      logger.log(format!(
+        "{} {} (stage {}): {:.3} ms, {} call(s)",
+        tap.plugin,
+        tap.hook,
+        tap.stage,
+        tap.duration.as_secs_f64() * 1000.0,
+        tap.calls
+      ));
+    }
+    logger.group_end();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::Instant;
+
+  use super::*;
+
+  fn call(plugin: &str, hook: &'static str, start: Instant, millis: u64) -> TapCall {
+    TapCall {
+      plugin: plugin.to_string(),
+      hook,
+      stage: 0,
+      start,
+      duration: Duration::from_millis(millis),
+    }
+  }
+
+  #[test]
+  fn hook_profile() {
+    let start = Instant::now();
+    let profile = HookProfile::new(vec![
+      call("APlugin", "CompilationProcessAssets", start, 1),
+      call(
+        "BPlugin",
+        "CompilerMake",
+        start + Duration::from_millis(1),
+        2,
+      ),
+      call(
+        "APlugin",
+        "CompilationProcessAssets",
+        start + Duration::from_millis(3),
+        2,
+      ),
+    ]);
+
+    assert_eq!(
+      profile.taps(),
+      vec![
+        TapProfile {
+          plugin: "APlugin".to_string(),
+          hook: "CompilationProcessAssets",
+          stage: 0,
+          duration: Duration::from_millis(3),
+          calls: 2,
+        },
+        TapProfile {
+          plugin: "BPlugin".to_string(),
+          hook: "CompilerMake",
+          stage: 0,
+          duration: Duration::from_millis(2),
+          calls: 1,
+        },
+      ]
+    );
+
+    let trace: serde_json::Value =
+      serde_json::from_str(&profile.to_chrome_trace()).expect("should be valid json");
+    let events = trace["traceEvents"].as_array().expect("should have events");
+    assert_eq!(events.len(), 6);
+    assert_eq!(events[2]["name"], "BPlugin::CompilerMake");
+    assert_eq!(events[2]["ph"], "b");
+    assert_eq!(events[2]["ts"], 1000.0);
+    assert_eq!(events[3]["ph"], "e");
+    assert_eq!(events[3]["ts"], 3000.0);
+    assert_eq!(events[2]["id"], events[3]["id"]);
+  }
+
+  #[test]
+  fn hook_profile_overlapping_calls() {
+    // build module taps of two modules running in concurrent tasks, which overlap without nesting
+    let start = Instant::now();
+    let profile = HookProfile::new(vec![
+      call("APlugin", "CompilationBuildModule", start, 3),
+      call(
+        "APlugin",
+        "CompilationBuildModule",
+        start + Duration::from_millis(1),
+        3,
+      ),
+    ]);
+
+    let trace: serde_json::Value =
+      serde_json::from_str(&profile.to_chrome_trace()).expect("should be valid json");
+    let events = trace["traceEvents"].as_array().expect("should have events");
+    let span = |id: &serde_json::Value| {
+      let ts = |ph: &str| {
+        events
+          .iter()
+          .find(|event| event["id"] == *id && event["ph"] == ph)
+          .and_then(|event| event["ts"].as_f64())
+          .expect("should have event")
+      };
+      (ts("b"), ts("e"))
+    };
+    assert_ne!(events[0]["id"], events[2]["id"]);
+    assert_eq!(span(&events[0]["id"]), (0.0, 3000.0));
+    assert_eq!(span(&events[2]["id"]), (1000.0, 4000.0));
+  }
+}
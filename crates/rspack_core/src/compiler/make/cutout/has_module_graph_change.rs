@@ -3,16 +3,29 @@ use rustc_hash::FxHashSet as HashSet;
 use swc_core::atoms::Atom;
 
 use super::super::MakeArtifact;
-use crate::{AsyncDependenciesBlockIdentifier, GroupOptions, ModuleGraph, ModuleIdentifier};
+use crate::{
+  AsyncDependenciesBlockIdentifier, DependenciesBlock, DependencyId, DependencyType, GroupOptions,
+  ModuleGraph, ModuleIdentifier,
+};
 
 #[derive(Debug, Default, Eq, PartialEq, Clone)]
 struct ModuleDeps {
   // child module identifier of current module
-  child_modules: IdentifierMap<HashSet<Atom>>,
-  // blocks in current module
+  child_modules: IdentifierMap<ChildModuleDeps>,
+  // blocks in current module, including nested blocks
   module_blocks: Vec<(AsyncDependenciesBlockIdentifier, Option<GroupOptions>)>,
 }
 
+// how current module depends on a child module
+#[derive(Debug, Default, Eq, PartialEq, Clone)]
+struct ChildModuleDeps {
+  // dependency types with the block which contains the dependency,
+  // e.g. a static import changed to `import()` keeps the child module but changes the chunks
+  dependencies: HashSet<(DependencyType, Option<AsyncDependenciesBlockIdentifier>)>,
+  // referenced ids, which decide the active state of the connections
+  ids: HashSet<Atom>,
+}
+
 impl ModuleDeps {
   fn from_module(module_graph: &ModuleGraph, module_identifier: &ModuleIdentifier) -> Self {
     let mut res = Self::default();
@@ -20,9 +33,26 @@ impl ModuleDeps {
       .module_by_identifier(module_identifier)
       .expect("should have module");
 
-    let deps = module.get_dependencies();
-    let mut child_deps: IdentifierMap<HashSet<Atom>> = Default::default();
+    res.add_dependencies(module_graph, module.get_dependencies(), None);
+    for block_id in module_graph.get_all_blocks(module_identifier) {
+      let block = module_graph
+        .block_by_id(&block_id)
+        .expect("should have block");
+      res.add_dependencies(module_graph, block.get_dependencies(), Some(block_id));
+      res
+        .module_blocks
+        .push((block_id, block.get_group_options().cloned()));
+    }
+
+    res
+  }
 
+  fn add_dependencies(
+    &mut self,
+    module_graph: &ModuleGraph,
+    deps: &[DependencyId],
+    block: Option<AsyncDependenciesBlockIdentifier>,
+  ) {
     for dep_id in deps {
       let dep = module_graph
         .dependency_by_id(dep_id)
@@ -31,29 +61,22 @@ impl ModuleDeps {
       let Some(conn) = module_graph.connection_by_dependency_id(dep_id) else {
         continue;
       };
-      let identifier = conn.module_identifier();
-      let ids = child_deps.entry(*identifier).or_default();
+      let child = self
+        .child_modules
+        .entry(*conn.module_identifier())
+        .or_default();
+      let dependency_type = *dep.dependency_type();
+      child.dependencies.insert((dependency_type, block));
 
       if matches!(
-        dep.dependency_type(),
-        crate::DependencyType::EsmImportSpecifier
+        dependency_type,
+        DependencyType::EsmImportSpecifier
+          | DependencyType::EsmExportImportedSpecifier
+          | DependencyType::CjsExportRequire
       ) {
-        let dep_ids = dep.get_ids(module_graph);
-        ids.extend(dep_ids.into_iter());
+        child.ids.extend(dep.get_ids(module_graph));
       }
     }
-
-    res.child_modules = child_deps;
-    for block_id in module.get_blocks() {
-      let block = module_graph
-        .block_by_id(block_id)
-        .expect("should have block");
-      res
-        .module_blocks
-        .push((*block_id, block.get_group_options().cloned()));
-    }
-
-    res
   }
 }
 
@@ -359,4 +382,45 @@ mod t {
     let module_deps_3 = ModuleDeps::from_module(&mg, &module_orig_id);
     assert_ne!(module_deps_3, module_deps_1);
   }
+
+  #[test]
+  #[allow(unused_results, clippy::unwrap_used)]
+  fn test_module_deps_ids_change() {
+    let mut partial = ModuleGraphPartial::default();
+    let mut mg = ModuleGraph::new(vec![], Some(&mut partial));
+
+    let dep1 = Box::new(TestDep::new(vec!["foo"]));
+    let dep1_id = *dep1.id();
+    let module_orig = Box::new(TestModule::new("app", vec![dep1_id]));
+    let module_orig_id = module_orig.identifier();
+    let module1 = Box::new(TestModule::new("lib_foo", vec![]));
+    let module1_id = module1.id;
+
+    mg.add_module(module_orig);
+    mg.add_module_graph_module(ModuleGraphModule::new(module_orig_id, ExportsInfo::new()));
+    mg.add_module(module1);
+    mg.add_module_graph_module(ModuleGraphModule::new(module1_id, ExportsInfo::new()));
+    mg.add_dependency(dep1);
+    mg.set_resolved_module(Some(module_orig_id), dep1_id, module1_id)
+      .unwrap();
+
+    let module_deps_1 = ModuleDeps::from_module(&mg, &module_orig_id);
+
+    // import another export from the same child module
+    let dep2 = Box::new(TestDep::new(vec!["bar"]));
+    let dep2_id = *dep2.id();
+    let module_orig: &mut TestModule = mg
+      .module_by_identifier_mut(&module_orig_id)
+      .expect("should have module")
+      .downcast_mut()
+      .expect("should be test module");
+    module_orig.add_dependency_id(dep2_id);
+    mg.add_dependency(dep2);
+    mg.set_resolved_module(Some(module_orig_id), dep2_id, module1_id)
+      .unwrap();
+
+    let module_deps_2 = ModuleDeps::from_module(&mg, &module_orig_id);
+    assert_eq!(module_deps_2.child_modules.len(), 1);
+    assert_ne!(module_deps_1, module_deps_2);
+  }
 }
@@ -78,6 +78,7 @@ impl Task<MakeTaskContext> for AddTask {
       compiler_options: context.compiler_options.clone(),
       plugin_driver: context.plugin_driver.clone(),
       fs: context.fs.clone(),
+      old_cache: context.old_cache.clone(),
     })])
   }
 }
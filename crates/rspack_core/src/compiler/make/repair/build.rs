@@ -5,6 +5,7 @@ use rspack_fs::ReadableFileSystem;
 
 use super::{process_dependencies::ProcessDependenciesTask, MakeTaskContext};
 use crate::{
+  old_cache::Cache as OldCache,
   utils::task_loop::{Task, TaskResult, TaskType},
   AsyncDependenciesBlock, BoxDependency, BuildContext, BuildResult, CompilerOptions,
   DependencyParents, Module, ModuleProfile, ResolverFactory, SharedPluginDriver,
@@ -18,6 +19,7 @@ pub struct BuildTask {
   pub compiler_options: Arc<CompilerOptions>,
   pub plugin_driver: SharedPluginDriver,
   pub fs: Arc<dyn ReadableFileSystem>,
+  pub old_cache: Arc<OldCache>,
 }
 
 #[async_trait::async_trait]
@@ -33,6 +35,7 @@ impl Task<MakeTaskContext> for BuildTask {
       current_profile,
       mut module,
       fs,
+      old_cache,
     } = *self;
     if let Some(current_profile) = &current_profile {
       current_profile.mark_building_start();
@@ -51,16 +54,32 @@ impl Task<MakeTaskContext> for BuildTask {
           resolver_factory: resolver_factory.clone(),
           plugin_driver: plugin_driver.clone(),
           fs: fs.clone(),
+          old_cache,
         },
         None,
       )
       .await;
 
-    plugin_driver
-      .compilation_hooks
-      .succeed_module
-      .call(&mut module)
-      .await?;
+    // like webpack, `failed_module` is only called when `build` itself fails, modules that
+    // are built with error diagnostics (e.g. loader errors) still call `succeed_module`
+    match &result {
+      Err(e) => {
+        let diagnostic = Diagnostic::error("ModuleBuildError".to_string(), e.to_string())
+          .with_module_identifier(Some(module.identifier()));
+        plugin_driver
+          .compilation_hooks
+          .failed_module
+          .call(&mut module, &diagnostic)
+          .await?;
+      }
+      Ok(_) => {
+        plugin_driver
+          .compilation_hooks
+          .succeed_module
+          .call(&mut module)
+          .await?;
+      }
+    }
 
     let build_result = result.map(|t| {
       let diagnostics = module
@@ -155,11 +174,16 @@ impl Task<MakeTaskContext> for BuildResultTask {
         module_graph.add_dependency(dependency);
       }
       if let Some(current_block) = current_block {
-        module.add_block_id(current_block.identifier());
         module_graph.add_block(current_block);
       }
       blocks
     };
+    // nested blocks are kept in the block ids of their parent block
+    let module_block_ids = build_result
+      .blocks
+      .iter()
+      .map(|block| block.identifier())
+      .collect::<Vec<_>>();
     let blocks = handle_block(build_result.dependencies, build_result.blocks, None);
     queue.extend(blocks);
 
@@ -168,6 +192,9 @@ impl Task<MakeTaskContext> for BuildResultTask {
       let blocks = handle_block(dependencies, block.take_blocks(), Some(block));
       queue.extend(blocks);
     }
+    for block_id in module_block_ids {
+      module.add_block_id(block_id);
+    }
 
     {
       let mgm = module_graph
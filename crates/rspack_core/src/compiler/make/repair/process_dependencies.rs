@@ -35,7 +35,8 @@ impl Task<MakeTaskContext> for ProcessDependenciesTask {
       // FIXME: now only module/context dependency can put into resolve queue.
       // FIXME: should align webpack
       let resource_identifier = if let Some(module_dependency) = dependency.as_module_dependency() {
-        // TODO need implement more dependency `resource_identifier()`
+        // builtin module dependencies all have a `resource_identifier()`, the fallback is kept
+        // for dependencies of third party plugins
         // https://github.com/webpack/webpack/blob/main/lib/Compilation.js#L1621
         let id = if let Some(resource_identifier) = module_dependency.resource_identifier() {
           Cow::Borrowed(resource_identifier)
@@ -1,7 +1,10 @@
 mod compilation;
 mod hmr;
+mod hook_profile;
 mod make;
 mod module_executor;
+mod records;
+mod watching;
 use std::sync::Arc;
 
 use rspack_error::Result;
@@ -9,7 +12,7 @@ use rspack_fs::{
   AsyncNativeFileSystem, AsyncWritableFileSystem, NativeFileSystem, ReadableFileSystem,
 };
 use rspack_futures::FuturesResults;
-use rspack_hook::define_hook;
+use rspack_hook::{define_hook, Hook, HookProfiler};
 use rspack_paths::{Utf8Path, Utf8PathBuf};
 use rspack_sources::BoxSource;
 use rustc_hash::FxHashMap as HashMap;
@@ -17,15 +20,21 @@ use tracing::instrument;
 
 pub use self::compilation::*;
 pub use self::hmr::{collect_changed_modules, CompilationRecords};
+pub use self::hook_profile::{HookProfile, TapProfile};
 pub use self::module_executor::{ExecuteModuleId, ExecutedRuntimeModule, ModuleExecutor};
+pub use self::records::{ChunkIdRecords, IdRecords, ModuleIdRecords};
+pub use self::watching::{FsWatcherOptions, Watching};
 use crate::incremental::IncrementalPasses;
 use crate::old_cache::Cache as OldCache;
 use crate::{
-  fast_set, include_hash, BoxPlugin, CompilerOptions, Logger, PluginDriver, ResolverFactory,
-  SharedPluginDriver,
+  fast_set, include_hash, BoxPlugin, CacheOptions, CompilerOptions, Logger, PluginDriver,
+  ResolverFactory, SharedPluginDriver, Snapshot,
 };
 use crate::{ContextModuleFactory, NormalModuleFactory};
 
+define_hook!(CompilerWatchRun: AsyncSeries(modified_files: &std::collections::HashSet<String>, removed_files: &std::collections::HashSet<String>));
+define_hook!(CompilerInvalid: SyncSeries(file_name: Option<&str>));
+define_hook!(CompilerBeforeCompile: AsyncSeries(params: &mut CompilationParams));
 // should be SyncHook, but rspack need call js hook
 define_hook!(CompilerThisCompilation: AsyncSeries(compilation: &mut Compilation, params: &mut CompilationParams));
 // should be SyncHook, but rspack need call js hook
@@ -33,22 +42,50 @@ define_hook!(CompilerCompilation: AsyncSeries(compilation: &mut Compilation, par
 // should be AsyncParallelHook
 define_hook!(CompilerMake: AsyncSeries(compilation: &mut Compilation));
 define_hook!(CompilerFinishMake: AsyncSeries(compilation: &mut Compilation));
+define_hook!(CompilerAfterCompile: AsyncSeries(compilation: &mut Compilation));
 // should be SyncBailHook, but rspack need call js hook
 define_hook!(CompilerShouldEmit: AsyncSeriesBail(compilation: &mut Compilation) -> bool);
 define_hook!(CompilerEmit: AsyncSeries(compilation: &mut Compilation));
 define_hook!(CompilerAfterEmit: AsyncSeries(compilation: &mut Compilation));
 define_hook!(CompilerAssetEmitted: AsyncSeries(compilation: &Compilation, filename: &str, info: &AssetEmittedInfo));
+define_hook!(CompilerDone: AsyncSeries(compilation: &mut Compilation));
+define_hook!(CompilerFailed: AsyncSeries(error: &rspack_error::Error));
 
 #[derive(Debug, Default)]
 pub struct CompilerHooks {
+  pub watch_run: CompilerWatchRunHook,
+  pub invalid: CompilerInvalidHook,
+  pub before_compile: CompilerBeforeCompileHook,
   pub this_compilation: CompilerThisCompilationHook,
   pub compilation: CompilerCompilationHook,
   pub make: CompilerMakeHook,
   pub finish_make: CompilerFinishMakeHook,
+  pub after_compile: CompilerAfterCompileHook,
   pub should_emit: CompilerShouldEmitHook,
   pub emit: CompilerEmitHook,
   pub after_emit: CompilerAfterEmitHook,
   pub asset_emitted: CompilerAssetEmittedHook,
+  pub done: CompilerDoneHook,
+  pub failed: CompilerFailedHook,
+}
+
+impl CompilerHooks {
+  pub(crate) fn set_profiler(&mut self, profiler: &HookProfiler) {
+    self.watch_run.set_profiler(profiler.clone());
+    self.invalid.set_profiler(profiler.clone());
+    self.before_compile.set_profiler(profiler.clone());
+    self.this_compilation.set_profiler(profiler.clone());
+    self.compilation.set_profiler(profiler.clone());
+    self.make.set_profiler(profiler.clone());
+    self.finish_make.set_profiler(profiler.clone());
+    self.after_compile.set_profiler(profiler.clone());
+    self.should_emit.set_profiler(profiler.clone());
+    self.emit.set_profiler(profiler.clone());
+    self.after_emit.set_profiler(profiler.clone());
+    self.asset_emitted.set_profiler(profiler.clone());
+    self.done.set_profiler(profiler.clone());
+    self.failed.set_profiler(profiler.clone());
+  }
 }
 
 #[derive(Debug)]
@@ -62,6 +99,8 @@ pub struct Compiler {
   pub resolver_factory: Arc<ResolverFactory>,
   pub loader_resolver_factory: Arc<ResolverFactory>,
   pub old_cache: Arc<OldCache>,
+  /// snapshot of the dependencies of the last compilation
+  pub snapshot: Snapshot,
   /// emitted asset versions
   /// the key of HashMap is filename, the value of HashMap is version
   pub emitted_asset_versions: HashMap<String, String>,
@@ -130,6 +169,7 @@ impl Compiler {
       resolver_factory,
       loader_resolver_factory,
       old_cache,
+      snapshot: Default::default(),
       emitted_asset_versions: Default::default(),
       input_filesystem,
     }
@@ -142,12 +182,18 @@ impl Compiler {
 
   #[instrument(name = "build", skip_all)]
   pub async fn build(&mut self) -> Result<()> {
+    let result = self.build_inner().await;
+    self.finish_build(result).await
+  }
+
+  async fn build_inner(&mut self) -> Result<()> {
     self.old_cache.end_idle();
     // TODO: clear the outdated cache entries in resolver,
     // TODO: maybe it's better to use external entries.
     self.plugin_driver.resolver_factory.clear_cache();
 
     let module_executor = ModuleExecutor::default();
+    let id_records = self.compilation.id_records.take();
     fast_set(
       &mut self.compilation,
       Compilation::new(
@@ -164,16 +210,70 @@ impl Compiler {
         self.input_filesystem.clone(),
       ),
     );
+    self.compilation.id_records = self.read_records(id_records)?;
 
     self.compile().await?;
+    self.take_snapshot();
     self.old_cache.begin_idle();
     self.compile_done().await?;
     Ok(())
   }
 
+  /// Calls the `done` hook when the build succeeds, or the `failed` hook with the error.
+  async fn finish_build(&mut self, result: Result<()>) -> Result<()> {
+    self.finish_hook_profile();
+    match result {
+      Ok(()) => {
+        self
+          .plugin_driver
+          .compiler_hooks
+          .done
+          .call(&mut self.compilation)
+          .await
+      }
+      Err(e) => {
+        self.plugin_driver.compiler_hooks.failed.call(&e).await?;
+        Err(e)
+      }
+    }
+  }
+
+  /// Snapshot the dependencies of the current compilation, so the next rebuild can find out
+  /// which files changed by itself. Snapshots are only used together with the cache.
+  #[instrument(name = "take_snapshot", skip_all)]
+  fn take_snapshot(&mut self) {
+    if matches!(self.options.cache, CacheOptions::Disabled) {
+      return;
+    }
+    let options = &self.options.snapshot;
+    let fs = self.input_filesystem.as_ref();
+    let mut snapshot = Snapshot::default();
+    let (file_dependencies, _, _) = self.compilation.file_dependencies();
+    snapshot.add_paths(file_dependencies, options.module, options, fs);
+    let (context_dependencies, _, _) = self.compilation.context_dependencies();
+    snapshot.add_paths(context_dependencies, options.module, options, fs);
+    let (missing_dependencies, _, _) = self.compilation.missing_dependencies();
+    snapshot.add_paths(missing_dependencies, options.resolve, options, fs);
+    let (build_dependencies, _, _) = self.compilation.build_dependencies();
+    snapshot.add_paths(build_dependencies, options.build_dependencies, options, fs);
+    self.snapshot = snapshot;
+  }
+
   #[instrument(name = "compile", skip_all)]
   async fn compile(&mut self) -> Result<()> {
+    if self.options.profile {
+      let profiler = &self.plugin_driver.hook_profiler;
+      // drop the calls recorded outside of this compilation
+      profiler.take_calls();
+      profiler.enable(true);
+    }
     let mut compilation_params = self.new_compilation_params();
+    self
+      .plugin_driver
+      .compiler_hooks
+      .before_compile
+      .call(&mut compilation_params)
+      .await?;
     // FOR BINDING SAFETY:
     // Make sure `thisCompilation` hook was called for each `JsCompilation` update before any access to it.
     // `JsCompiler` tapped `thisCompilation` to update the `JsCompilation` on the JavaScript side.
@@ -231,7 +331,12 @@ impl Compiler {
       .compilation
       .extend_diagnostics(plugin_driver_diagnostics);
 
-    Ok(())
+    self
+      .plugin_driver
+      .compiler_hooks
+      .after_compile
+      .call(&mut self.compilation)
+      .await
   }
 
   #[instrument(name = "compile_done", skip_all)]
@@ -254,9 +359,25 @@ impl Compiler {
     self.emit_assets().await?;
     logger.time_end(start);
 
+    let start = logger.time("emitRecords");
+    self.emit_records().await?;
+    logger.time_end(start);
+
     Ok(())
   }
 
+  /// Stops recording the tap calls, collects the calls of this compilation and logs a summary of
+  /// them to the stats logging.
+  fn finish_hook_profile(&mut self) {
+    if !self.options.profile {
+      return;
+    }
+    let profiler = &self.plugin_driver.hook_profiler;
+    profiler.enable(false);
+    self.compilation.hook_profile = HookProfile::new(profiler.take_calls());
+    self.compilation.hook_profile.log_summary(&self.compilation);
+  }
+
   #[instrument(name = "emit_assets", skip_all)]
   pub async fn emit_assets(&mut self) -> Result<()> {
     if self.options.output.clean {
@@ -422,6 +543,7 @@ impl Compiler {
         self.options.clone(),
         self.loader_resolver_factory.clone(),
         self.plugin_driver.clone(),
+        self.old_cache.clone(),
       )),
       context_module_factory: Arc::new(ContextModuleFactory::new(
         self.resolver_factory.clone(),
@@ -444,3 +566,323 @@ pub struct AssetEmittedInfo {
   pub output_path: Utf8PathBuf,
   pub target_path: Utf8PathBuf,
 }
+
+#[cfg(test)]
+mod tests {
+  use std::{
+    collections::HashSet,
+    sync::{
+      atomic::{AtomicBool, Ordering},
+      Mutex,
+    },
+  };
+
+  use rspack_error::error;
+  use rspack_hash::{HashDigest, HashFunction, HashSalt};
+  use rspack_hook::{plugin, plugin_hook};
+
+  use super::*;
+  use crate::{
+    incremental::IncrementalPasses, ApplyContext, ChunkLoading, CompilationProcessAssets,
+    CompilerOptions, CrossOriginLoading, Environment, Experiments, Filename, Mode, ModuleOptions,
+    Optimization, OutputOptions, PathInfo, Plugin, PluginContext, PublicPath, Resolve,
+    RspackFuture, SnapshotOptions, StatsOptions, WasmLoading,
+  };
+
+  #[plugin]
+  #[derive(Debug)]
+  struct RecordPlugin {
+    calls: Arc<Mutex<Vec<String>>>,
+    fail_finish_make: AtomicBool,
+  }
+
+  impl RecordPlugin {
+    fn record(&self, call: impl Into<String>) {
+      self.calls.lock().expect("should lock").push(call.into());
+    }
+  }
+
+  #[plugin_hook(CompilerWatchRun for RecordPlugin)]
+  async fn watch_run(
+    &self,
+    modified_files: &std::collections::HashSet<String>,
+    removed_files: &std::collections::HashSet<String>,
+  ) -> Result<()> {
+    self.record(format!(
+      "watch_run {} {}",
+      modified_files.len(),
+      removed_files.len()
+    ));
+    Ok(())
+  }
+
+  #[plugin_hook(CompilerInvalid for RecordPlugin)]
+  fn invalid(&self, file_name: Option<&str>) -> Result<()> {
+    self.record(format!("invalid {}", file_name.unwrap_or_default()));
+    Ok(())
+  }
+
+  #[plugin_hook(CompilerBeforeCompile for RecordPlugin)]
+  async fn before_compile(&self, _params: &mut CompilationParams) -> Result<()> {
+    self.record("before_compile");
+    Ok(())
+  }
+
+  #[plugin_hook(CompilerMake for RecordPlugin)]
+  async fn make(&self, _compilation: &mut Compilation) -> Result<()> {
+    self.record("make");
+    Ok(())
+  }
+
+  #[plugin_hook(CompilerFinishMake for RecordPlugin)]
+  async fn finish_make(&self, _compilation: &mut Compilation) -> Result<()> {
+    self.record("finish_make");
+    if self.fail_finish_make.load(Ordering::Relaxed) {
+      return Err(error!("build failed"));
+    }
+    Ok(())
+  }
+
+  #[plugin_hook(CompilationProcessAssets for RecordPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONAL)]
+  async fn process_assets_additional(&self, _compilation: &mut Compilation) -> Result<()> {
+    self.record("process_assets additional");
+    Ok(())
+  }
+
+  #[plugin_hook(CompilationOptimizeAssets for RecordPlugin)]
+  async fn optimize_assets(&self, _compilation: &mut Compilation) -> Result<()> {
+    self.record("optimize_assets");
+    Ok(())
+  }
+
+  #[plugin_hook(CompilationProcessAssets for RecordPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_REPORT)]
+  async fn process_assets_report(&self, _compilation: &mut Compilation) -> Result<()> {
+    self.record("process_assets report");
+    Ok(())
+  }
+
+  #[plugin_hook(CompilerAfterCompile for RecordPlugin)]
+  async fn after_compile(&self, _compilation: &mut Compilation) -> Result<()> {
+    self.record("after_compile");
+    Ok(())
+  }
+
+  #[plugin_hook(CompilerEmit for RecordPlugin)]
+  async fn emit(&self, _compilation: &mut Compilation) -> Result<()> {
+    self.record("emit");
+    Ok(())
+  }
+
+  #[plugin_hook(CompilerAfterEmit for RecordPlugin)]
+  async fn after_emit(&self, _compilation: &mut Compilation) -> Result<()> {
+    self.record("after_emit");
+    Ok(())
+  }
+
+  #[plugin_hook(CompilerDone for RecordPlugin)]
+  async fn done(&self, _compilation: &mut Compilation) -> Result<()> {
+    self.record("done");
+    Ok(())
+  }
+
+  #[plugin_hook(CompilerFailed for RecordPlugin)]
+  async fn failed(&self, error: &rspack_error::Error) -> Result<()> {
+    self.record(format!("failed {error}"));
+    Ok(())
+  }
+
+  impl Plugin for RecordPlugin {
+    fn name(&self) -> &'static str {
+      "RecordPlugin"
+    }
+
+    fn apply(
+      &self,
+      ctx: PluginContext<&mut ApplyContext>,
+      _options: &CompilerOptions,
+    ) -> Result<()> {
+      let compiler_hooks = &mut *ctx.context.compiler_hooks;
+      compiler_hooks.invalid.tap(invalid::new(self));
+      compiler_hooks.watch_run.tap(watch_run::new(self));
+      compiler_hooks.before_compile.tap(before_compile::new(self));
+      compiler_hooks.make.tap(make::new(self));
+      compiler_hooks.finish_make.tap(finish_make::new(self));
+      compiler_hooks.after_compile.tap(after_compile::new(self));
+      compiler_hooks.emit.tap(emit::new(self));
+      compiler_hooks.after_emit.tap(after_emit::new(self));
+      compiler_hooks.done.tap(done::new(self));
+      compiler_hooks.failed.tap(failed::new(self));
+      let compilation_hooks = &mut *ctx.context.compilation_hooks;
+      compilation_hooks
+        .process_assets
+        .tap(process_assets_additional::new(self));
+      compilation_hooks
+        .process_assets
+        .tap(process_assets_report::new(self));
+      compilation_hooks
+        .optimize_assets
+        .tap(optimize_assets::new(self));
+      Ok(())
+    }
+  }
+
+  fn options(context: &Utf8Path) -> CompilerOptions {
+    CompilerOptions {
+      context: context.as_str().into(),
+      output: OutputOptions {
+        path: context.join("dist"),
+        pathinfo: PathInfo::Bool(false),
+        clean: false,
+        public_path: PublicPath::Auto,
+        asset_module_filename: Filename::from("[hash][ext]".to_string()),
+        wasm_loading: WasmLoading::Disable,
+        webassembly_module_filename: "[hash].module.wasm".to_string().into(),
+        unique_name: "test".to_string(),
+        chunk_loading: ChunkLoading::Disable,
+        chunk_loading_global: "webpackChunktest".to_string(),
+        chunk_load_timeout: 120_000,
+        charset: false,
+        filename: Filename::from("[name].js".to_string()),
+        chunk_filename: Filename::from("[id].js".to_string()),
+        cross_origin_loading: CrossOriginLoading::Disable,
+        css_filename: Filename::from("[name].css".to_string()),
+        css_chunk_filename: Filename::from("[id].css".to_string()),
+        css_head_data_compression: false,
+        hot_update_main_filename: "[runtime].[fullhash].hot-update.json".to_string().into(),
+        hot_update_chunk_filename: "[id].[fullhash].hot-update.js".to_string().into(),
+        hot_update_global: "webpackHotUpdatetest".to_string(),
+        library: None,
+        enabled_library_types: None,
+        strict_module_error_handling: false,
+        global_object: "self".to_string(),
+        import_function_name: "import".to_string(),
+        import_meta_name: "import.meta".to_string(),
+        iife: true,
+        module: false,
+        trusted_types: None,
+        source_map_filename: "[file].map".to_string().into(),
+        hash_function: HashFunction::Xxhash64,
+        hash_digest: HashDigest::Hex,
+        hash_digest_length: 16,
+        hash_salt: HashSalt::None,
+        async_chunks: true,
+        worker_chunk_loading: ChunkLoading::Disable,
+        worker_wasm_loading: WasmLoading::Disable,
+        worker_public_path: String::new(),
+        script_type: String::new(),
+        environment: Environment {
+          r#const: Some(true),
+          arrow_function: Some(true),
+        },
+        compare_before_emit: true,
+      },
+      mode: Mode::None,
+      resolve: Resolve {
+        extensions: Some(vec![".js".to_string()]),
+        ..Default::default()
+      },
+      resolve_loader: Resolve {
+        extensions: Some(vec![".js".to_string()]),
+        ..Default::default()
+      },
+      module: ModuleOptions::default(),
+      stats: StatsOptions::default(),
+      snapshot: SnapshotOptions::default(),
+      cache: Default::default(),
+      experiments: Experiments {
+        layers: false,
+        incremental: IncrementalPasses::empty(),
+        top_level_await: false,
+        rspack_future: RspackFuture {},
+      },
+      node: None,
+      optimization: Optimization {
+        remove_available_modules: false,
+        side_effects: Default::default(),
+        provided_exports: false,
+        used_exports: Default::default(),
+        inner_graph: false,
+        mangle_exports: Default::default(),
+        concatenate_modules: false,
+      },
+      profile: false,
+      bail: false,
+      records_input_path: None,
+      records_output_path: None,
+      __references: Default::default(),
+    }
+  }
+
+  fn new_compiler(context: &Utf8Path, plugin: RecordPlugin) -> Compiler {
+    Compiler::new(
+      options(context),
+      vec![Box::new(plugin)],
+      vec![],
+      None,
+      None,
+      None,
+      None,
+    )
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn compiler_lifecycle_hooks() -> Result<()> {
+    let context = Utf8PathBuf::from(
+      std::env::temp_dir()
+        .join(format!("rspack-compiler-hooks-{}", std::process::id()))
+        .to_string_lossy()
+        .to_string(),
+    );
+    let compile_calls = [
+      "before_compile",
+      "make",
+      "finish_make",
+      "process_assets additional",
+      "optimize_assets",
+      "process_assets report",
+      "after_compile",
+      "emit",
+      "after_emit",
+      "done",
+    ];
+
+    let calls = Arc::new(Mutex::new(vec![]));
+    let mut compiler = new_compiler(
+      &context,
+      RecordPlugin::new_inner(calls.clone(), AtomicBool::new(false)),
+    );
+    compiler.build().await?;
+    assert_eq!(*calls.lock().expect("should lock"), compile_calls);
+
+    calls.lock().expect("should lock").clear();
+    compiler
+      .rebuild(
+        HashSet::from(["/src/index.js".to_string()]),
+        HashSet::default(),
+      )
+      .await?;
+    let mut rebuild_calls = vec!["invalid /src/index.js", "watch_run 1 0"];
+    rebuild_calls.extend(compile_calls);
+    assert_eq!(*calls.lock().expect("should lock"), rebuild_calls);
+
+    // a failed build calls `failed` instead of `done`
+    let calls = Arc::new(Mutex::new(vec![]));
+    let mut compiler = new_compiler(
+      &context,
+      RecordPlugin::new_inner(calls.clone(), AtomicBool::new(true)),
+    );
+    assert!(compiler.build().await.is_err());
+    assert_eq!(
+      *calls.lock().expect("should lock"),
+      vec![
+        "before_compile",
+        "make",
+        "finish_make",
+        "failed build failed"
+      ]
+    );
+
+    let _ = std::fs::remove_dir_all(&context);
+    Ok(())
+  }
+}
@@ -112,6 +112,7 @@ impl Task<MakeTaskContext> for ExecuteTask {
         name: Some("build time".into()),
         runtime: Some("runtime".into()),
         chunk_loading: Some(crate::ChunkLoading::Disable),
+        wasm_loading: None,
         async_chunks: Some(false),
         public_path,
         base_uri,
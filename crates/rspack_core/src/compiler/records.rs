@@ -0,0 +1,296 @@
+use std::{collections::BTreeMap, io::ErrorKind};
+
+use rspack_error::{error, Result};
+use rspack_paths::Utf8Path;
+use rspack_util::identifier::absolute_to_request;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use serde::{Deserialize, Serialize};
+
+use crate::{Chunk, ChunkUkey, Compilation, Compiler};
+
+/// The module and chunk ids read from `recordsInputPath` and written to `recordsOutputPath`,
+/// in the same format as the records of webpack.
+///
+/// Only numeric ids are recorded, named ids are already stable.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct IdRecords {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub modules: Option<ModuleIdRecords>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub chunks: Option<ChunkIdRecords>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleIdRecords {
+  #[serde(default)]
+  pub by_identifier: BTreeMap<String, u64>,
+  #[serde(default)]
+  pub used_ids: Vec<u64>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkIdRecords {
+  #[serde(default)]
+  pub by_name: BTreeMap<String, u64>,
+  #[serde(default)]
+  pub by_source: BTreeMap<String, u64>,
+  #[serde(default)]
+  pub used_ids: Vec<u64>,
+}
+
+impl Compiler {
+  /// Reads the records for the next compilation, records are only kept when
+  /// `recordsInputPath` or `recordsOutputPath` is set.
+  ///
+  /// Without `recordsInputPath` the records of the previous compilation are kept in memory,
+  /// so the ids are stable across rebuilds as well.
+  pub(super) fn read_records(&self, previous: Option<IdRecords>) -> Result<Option<IdRecords>> {
+    let Some(path) = &self.options.records_input_path else {
+      return Ok(
+        self
+          .options
+          .records_output_path
+          .is_some()
+          .then(|| previous.unwrap_or_default()),
+      );
+    };
+    let content = match self.input_filesystem.read(path.as_std_path()) {
+      Ok(content) => content,
+      Err(err) if err.kind() == ErrorKind::NotFound => {
+        return Ok(Some(previous.unwrap_or_default()))
+      }
+      Err(err) => return Err(error!("Failed to read records from {path}: {err}")),
+    };
+    serde_json::from_slice(&content)
+      .map(Some)
+      .map_err(|err| error!("Cannot parse records from {path}: {err}"))
+  }
+
+  pub(super) async fn emit_records(&self) -> Result<()> {
+    let (Some(path), Some(records)) = (
+      &self.options.records_output_path,
+      &self.compilation.id_records,
+    ) else {
+      return Ok(());
+    };
+    if let Some(dir) = path.parent() {
+      self.output_filesystem.create_dir_all(dir).await?;
+    }
+    let content = serde_json::to_string_pretty(records)
+      .map_err(|err| error!("Failed to serialize records: {err}"))?;
+    self
+      .output_filesystem
+      .write(path, content.as_bytes())
+      .await?;
+    Ok(())
+  }
+}
+
+impl Compilation {
+  /// Assigns the recorded ids to the modules without an id, before the ids plugins run.
+  pub(crate) fn revive_module_ids(&mut self) {
+    let Some(records) = self.id_records.as_ref().and_then(|r| r.modules.as_ref()) else {
+      return;
+    };
+    let context = self.options.context.as_path();
+    let module_graph = self.get_module_graph();
+    let mut used_ids = HashSet::default();
+    let mut revived = vec![];
+    for module in module_graph.modules().values() {
+      let module_identifier = module.identifier();
+      if !module.need_id()
+        || self.chunk_graph.get_module_id(module_identifier).is_some()
+        || self
+          .chunk_graph
+          .get_number_of_module_chunks(module_identifier)
+          == 0
+      {
+        continue;
+      }
+      let Some(id) = records
+        .by_identifier
+        .get(&portable_identifier(context, &module_identifier))
+      else {
+        continue;
+      };
+      if used_ids.insert(*id) {
+        revived.push((module_identifier, id.to_string()));
+      }
+    }
+
+    for (module_identifier, id) in revived {
+      self.chunk_graph.set_module_id(module_identifier, id);
+    }
+    self.used_module_ids = records.used_ids.iter().map(|id| id.to_string()).collect();
+  }
+
+  /// Assigns the recorded ids to the chunks without an id, by chunk name first and
+  /// then by the origins of the chunk.
+  pub(crate) fn revive_chunk_ids(&mut self) {
+    let Some(records) = self.id_records.as_ref().and_then(|r| r.chunks.as_ref()) else {
+      return;
+    };
+    let mut used_ids = HashSet::default();
+    let mut revived: HashMap<ChunkUkey, u64> = HashMap::default();
+    for chunk in self.chunk_by_ukey.values() {
+      if chunk.id.is_some() {
+        continue;
+      }
+      if let Some(id) = chunk
+        .name
+        .as_ref()
+        .and_then(|name| records.by_name.get(name))
+        && used_ids.insert(*id)
+      {
+        revived.insert(chunk.ukey, *id);
+      }
+    }
+    for chunk in self.chunk_by_ukey.values() {
+      if chunk.id.is_some() || revived.contains_key(&chunk.ukey) {
+        continue;
+      }
+      for source in get_chunk_sources(self, chunk) {
+        if let Some(id) = records.by_source.get(&source)
+          && used_ids.insert(*id)
+        {
+          revived.insert(chunk.ukey, *id);
+          break;
+        }
+      }
+    }
+
+    for (chunk_ukey, id) in revived {
+      let chunk = self.chunk_by_ukey.expect_get_mut(&chunk_ukey);
+      chunk.id = Some(id.to_string());
+      chunk.ids = vec![id.to_string()];
+    }
+    self.used_chunk_ids = records.used_ids.iter().map(|id| id.to_string()).collect();
+  }
+
+  /// Records the numeric module and chunk ids of this compilation.
+  pub(crate) fn record_ids(&mut self) {
+    let Some(mut records) = self.id_records.take() else {
+      return;
+    };
+    let context = self.options.context.as_path();
+
+    let module_records = records.modules.get_or_insert_default();
+    let mut used_module_ids = HashSet::default();
+    for module in self.get_module_graph().modules().values() {
+      if !module.need_id() {
+        continue;
+      }
+      let module_identifier = module.identifier();
+      let Some(id) = self
+        .chunk_graph
+        .get_module_id(module_identifier)
+        .and_then(|id| id.parse::<u64>().ok())
+      else {
+        continue;
+      };
+      module_records
+        .by_identifier
+        .insert(portable_identifier(context, &module_identifier), id);
+      used_module_ids.insert(id);
+    }
+    module_records.used_ids = used_module_ids.into_iter().collect();
+    module_records.used_ids.sort_unstable();
+
+    let chunk_records = records.chunks.get_or_insert_default();
+    let mut used_chunk_ids = HashSet::default();
+    for chunk in self.chunk_by_ukey.values() {
+      let Some(id) = chunk.id.as_ref().and_then(|id| id.parse::<u64>().ok()) else {
+        continue;
+      };
+      if let Some(name) = &chunk.name {
+        chunk_records.by_name.insert(name.clone(), id);
+      }
+      for source in get_chunk_sources(self, chunk) {
+        chunk_records.by_source.insert(source, id);
+      }
+      used_chunk_ids.insert(id);
+    }
+    chunk_records.used_ids = used_chunk_ids.into_iter().collect();
+    chunk_records.used_ids.sort_unstable();
+
+    self.id_records = Some(records);
+  }
+}
+
+/// The sources of a chunk are its index in each chunk group, together with the name of
+/// the chunk group or the origins of unnamed chunk groups.
+fn get_chunk_sources(compilation: &Compilation, chunk: &Chunk) -> Vec<String> {
+  let context = compilation.options.context.as_path();
+  let mut sources = vec![];
+  for chunk_group in chunk
+    .groups
+    .iter()
+    .map(|ukey| compilation.chunk_group_by_ukey.expect_get(ukey))
+  {
+    let Some(index) = chunk_group
+      .chunks
+      .iter()
+      .position(|ukey| ukey == &chunk.ukey)
+    else {
+      continue;
+    };
+    if let Some(name) = chunk_group.name() {
+      sources.push(format!("{index} {name}"));
+      continue;
+    }
+    for origin in chunk_group.origins() {
+      let Some(module_identifier) = &origin.module_id else {
+        continue;
+      };
+      let module = portable_identifier(context, module_identifier);
+      if let Some(request) = &origin.request {
+        sources.push(format!("{index} {module} {request}"));
+      } else if let Some(loc) = &origin.loc {
+        sources.push(format!("{index} {module} {loc}"));
+      }
+    }
+  }
+  // chunk groups are not ordered
+  sources.sort_unstable();
+  sources
+}
+
+/// Makes the absolute paths in an identifier relative to the context,
+/// so the records can be shared between machines.
+fn portable_identifier(context: &Utf8Path, identifier: &str) -> String {
+  let mut portable = String::with_capacity(identifier.len());
+  let mut last = 0;
+  for (index, separator) in identifier.match_indices(['|', '!']) {
+    portable.push_str(&absolute_to_request(
+      context.as_str(),
+      &identifier[last..index],
+    ));
+    portable.push_str(separator);
+    last = index + separator.len();
+  }
+  portable.push_str(&absolute_to_request(context.as_str(), &identifier[last..]));
+  portable
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_portable_identifier() {
+    let context = Utf8Path::new("/project");
+    assert_eq!(
+      portable_identifier(
+        context,
+        "javascript/auto|/project/node_modules/loader/index.js!/project/src/a.js?query"
+      ),
+      "javascript/auto|./node_modules/loader/index.js!./src/a.js?query"
+    );
+    assert_eq!(
+      portable_identifier(context, "webpack/runtime/load_script"),
+      "webpack/runtime/load_script"
+    );
+  }
+}
@@ -0,0 +1,84 @@
+use std::collections::HashSet;
+
+use rspack_error::Result;
+use rspack_watcher::FsWatcher;
+pub use rspack_watcher::FsWatcherOptions;
+
+use crate::Compiler;
+
+/// Runs the [Compiler] in watch mode without relying on the javascript side.
+///
+/// The compiler builds once, then rebuilds whenever the file, context or missing dependencies
+/// of the last compilation change, passing the changed and removed paths to [Compiler::rebuild].
+#[derive(Debug)]
+pub struct Watching {
+  compiler: Compiler,
+  watcher: FsWatcher,
+}
+
+impl Watching {
+  pub fn new(compiler: Compiler, options: FsWatcherOptions) -> Result<Self> {
+    Ok(Self {
+      compiler,
+      watcher: FsWatcher::new(options)?,
+    })
+  }
+
+  pub fn compiler(&self) -> &Compiler {
+    &self.compiler
+  }
+
+  pub fn into_compiler(self) -> Compiler {
+    self.compiler
+  }
+
+  /// Builds, then keeps rebuilding on changes.
+  ///
+  /// `handler` is called with the result of every build. Watching stops once it returns `false`,
+  /// or once the watcher stops receiving events.
+  pub async fn watch(
+    &mut self,
+    mut handler: impl FnMut(Result<()>, &Compiler) -> bool,
+  ) -> Result<()> {
+    self
+      .compiler
+      .plugin_driver
+      .compiler_hooks
+      .watch_run
+      .call(&HashSet::default(), &HashSet::default())
+      .await?;
+    let mut result = self.compiler.build().await;
+    loop {
+      if !handler(result, &self.compiler) {
+        return Ok(());
+      }
+      self.watch_dependencies()?;
+
+      let Some(changes) = self.watcher.wait().await else {
+        return Ok(());
+      };
+      let to_strings = |paths: rustc_hash::FxHashSet<std::path::PathBuf>| {
+        paths
+          .into_iter()
+          .map(|path| path.to_string_lossy().to_string())
+          .collect::<HashSet<_>>()
+      };
+      result = self
+        .compiler
+        .rebuild(to_strings(changes.changed), to_strings(changes.removed))
+        .await;
+    }
+  }
+
+  fn watch_dependencies(&mut self) -> Result<()> {
+    let compilation = &self.compiler.compilation;
+    let (file_dependencies, _, _) = compilation.file_dependencies();
+    let (context_dependencies, _, _) = compilation.context_dependencies();
+    let (missing_dependencies, _, _) = compilation.missing_dependencies();
+    self.watcher.watch(
+      file_dependencies.cloned(),
+      context_dependencies.cloned(),
+      missing_dependencies.cloned(),
+    )
+  }
+}
@@ -16,7 +16,7 @@ use rspack_collections::{
 };
 use rspack_error::{Diagnosable, Diagnostic, DiagnosticKind, Result, TraceableError};
 use rspack_hash::{HashDigest, HashFunction, RspackHash};
-use rspack_hook::define_hook;
+use rspack_hook::{define_hook, Hook, HookProfiler};
 use rspack_sources::{CachedSource, ConcatSource, RawSource, ReplaceSource, Source, SourceExt};
 use rspack_util::{ext::DynHash, itoa, source_map::SourceMapKind, swc::join_atom};
 use rustc_hash::FxHasher;
@@ -55,6 +55,12 @@ pub struct ConcatenatedModuleHooks {
   pub exports_definitions: ConcatenatedModuleExportsDefinitionsHook,
 }
 
+impl ConcatenatedModuleHooks {
+  pub(crate) fn set_profiler(&mut self, profiler: &HookProfiler) {
+    self.exports_definitions.set_profiler(profiler.clone());
+  }
+}
+
 #[derive(Debug)]
 pub struct RootModuleContext {
   pub id: ModuleIdentifier,
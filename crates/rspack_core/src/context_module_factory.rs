@@ -3,7 +3,7 @@ use std::{borrow::Cow, fs, sync::Arc};
 use cow_utils::CowUtils;
 use derivative::Derivative;
 use rspack_error::{error, miette::IntoDiagnostic, Result};
-use rspack_hook::define_hook;
+use rspack_hook::{define_hook, Hook, HookProfiler};
 use rspack_paths::{AssertUtf8, Utf8Path, Utf8PathBuf};
 use rspack_regex::RspackRegex;
 use swc_core::common::util::take::Take;
@@ -84,6 +84,13 @@ pub struct ContextModuleFactoryHooks {
   pub after_resolve: ContextModuleFactoryAfterResolveHook,
 }
 
+impl ContextModuleFactoryHooks {
+  pub(crate) fn set_profiler(&mut self, profiler: &HookProfiler) {
+    self.before_resolve.set_profiler(profiler.clone());
+    self.after_resolve.set_profiler(profiler.clone());
+  }
+}
+
 #[derive(Derivative)]
 #[derivative(Debug)]
 pub struct ContextModuleFactory {
@@ -137,10 +137,13 @@ impl AsyncDependenciesBlock {
     std::mem::take(&mut self.dependencies)
   }
 
-  pub fn add_block(&mut self, _block: AsyncDependenciesBlock) {
-    unimplemented!("Nested block are not implemented");
-    // self.block_ids.push(block.id);
-    // self.blocks.push(block);
+  /// Adds a nested block, e.g. a `require.ensure` inside the callback of another `require.ensure`.
+  ///
+  /// The nested block is taken out and added to the module graph after the module is built,
+  /// while its identifier is kept in `get_blocks`.
+  pub fn add_block(&mut self, block: AsyncDependenciesBlock) {
+    self.block_ids.push(block.id);
+    self.blocks.push(Box::new(block));
   }
 
   pub fn take_blocks(&mut self) -> Vec<Box<AsyncDependenciesBlock>> {
@@ -183,9 +186,8 @@ impl AsyncDependenciesBlock {
 }
 
 impl DependenciesBlock for AsyncDependenciesBlock {
-  fn add_block_id(&mut self, _block: AsyncDependenciesBlockIdentifier) {
-    unimplemented!("Nested block are not implemented");
-    // self.block_ids.push(block);
+  fn add_block_id(&mut self, block: AsyncDependenciesBlockIdentifier) {
+    self.block_ids.push(block);
   }
 
   fn get_blocks(&self) -> &[AsyncDependenciesBlockIdentifier] {
@@ -1,7 +1,8 @@
 use super::AffectType;
 use crate::{
-  AsContextDependency, AsDependencyTemplate, Context, Dependency, DependencyCategory, DependencyId,
-  DependencyType, ModuleDependency, ModuleLayer,
+  create_resource_identifier_for_module_dependency, AsContextDependency, AsDependencyTemplate,
+  Context, Dependency, DependencyCategory, DependencyId, DependencyType, ModuleDependency,
+  ModuleLayer,
 };
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
@@ -11,6 +12,7 @@ pub struct EntryDependency {
   context: Context,
   layer: Option<ModuleLayer>,
   is_global: bool,
+  resource_identifier: String,
 }
 
 impl EntryDependency {
@@ -20,12 +22,18 @@ impl EntryDependency {
     layer: Option<ModuleLayer>,
     is_global: bool,
   ) -> Self {
+    let resource_identifier = create_resource_identifier_for_module_dependency(
+      &DependencyCategory::Esm,
+      &DependencyType::Entry,
+      &request,
+    );
     Self {
       request,
       context,
       layer,
       id: DependencyId::new(),
       is_global,
+      resource_identifier,
     }
   }
 
@@ -39,6 +47,10 @@ impl Dependency for EntryDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn category(&self) -> &DependencyCategory {
     &DependencyCategory::Esm
   }
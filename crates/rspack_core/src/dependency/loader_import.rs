@@ -1,7 +1,7 @@
 use super::AffectType;
 use crate::{
-  AsContextDependency, AsDependencyTemplate, Context, Dependency, DependencyCategory, DependencyId,
-  DependencyType, ModuleDependency,
+  create_resource_identifier_for_module_dependency, AsContextDependency, AsDependencyTemplate,
+  Context, Dependency, DependencyCategory, DependencyId, DependencyType, ModuleDependency,
 };
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
@@ -9,14 +9,21 @@ pub struct LoaderImportDependency {
   id: DependencyId,
   context: Context,
   request: String,
+  resource_identifier: String,
 }
 
 impl LoaderImportDependency {
   pub fn new(request: String, context: Context) -> Self {
+    let resource_identifier = create_resource_identifier_for_module_dependency(
+      &DependencyCategory::LoaderImport,
+      &DependencyType::LoaderImport,
+      &request,
+    );
     Self {
       request,
       context,
       id: DependencyId::new(),
+      resource_identifier,
     }
   }
 }
@@ -29,6 +36,10 @@ impl Dependency for LoaderImportDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn get_context(&self) -> Option<&Context> {
     Some(&self.context)
   }
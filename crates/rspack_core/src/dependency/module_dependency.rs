@@ -1,7 +1,7 @@
 use dyn_clone::clone_trait_object;
 
 use super::Dependency;
-use crate::{DependencyCondition, ErrorSpan};
+use crate::{DependencyCategory, DependencyCondition, DependencyType, ErrorSpan};
 
 pub trait ModuleDependency: Dependency {
   fn request(&self) -> &str;
@@ -42,6 +42,16 @@ pub trait ModuleDependency: Dependency {
 
 clone_trait_object!(ModuleDependency);
 
+/// Dependencies of a module with the same resource identifier are factorized only once,
+/// like `getResourceIdentifier` of `ModuleDependency` in webpack.
+pub fn create_resource_identifier_for_module_dependency(
+  category: &DependencyCategory,
+  dependency_type: &DependencyType,
+  request: &str,
+) -> String {
+  format!("{category}|{dependency_type}|{request}")
+}
+
 pub trait AsModuleDependency {
   fn as_module_dependency(&self) -> Option<&dyn ModuleDependency> {
     None
@@ -100,7 +100,7 @@ impl miette::Diagnostic for ModuleParseError {
 
   fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
     match self.severity().unwrap_or(miette::Severity::Error) {
-      miette::Severity::Advice => unreachable!("miette::Severity::Advice should not be used"),
+      miette::Severity::Advice => Some(Box::new("ModuleParseAdvice")),
       miette::Severity::Warning => Some(Box::new("ModuleParseWarning")),
       miette::Severity::Error => Some(Box::new("ModuleParseError")),
     }
@@ -136,6 +136,8 @@ impl ModuleParseError {
           .join("");
         help = format!("{help}\nFile was processed with these loaders:{s}\nYou may need an additional loader to handle the result of these loaders.");
       }
+    } else if source.severity() == Some(miette::Severity::Advice) {
+      title = "Module parse advice:"
     } else {
       title = "Module parse warning:"
     }
@@ -254,3 +256,32 @@ pub fn map_box_diagnostics_to_module_parse_diagnostics(
     })
     .collect()
 }
+
+#[cfg(test)]
+mod test {
+  use rspack_error::{
+    miette::{self, Diagnostic, MietteDiagnostic},
+    Severity,
+  };
+
+  use super::map_box_diagnostics_to_module_parse_diagnostics;
+
+  #[test]
+  fn should_render_module_parse_advice() {
+    let advice: Box<dyn Diagnostic + Send + Sync> = Box::new(
+      MietteDiagnostic::new("Prefer the shorthand syntax").with_severity(miette::Severity::Advice),
+    );
+    let diagnostics = map_box_diagnostics_to_module_parse_diagnostics(vec![advice], &[]);
+    assert_eq!(diagnostics.len(), 1);
+    let diagnostic = &diagnostics[0];
+    assert_eq!(diagnostic.severity(), Severity::Advice);
+    let report = diagnostic
+      .render_report(false)
+      .expect("should render the advice");
+    assert!(report.contains("Module parse advice:"));
+    assert_eq!(
+      diagnostic.code().map(|code| code.to_string()).as_deref(),
+      Some("ModuleParseAdvice")
+    );
+  }
+}
@@ -232,9 +232,9 @@ fn compute_affected_modules_with_chunk_graph(
     for module_id in module_ids {
       module_id.hash(&mut hasher);
     }
-    for block_id in module.get_blocks() {
+    for block_id in module_graph.get_all_blocks(&module_identifier) {
       let Some(chunk_group) =
-        chunk_graph.get_block_chunk_group(block_id, &compilation.chunk_group_by_ukey)
+        chunk_graph.get_block_chunk_group(&block_id, &compilation.chunk_group_by_ukey)
       else {
         continue;
       };
@@ -64,6 +64,8 @@ mod options;
 pub use options::*;
 mod module_graph;
 pub use module_graph::*;
+mod snapshot;
+pub use snapshot::*;
 mod chunk;
 pub use chunk::*;
 mod dependency;
@@ -156,6 +158,7 @@ impl From<&str> for SourceType {
       "consume-shared" => Self::ConsumeShared,
       "unknown" => Self::Unknown,
       "css-import" => Self::CssImport,
+      "runtime" => Self::Runtime,
       other => SourceType::Custom(other.into()),
     }
   }
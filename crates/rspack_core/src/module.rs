@@ -18,6 +18,7 @@ use rustc_hash::FxHashSet as HashSet;
 
 use crate::concatenated_module::ConcatenatedModule;
 use crate::dependencies_block::dependencies_block_update_hash;
+use crate::old_cache::Cache as OldCache;
 use crate::{
   AsyncDependenciesBlock, BoxDependency, ChunkGraph, ChunkUkey, CodeGenerationResult, Compilation,
   CompilerOptions, ConcatenationScope, ConnectionState, Context, ContextModule, DependenciesBlock,
@@ -31,6 +32,7 @@ pub struct BuildContext {
   pub resolver_factory: Arc<ResolverFactory>,
   pub plugin_driver: SharedPluginDriver,
   pub fs: Arc<dyn ReadableFileSystem>,
+  pub old_cache: Arc<OldCache>,
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -7,8 +7,8 @@ use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use swc_core::ecma::atoms::Atom;
 
 use crate::{
-  AsyncDependenciesBlock, AsyncDependenciesBlockIdentifier, Compilation, Dependency,
-  ExportProvided, ProvidedExports, RuntimeSpec, UsedExports,
+  AsyncDependenciesBlock, AsyncDependenciesBlockIdentifier, Compilation, DependenciesBlock,
+  Dependency, ExportProvided, ProvidedExports, RuntimeSpec, UsedExports,
 };
 mod module;
 pub use module::*;
@@ -251,10 +251,7 @@ impl<'a> ModuleGraph<'a> {
   }
 
   pub fn revoke_module(&mut self, module_id: &ModuleIdentifier) -> Vec<BuildDependency> {
-    let blocks = self
-      .module_by_identifier(module_id)
-      .map(|m| Vec::from(m.get_blocks()))
-      .unwrap_or_default();
+    let blocks = self.get_all_blocks(module_id);
 
     let (outgoing_connections, incoming_connections) = self
       .module_graph_module_by_identifier(module_id)
@@ -574,6 +571,25 @@ impl<'a> ModuleGraph<'a> {
       .as_mut()
   }
 
+  /// Get the blocks of a module, including the blocks nested in other blocks.
+  pub fn get_all_blocks(
+    &self,
+    module_identifier: &ModuleIdentifier,
+  ) -> Vec<AsyncDependenciesBlockIdentifier> {
+    let Some(module) = self.module_by_identifier(module_identifier) else {
+      return vec![];
+    };
+    let mut blocks = module.get_blocks().to_vec();
+    let mut index = 0;
+    while index < blocks.len() {
+      if let Some(block) = self.block_by_id(&blocks[index]) {
+        blocks.extend_from_slice(block.get_blocks());
+      }
+      index += 1;
+    }
+    blocks
+  }
+
   pub fn block_by_id_expect(
     &self,
     block_id: &AsyncDependenciesBlockIdentifier,
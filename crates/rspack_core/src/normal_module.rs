@@ -12,9 +12,12 @@ use std::{
 use dashmap::DashMap;
 use derivative::Derivative;
 use rspack_collections::{Identifiable, IdentifierSet};
-use rspack_error::{error, Diagnosable, Diagnostic, DiagnosticExt, NodeError, Result, Severity};
+use rspack_error::{
+  error, Diagnosable, Diagnostic, DiagnosticExt, IntoTWithDiagnosticArray, NodeError, Result,
+  Severity,
+};
 use rspack_hash::{RspackHash, RspackHashDigest};
-use rspack_hook::define_hook;
+use rspack_hook::{define_hook, Hook, HookProfiler};
 use rspack_loader_runner::{run_loaders, AdditionalData, Content, LoaderContext, ResourceData};
 use rspack_macros::impl_source_map_config;
 use rspack_sources::{
@@ -91,6 +94,17 @@ pub struct NormalModuleHooks {
   pub additional_data: NormalModuleAdditionalDataHook,
 }
 
+impl NormalModuleHooks {
+  pub(crate) fn set_profiler(&mut self, profiler: &HookProfiler) {
+    self.read_resource.set_profiler(profiler.clone());
+    self.loader.set_profiler(profiler.clone());
+    self.loader_should_yield.set_profiler(profiler.clone());
+    self.loader_yield.set_profiler(profiler.clone());
+    self.before_loaders.set_profiler(profiler.clone());
+    self.additional_data.set_profiler(profiler.clone());
+  }
+}
+
 #[impl_source_map_config]
 #[derive(Derivative)]
 #[derivative(Debug)]
@@ -175,13 +189,18 @@ impl NormalModule {
     module_type: &ModuleType,
     layer: Option<&ModuleLayer>,
     request: &'request str,
+    identifier_suffix: Option<&str>,
   ) -> Cow<'request, str> {
-    if let Some(layer) = layer {
+    let id: Cow<str> = if let Some(layer) = layer {
       format!("{module_type}|{request}|{layer}").into()
     } else if *module_type == ModuleType::JsAuto {
       request.into()
     } else {
       format!("{module_type}|{request}").into()
+    };
+    match identifier_suffix {
+      Some(suffix) => format!("{id}{suffix}").into(),
+      None => id,
     }
   }
 
@@ -192,6 +211,7 @@ impl NormalModule {
     raw_request: String,
     module_type: impl Into<ModuleType>,
     layer: Option<ModuleLayer>,
+    identifier_suffix: Option<&str>,
     parser_and_generator: Box<dyn ParserAndGenerator>,
     parser_options: Option<ParserOptions>,
     generator_options: Option<GeneratorOptions>,
@@ -201,7 +221,7 @@ impl NormalModule {
     loaders: Vec<BoxLoader>,
   ) -> Self {
     let module_type = module_type.into();
-    let id = Self::create_id(&module_type, layer.as_ref(), &request);
+    let id = Self::create_id(&module_type, layer.as_ref(), &request, identifier_suffix);
     Self {
       blocks: Vec::new(),
       dependencies: Vec::new(),
@@ -420,20 +440,39 @@ impl Module for NormalModule {
       current_loader: Default::default(),
     });
 
-    let loader_result = run_loaders(
-      self.loaders.clone(),
-      self.resource_data.clone(),
-      Some(plugin.clone()),
-      RunnerContext {
-        options: build_context.compiler_options.clone(),
-        resolver_factory: build_context.resolver_factory.clone(),
-        #[allow(clippy::unwrap_used)]
-        module: NonNull::new(self).unwrap(),
-        module_source_map_kind: self.source_map_kind,
-      },
-      build_context.fs.clone(),
-    )
-    .await;
+    // modules without loaders only read their resource, which is not worth caching
+    let build_module_occasion = &build_context.old_cache.build_module_occasion;
+    let cached_loader_result = if self.loaders.is_empty() {
+      None
+    } else {
+      build_module_occasion.get(&self.identifier(), &*build_context.fs)
+    };
+    let loader_result = if let Some(cached_loader_result) = cached_loader_result {
+      Ok(cached_loader_result.with_empty_diagnostic())
+    } else {
+      let loader_result = run_loaders(
+        self.loaders.clone(),
+        self.resource_data.clone(),
+        Some(plugin.clone()),
+        RunnerContext {
+          options: build_context.compiler_options.clone(),
+          resolver_factory: build_context.resolver_factory.clone(),
+          #[allow(clippy::unwrap_used)]
+          module: NonNull::new(self).unwrap(),
+          module_source_map_kind: self.source_map_kind,
+        },
+        build_context.fs.clone(),
+      )
+      .await;
+      // the diagnostics of loaders can't be replayed from the cache
+      if !self.loaders.is_empty()
+        && let Ok(loader_result) = &loader_result
+        && loader_result.diagnostics().is_empty()
+      {
+        build_module_occasion.set(self.identifier(), loader_result.get(), &*build_context.fs);
+      }
+      loader_result
+    };
     let (mut loader_result, ds) = match loader_result {
       Ok(r) => r.split_into_parts(),
       Err(mut r) => {
@@ -746,7 +785,8 @@ impl Module for NormalModule {
           let state =
             dependency.get_module_evaluation_side_effects_state(module_graph, module_chain);
           if matches!(state, ConnectionState::Bool(true)) {
-            // TODO add optimization bailout
+            // the module graph is immutable here, the optimization bailout is computed for stats
+            // by `get_side_effects_bailout`
             module_chain.remove(&self.identifier());
             return ConnectionState::Bool(true);
           } else if !matches!(state, ConnectionState::CircularConnection) {
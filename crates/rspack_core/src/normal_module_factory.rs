@@ -3,7 +3,7 @@ use std::{borrow::Cow, sync::Arc};
 
 use regex::Regex;
 use rspack_error::{error, Result};
-use rspack_hook::define_hook;
+use rspack_hook::{define_hook, Hook, HookProfiler};
 use rspack_loader_runner::{get_scheme, Loader, Scheme};
 use rspack_paths::Utf8PathBuf;
 use rspack_util::MergeFrom;
@@ -11,7 +11,7 @@ use sugar_path::SugarPath;
 use swc_core::common::Span;
 
 use crate::{
-  diagnostics::EmptyDependency, module_rules_matcher, parse_resource, resolve,
+  diagnostics::EmptyDependency, module_rules_matcher, old_cache::Cache as OldCache, parse_resource,
   stringify_loaders_and_resource, BoxLoader, BoxModule, CompilerOptions, Context, Dependency,
   DependencyCategory, DependencyRange, FuncUseCtx, GeneratorOptions, ModuleExt, ModuleFactory,
   ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier, ModuleLayer, ModuleRuleEffect,
@@ -55,11 +55,27 @@ pub struct NormalModuleFactoryHooks {
   pub resolve_loader: NormalModuleFactoryResolveLoaderHook,
 }
 
+impl NormalModuleFactoryHooks {
+  pub(crate) fn set_profiler(&mut self, profiler: &HookProfiler) {
+    self.before_resolve.set_profiler(profiler.clone());
+    self.factorize.set_profiler(profiler.clone());
+    self.resolve.set_profiler(profiler.clone());
+    self.resolve_for_scheme.set_profiler(profiler.clone());
+    self.resolve_in_scheme.set_profiler(profiler.clone());
+    self.after_resolve.set_profiler(profiler.clone());
+    self.create_module.set_profiler(profiler.clone());
+    self.module.set_profiler(profiler.clone());
+    self.parser.set_profiler(profiler.clone());
+    self.resolve_loader.set_profiler(profiler.clone());
+  }
+}
+
 #[derive(Debug)]
 pub struct NormalModuleFactory {
   options: Arc<CompilerOptions>,
   loader_resolver_factory: Arc<ResolverFactory>,
   plugin_driver: SharedPluginDriver,
+  old_cache: Arc<OldCache>,
 }
 
 #[async_trait::async_trait]
@@ -95,11 +111,13 @@ impl NormalModuleFactory {
     options: Arc<CompilerOptions>,
     loader_resolver_factory: Arc<ResolverFactory>,
     plugin_driver: SharedPluginDriver,
+    old_cache: Arc<OldCache>,
   ) -> Self {
     Self {
       options,
       loader_resolver_factory,
       plugin_driver,
+      old_cache,
     }
   }
 
@@ -336,7 +354,11 @@ impl NormalModuleFactory {
           missing_dependencies: &mut missing_dependencies,
         };
 
-        let resource_data = resolve(resolve_args, plugin_driver).await;
+        let resource_data = self
+          .old_cache
+          .resolve_occasion
+          .use_cache(resolve_args, plugin_driver)
+          .await;
 
         match resource_data {
           Ok(ResolveResult::Resource(resource)) => ResourceData::new(resource.full_path())
@@ -560,6 +582,7 @@ impl NormalModuleFactory {
         resource_resolve_data: resource_data,
         match_resource: match_resource_data.as_ref().map(|d| d.resource.clone()),
         side_effects: resolved_side_effects,
+        identifier_suffix: None,
       };
       if let Some(plugin_result) = self
         .plugin_driver
@@ -593,6 +616,7 @@ impl NormalModuleFactory {
         create_data.raw_request.clone(),
         resolved_module_type,
         resolved_module_layer,
+        create_data.identifier_suffix.as_deref(),
         resolved_parser_and_generator,
         resolved_parser_options,
         resolved_generator_options,
@@ -874,6 +898,10 @@ pub struct NormalModuleCreateData {
   pub resource_resolve_data: ResourceData,
   pub match_resource: Option<String>,
   pub side_effects: Option<bool>,
+  /// Appended to the identifier of the created module, for modules which are created from the
+  /// same request but must not be merged, e.g. css imported with different `layer`, `supports`
+  /// or `media`.
+  pub identifier_suffix: Option<String>,
 }
 
 #[test]
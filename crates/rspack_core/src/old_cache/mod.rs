@@ -2,10 +2,12 @@ use std::{
   path::PathBuf,
   sync::{
     atomic::{AtomicBool, Ordering},
-    Arc,
+    Arc, Mutex,
   },
 };
 
+use rspack_collections::Identifier;
+
 use crate::CompilerOptions;
 
 mod local;
@@ -13,13 +15,18 @@ mod occasion;
 mod storage;
 pub use local::*;
 use occasion::{
-  CodeGenerateOccasion, CreateChunkAssetsOccasion, ProcessRuntimeRequirementsOccasion,
+  BuildModuleOccasion, CodeGenerateOccasion, CreateChunkAssetsOccasion,
+  ProcessRuntimeRequirementsOccasion, ResolveOccasion,
 };
-use storage::new_storage;
+use storage::{new_persistent_storage, new_storage};
 
 #[derive(Debug)]
 pub struct Cache {
   is_idle: AtomicBool,
+  /// files modified since the last idle, used to evict outdated items
+  modified_files: Mutex<Vec<PathBuf>>,
+  pub build_module_occasion: BuildModuleOccasion,
+  pub resolve_occasion: ResolveOccasion,
   pub code_generate_occasion: CodeGenerateOccasion,
   pub process_runtime_requirements_occasion: ProcessRuntimeRequirementsOccasion,
   pub create_chunk_assets_occasion: CreateChunkAssetsOccasion,
@@ -29,25 +36,97 @@ impl Cache {
   pub fn new(options: Arc<CompilerOptions>) -> Self {
     Self {
       is_idle: true.into(),
-      code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options.cache)),
+      modified_files: Default::default(),
+      build_module_occasion: BuildModuleOccasion::new(
+        new_persistent_storage(&options, "build-module"),
+        options.snapshot.clone(),
+      ),
+      resolve_occasion: ResolveOccasion::new(
+        new_persistent_storage(&options, "resolve"),
+        options.snapshot.clone(),
+      ),
+      code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options, "code-generate")),
       process_runtime_requirements_occasion: ProcessRuntimeRequirementsOccasion::new(new_storage(
-        &options.cache,
+        &options,
+        "process-runtime-requirements",
+      )),
+      create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(
+        &options,
+        "create-chunk-assets",
       )),
-      create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(&options.cache)),
     }
   }
 
-  pub fn set_modified_files(&self, _modified_files: Vec<PathBuf>) {
-    // TODO
+  pub fn set_modified_files(&self, modified_files: Vec<PathBuf>) {
+    if let Ok(mut files) = self.modified_files.lock() {
+      files.extend(modified_files);
+    }
   }
 
   pub fn begin_idle(&self) {
-    if self.is_idle.load(Ordering::Relaxed) {
-      // TODO clean cache
+    if self.is_idle.swap(true, Ordering::Relaxed) {
+      return;
     }
+    let modified_files = self
+      .modified_files
+      .lock()
+      .map(|mut files| std::mem::take(&mut *files))
+      .unwrap_or_default()
+      .into_iter()
+      .map(|file| file.to_string_lossy().to_string())
+      .collect::<Vec<_>>();
+    // cache keys start with the module identifier, which contains the resource path
+    let is_outdated = |id: &Identifier| {
+      modified_files
+        .iter()
+        .any(|file| contains_path(id.as_str(), file))
+    };
+    self.build_module_occasion.begin_idle(&is_outdated);
+    // resolve results are keyed by the request, they are validated with their snapshot
+    self.resolve_occasion.begin_idle(&|_| false);
+    self.code_generate_occasion.begin_idle(&is_outdated);
+    self
+      .process_runtime_requirements_occasion
+      .begin_idle(&is_outdated);
+    self.create_chunk_assets_occasion.begin_idle(&is_outdated);
   }
 
   pub fn end_idle(&self) {
     self.is_idle.store(false, Ordering::Relaxed);
   }
 }
+
+/// Whether the identifier contains the whole path, e.g. `/src/a.js` is not contained in
+/// `javascript/auto|/src/a.jsx`, while it is in `javascript/auto|/loader.js!/src/a.js?raw`.
+fn contains_path(identifier: &str, path: &str) -> bool {
+  if path.is_empty() {
+    return false;
+  }
+  identifier.match_indices(path).any(|(start, _)| {
+    let before = identifier[..start].chars().next_back();
+    let after = identifier[start + path.len()..].chars().next();
+    matches!(before, None | Some('|' | '!' | ' '))
+      && matches!(after, None | Some('|' | '!' | '?' | '#' | ' '))
+  })
+}
+
+#[cfg(test)]
+mod test {
+  use super::contains_path;
+
+  #[test]
+  fn should_match_whole_paths_in_identifiers() {
+    assert!(contains_path("javascript/auto|/src/a.js", "/src/a.js"));
+    assert!(contains_path(
+      "css|/node_modules/loader.js!/src/a.css?raw|layer",
+      "/src/a.css"
+    ));
+    assert!(contains_path("/src/a.js|runtime", "/src/a.js"));
+    assert!(!contains_path("javascript/auto|/src/a.jsx", "/src/a.js"));
+    assert!(!contains_path(
+      "javascript/auto|/other/src/a.js",
+      "/src/a.js"
+    ));
+    assert!(!contains_path("javascript/auto|/src/a.js", ""));
+  }
+}
@@ -0,0 +1,155 @@
+use std::path::PathBuf;
+
+use rspack_cacheable::cacheable;
+use rspack_collections::Identifier;
+use rspack_fs::ReadableFileSystem;
+use rspack_loader_runner::{Content, LoaderResult};
+use rspack_sources::SourceMap;
+
+use crate::old_cache::storage::{self, Persistable};
+use crate::{ModuleIdentifier, Snapshot, SnapshotOptions};
+
+type Storage = dyn storage::Storage<BuildModuleItem>;
+
+/// The output of the loaders of a module, together with the snapshot of the files it was
+/// created from.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct BuildModuleItem {
+  content: Vec<u8>,
+  source_map: Option<String>,
+  parse_meta: Vec<(String, String)>,
+  file_dependencies: Vec<String>,
+  missing_dependencies: Vec<String>,
+  build_dependencies: Vec<String>,
+  snapshot: Snapshot,
+}
+
+/// Reuses the loader results of modules across compiler instances, so a cold start does not
+/// run the loaders of unchanged modules again.
+#[derive(Debug)]
+pub struct BuildModuleOccasion {
+  storage: Option<Box<Storage>>,
+  snapshot_options: SnapshotOptions,
+}
+
+impl BuildModuleOccasion {
+  pub fn new(storage: Option<Box<Storage>>, snapshot_options: SnapshotOptions) -> Self {
+    Self {
+      storage,
+      snapshot_options,
+    }
+  }
+
+  pub fn begin_idle(&self, is_outdated: &dyn Fn(&Identifier) -> bool) {
+    if let Some(storage) = &self.storage {
+      storage.begin_idle(is_outdated);
+    }
+  }
+
+  pub fn get(
+    &self,
+    module: &ModuleIdentifier,
+    fs: &dyn ReadableFileSystem,
+  ) -> Option<LoaderResult> {
+    let storage = self.storage.as_ref()?;
+    let item = storage.get(module)?;
+    if !item.snapshot.is_unchanged(&self.snapshot_options, fs) {
+      storage.remove(module);
+      return None;
+    }
+    let source_map = match &item.source_map {
+      Some(map) => Some(SourceMap::from_json(map).ok()?),
+      None => None,
+    };
+    let to_paths = |paths: Vec<String>| paths.into_iter().map(PathBuf::from).collect();
+    Some(LoaderResult {
+      cacheable: true,
+      file_dependencies: to_paths(item.file_dependencies),
+      context_dependencies: Default::default(),
+      missing_dependencies: to_paths(item.missing_dependencies),
+      build_dependencies: to_paths(item.build_dependencies),
+      content: Content::Buffer(item.content),
+      source_map,
+      additional_data: None,
+      parse_meta: item.parse_meta.into_iter().collect(),
+    })
+  }
+
+  /// Results of non-cacheable loaders, results with context dependencies and results which
+  /// carry additional data are not stored, as they can't be validated or restored.
+  pub fn set(&self, module: ModuleIdentifier, result: &LoaderResult, fs: &dyn ReadableFileSystem) {
+    let Some(storage) = &self.storage else {
+      return;
+    };
+    if !result.cacheable
+      || !result.context_dependencies.is_empty()
+      || result
+        .additional_data
+        .as_ref()
+        .is_some_and(|data| !data.is_empty())
+    {
+      return;
+    }
+    let source_map = match &result.source_map {
+      Some(map) => match map.clone().to_json() {
+        Ok(map) => Some(map),
+        Err(_) => return,
+      },
+      None => None,
+    };
+    let to_strings = |paths: &rustc_hash::FxHashSet<PathBuf>| {
+      let mut paths = paths
+        .iter()
+        .map(|path| path.to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+      paths.sort();
+      paths
+    };
+    let mut parse_meta = result
+      .parse_meta
+      .iter()
+      .map(|(key, value)| (key.clone(), value.clone()))
+      .collect::<Vec<_>>();
+    parse_meta.sort();
+    let options = &self.snapshot_options;
+    let mut snapshot = Snapshot::default();
+    snapshot.add_paths(result.file_dependencies.iter(), options.module, options, fs);
+    snapshot.add_paths(
+      result.missing_dependencies.iter(),
+      options.resolve,
+      options,
+      fs,
+    );
+    storage.set(
+      module,
+      BuildModuleItem {
+        content: result.content.as_bytes().to_vec(),
+        source_map,
+        parse_meta,
+        file_dependencies: to_strings(&result.file_dependencies),
+        missing_dependencies: to_strings(&result.missing_dependencies),
+        build_dependencies: to_strings(&result.build_dependencies),
+        snapshot,
+      },
+    );
+  }
+
+  /// Drops the cached result of a module, e.g. when its loaders emitted assets which
+  /// can't be restored.
+  pub fn remove(&self, module: &ModuleIdentifier) {
+    if let Some(storage) = &self.storage {
+      storage.remove(module);
+    }
+  }
+}
+
+impl Persistable for BuildModuleItem {
+  fn to_bytes(&self) -> Option<Vec<u8>> {
+    rspack_cacheable::to_bytes(self, &()).ok()
+  }
+
+  fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    rspack_cacheable::from_bytes(bytes, &()).ok()
+  }
+}
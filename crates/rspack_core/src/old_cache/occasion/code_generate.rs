@@ -1,8 +1,11 @@
+use rspack_cacheable::cacheable;
 use rspack_collections::Identifier;
 use rspack_error::Result;
 
-use crate::{old_cache::storage, CodeGenerationResult};
+use super::PersistentSource;
+use crate::old_cache::storage::{self, Persistable};
 use crate::{CodeGenerationJob, ModuleIdentifier, RuntimeSpec};
+use crate::{CodeGenerationResult, RuntimeGlobals, SourceType};
 
 type Storage = dyn storage::Storage<CodeGenerationResult>;
 
@@ -16,6 +19,12 @@ impl CodeGenerateOccasion {
     Self { storage }
   }
 
+  pub fn begin_idle(&self, is_outdated: &dyn Fn(&Identifier) -> bool) {
+    if let Some(storage) = &self.storage {
+      storage.begin_idle(is_outdated);
+    }
+  }
+
   #[tracing::instrument(skip_all, fields(module = ?job.module))]
   pub fn use_cache(
     &self,
@@ -43,3 +52,47 @@ impl CodeGenerateOccasion {
     }
   }
 }
+
+#[cacheable]
+struct PersistentCodeGenerationResult {
+  sources: Vec<PersistentSource>,
+  runtime_requirements: u128,
+  hash: Option<String>,
+}
+
+impl Persistable for CodeGenerationResult {
+  fn to_bytes(&self) -> Option<Vec<u8>> {
+    // codegen data, init fragments and concatenation scope hold trait objects
+    // which are not cacheable yet, such results can only live in memory
+    if !self.data.is_empty()
+      || !self.chunk_init_fragments.is_empty()
+      || self.concatenation_scope.is_some()
+    {
+      return None;
+    }
+    let sources = self
+      .inner
+      .iter()
+      .map(|(source_type, source)| PersistentSource::new(source_type.to_string(), source))
+      .collect();
+    let result = PersistentCodeGenerationResult {
+      sources,
+      runtime_requirements: self.runtime_requirements.bits(),
+      hash: self.hash.as_ref().map(|hash| hash.encoded().to_string()),
+    };
+    rspack_cacheable::to_bytes(&result, &()).ok()
+  }
+
+  fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    let result: PersistentCodeGenerationResult = rspack_cacheable::from_bytes(bytes, &()).ok()?;
+    let mut codegen_result = CodeGenerationResult::default();
+    for source in result.sources {
+      let source_type = SourceType::from(source.name.as_str());
+      codegen_result.add(source_type, source.into_source()?);
+    }
+    codegen_result.runtime_requirements =
+      RuntimeGlobals::from_bits_retain(result.runtime_requirements);
+    codegen_result.hash = result.hash.as_deref().map(Into::into);
+    Some(codegen_result)
+  }
+}
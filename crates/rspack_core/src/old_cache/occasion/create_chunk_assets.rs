@@ -1,8 +1,13 @@
 use futures::Future;
+use rspack_cacheable::cacheable;
 use rspack_collections::Identifier;
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result};
 
-use crate::{old_cache::storage, Chunk, Compilation, NormalModuleSource, RenderManifestEntry};
+use super::PersistentSource;
+use crate::{
+  old_cache::storage::{self, Persistable},
+  AssetInfo, Chunk, Compilation, RenderManifestEntry,
+};
 
 type Storage = dyn storage::Storage<Vec<RenderManifestEntry>>;
 
@@ -16,48 +21,139 @@ impl CreateChunkAssetsOccasion {
     Self { storage }
   }
 
+  pub fn begin_idle(&self, is_outdated: &dyn Fn(&Identifier) -> bool) {
+    if let Some(storage) = &self.storage {
+      storage.begin_idle(is_outdated);
+    }
+  }
+
   pub async fn use_cache<G, F>(
     &self,
     compilation: &Compilation,
     chunk: &Chunk,
     generator: G,
-  ) -> Result<Vec<RenderManifestEntry>>
+  ) -> Result<(Vec<RenderManifestEntry>, Vec<Diagnostic>)>
   where
     G: Fn() -> F,
-    F: Future<Output = Result<Vec<RenderManifestEntry>>>,
+    F: Future<Output = Result<(Vec<RenderManifestEntry>, Vec<Diagnostic>)>>,
   {
     let storage = match &self.storage {
       Some(s) => s,
       // no cache return directly
       None => return generator().await,
     };
+    let (Some(chunk_id), Some(chunk_hash)) = (&chunk.id, &chunk.hash) else {
+      return generator().await;
+    };
 
-    let chunk_id = Identifier::from(chunk.expect_id());
-    let modules = &compilation
-      .chunk_graph
-      .expect_chunk_graph_chunk(&chunk.ukey)
-      .modules;
-    let is_cache_valid = modules.iter().all(|module_id| {
-      matches!(
-        compilation
-          .get_module_graph()
-          .module_by_identifier(module_id)
-          .and_then(|m| m.as_normal_module())
-          .map(|m| matches!(m.source(), NormalModuleSource::Unbuild)),
-        Some(true)
-      )
-    });
-
-    if is_cache_valid {
-      // read
-      if let Some(data) = storage.get(&chunk_id) {
-        return Ok(data);
-      }
+    // the chunk hash covers the modules and the runtime of the chunk, the runtime chunks
+    // also embed the full hash
+    let cache_key = if chunk.has_runtime(&compilation.chunk_group_by_ukey)
+      && let Some(full_hash) = &compilation.hash
+    {
+      Identifier::from(format!(
+        "{chunk_id}|{}|{}",
+        chunk_hash.encoded(),
+        full_hash.encoded()
+      ))
+    } else {
+      Identifier::from(format!("{chunk_id}|{}", chunk_hash.encoded()))
+    };
+    if let Some(data) = storage.get(&cache_key) {
+      return Ok((data, vec![]));
     }
     // run generator and save to cache
-    let data = generator().await?;
-    // TODO sometime may not run save
-    storage.set(chunk_id, data.clone());
-    Ok(data)
+    let (data, diagnostics) = generator().await?;
+    // the diagnostics can't be replayed from the cache
+    if diagnostics.is_empty() {
+      storage.set(cache_key, data.clone());
+    }
+    Ok((data, diagnostics))
+  }
+}
+
+#[cacheable]
+struct PersistentRenderManifestEntry {
+  source: PersistentSource,
+  filename: String,
+  auxiliary: bool,
+  has_filename: bool,
+  immutable: Option<bool>,
+  full_hash: Vec<String>,
+  chunk_hash: Vec<String>,
+  content_hash: Vec<String>,
+  development: Option<bool>,
+  hot_module_replacement: Option<bool>,
+  javascript_module: Option<bool>,
+  css_unused_idents: Option<Vec<String>>,
+}
+
+impl Persistable for Vec<RenderManifestEntry> {
+  fn to_bytes(&self) -> Option<Vec<u8>> {
+    let entries = self
+      .iter()
+      .map(|entry| {
+        let info = &entry.info;
+        // only the fields set by rendering are persisted, assets with other fields
+        // can only live in memory
+        if info.minimized.is_some()
+          || info.source_filename.is_some()
+          || info.copied.is_some()
+          || info.related.source_map.is_some()
+          || !info.version.is_empty()
+          || !info.extras.is_empty()
+        {
+          return None;
+        }
+        Some(PersistentRenderManifestEntry {
+          source: PersistentSource::new(entry.filename().to_string(), entry.source()),
+          filename: entry.filename().to_string(),
+          auxiliary: entry.auxiliary,
+          has_filename: entry.has_filename(),
+          immutable: info.immutable,
+          full_hash: info.full_hash.iter().cloned().collect(),
+          chunk_hash: info.chunk_hash.iter().cloned().collect(),
+          content_hash: info.content_hash.iter().cloned().collect(),
+          development: info.development,
+          hot_module_replacement: info.hot_module_replacement,
+          javascript_module: info.javascript_module,
+          css_unused_idents: info
+            .css_unused_idents
+            .as_ref()
+            .map(|idents| idents.iter().cloned().collect()),
+        })
+      })
+      .collect::<Option<Vec<_>>>()?;
+    rspack_cacheable::to_bytes(&entries, &()).ok()
+  }
+
+  fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    let entries: Vec<PersistentRenderManifestEntry> =
+      rspack_cacheable::from_bytes(bytes, &()).ok()?;
+    entries
+      .into_iter()
+      .map(|entry| {
+        let info = AssetInfo {
+          immutable: entry.immutable,
+          full_hash: entry.full_hash.into_iter().collect(),
+          chunk_hash: entry.chunk_hash.into_iter().collect(),
+          content_hash: entry.content_hash.into_iter().collect(),
+          development: entry.development,
+          hot_module_replacement: entry.hot_module_replacement,
+          javascript_module: entry.javascript_module,
+          css_unused_idents: entry
+            .css_unused_idents
+            .map(|idents| idents.into_iter().collect()),
+          ..Default::default()
+        };
+        Some(RenderManifestEntry::new(
+          entry.source.into_source()?,
+          entry.filename,
+          info,
+          entry.auxiliary,
+          entry.has_filename,
+        ))
+      })
+      .collect()
   }
 }
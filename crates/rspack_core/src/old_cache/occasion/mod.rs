@@ -1,6 +1,52 @@
+use rspack_cacheable::cacheable;
+use rspack_sources::{
+  BoxSource, MapOptions, RawSource, Source, SourceExt, SourceMap, SourceMapSource,
+  WithoutOriginalOptions,
+};
+
 mod code_generate;
 pub use code_generate::*;
 mod process_runtime_requirements;
 pub use process_runtime_requirements::*;
 mod create_chunk_assets;
 pub use create_chunk_assets::*;
+mod build_module;
+pub use build_module::*;
+mod resolve;
+pub use resolve::*;
+
+/// A source with its source map, in the form written to the persistent storage.
+#[cacheable]
+struct PersistentSource {
+  name: String,
+  code: Vec<u8>,
+  map: Option<String>,
+}
+
+impl PersistentSource {
+  fn new(name: String, source: &BoxSource) -> Self {
+    Self {
+      name,
+      code: source.buffer().to_vec(),
+      map: source
+        .map(&MapOptions::default())
+        .and_then(|map| map.to_json().ok()),
+    }
+  }
+
+  fn into_source(self) -> Option<BoxSource> {
+    let source = match self.map.and_then(|map| SourceMap::from_json(&map).ok()) {
+      Some(source_map) => SourceMapSource::new(WithoutOriginalOptions {
+        value: String::from_utf8(self.code).ok()?,
+        name: self.name,
+        source_map,
+      })
+      .boxed(),
+      None => match String::from_utf8(self.code) {
+        Ok(code) => RawSource::from(code).boxed(),
+        Err(err) => RawSource::from(err.into_bytes()).boxed(),
+      },
+    };
+    Some(source)
+  }
+}
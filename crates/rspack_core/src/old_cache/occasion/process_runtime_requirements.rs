@@ -1,7 +1,7 @@
 use rspack_collections::Identifier;
 use rspack_error::Result;
 
-use crate::old_cache::storage;
+use crate::old_cache::storage::{self, Persistable};
 use crate::{
   get_runtime_key, ChunkGraph, Compilation, ModuleIdentifier, RuntimeGlobals, RuntimeSpec,
 };
@@ -18,6 +18,12 @@ impl ProcessRuntimeRequirementsOccasion {
     Self { storage }
   }
 
+  pub fn begin_idle(&self, is_outdated: &dyn Fn(&Identifier) -> bool) {
+    if let Some(storage) = &self.storage {
+      storage.begin_idle(is_outdated);
+    }
+  }
+
   #[tracing::instrument(skip_all, fields(module = ?module))]
   pub fn use_cache(
     &self,
@@ -50,3 +56,15 @@ impl ProcessRuntimeRequirementsOccasion {
     }
   }
 }
+
+impl Persistable for RuntimeGlobals {
+  fn to_bytes(&self) -> Option<Vec<u8>> {
+    rspack_cacheable::to_bytes(&self.bits(), &()).ok()
+  }
+
+  fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    rspack_cacheable::from_bytes::<u128, ()>(bytes, &())
+      .ok()
+      .map(RuntimeGlobals::from_bits_retain)
+  }
+}
@@ -0,0 +1,193 @@
+use std::{fs, path::PathBuf, sync::Arc};
+
+use rspack_cacheable::cacheable;
+use rspack_collections::Identifier;
+use rspack_error::Error;
+use rspack_fs::NativeFileSystem;
+use rspack_loader_runner::DescriptionData;
+use rspack_paths::Utf8PathBuf;
+
+use crate::old_cache::storage::{self, Persistable};
+use crate::{
+  resolve, ResolveArgs, ResolveResult, Resource, SharedPluginDriver, Snapshot, SnapshotOptions,
+};
+
+type Storage = dyn storage::Storage<ResolveItem>;
+
+#[cacheable]
+#[derive(Debug, Clone)]
+struct ResolvedResource {
+  path: String,
+  query: String,
+  fragment: String,
+  /// Path to the `package.json`, which is read again on restore.
+  description_file: Option<String>,
+}
+
+/// A resolve result, together with the snapshot of the files the resolution depends on.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct ResolveItem {
+  /// `None` for ignored requests.
+  resource: Option<ResolvedResource>,
+  file_dependencies: Vec<String>,
+  missing_dependencies: Vec<String>,
+  snapshot: Snapshot,
+}
+
+/// Reuses the results of resolving module requests across compiler instances.
+#[derive(Debug)]
+pub struct ResolveOccasion {
+  storage: Option<Box<Storage>>,
+  snapshot_options: SnapshotOptions,
+}
+
+impl ResolveOccasion {
+  pub fn new(storage: Option<Box<Storage>>, snapshot_options: SnapshotOptions) -> Self {
+    Self {
+      storage,
+      snapshot_options,
+    }
+  }
+
+  pub fn begin_idle(&self, is_outdated: &dyn Fn(&Identifier) -> bool) {
+    if let Some(storage) = &self.storage {
+      storage.begin_idle(is_outdated);
+    }
+  }
+
+  /// Requests with their own resolve options are always resolved again, and failed
+  /// resolutions are never cached.
+  pub async fn use_cache(
+    &self,
+    args: ResolveArgs<'_>,
+    plugin_driver: &SharedPluginDriver,
+  ) -> Result<ResolveResult, Error> {
+    let storage = match &self.storage {
+      Some(storage) if args.resolve_options.is_none() => storage,
+      _ => return resolve(args, plugin_driver).await,
+    };
+    let cache_key = Identifier::from(format!(
+      "{}|{}|{:?}|{}",
+      args.context, args.specifier, args.dependency_category, args.resolve_to_context
+    ));
+
+    if let Some(item) = storage.get(&cache_key) {
+      if let Some(result) = item.restore(&self.snapshot_options) {
+        args
+          .file_dependencies
+          .extend(item.file_dependencies.into_iter().map(PathBuf::from));
+        args
+          .missing_dependencies
+          .extend(item.missing_dependencies.into_iter().map(PathBuf::from));
+        return Ok(result);
+      }
+      storage.remove(&cache_key);
+    }
+
+    // collect the dependencies of this resolution only, the sets of the args are shared
+    // with other requests of the same module
+    let mut file_dependencies = Default::default();
+    let mut missing_dependencies = Default::default();
+    let ResolveArgs {
+      file_dependencies: args_file_dependencies,
+      missing_dependencies: args_missing_dependencies,
+      ..
+    } = args;
+    let result = resolve(
+      ResolveArgs {
+        file_dependencies: &mut file_dependencies,
+        missing_dependencies: &mut missing_dependencies,
+        ..args
+      },
+      plugin_driver,
+    )
+    .await;
+
+    if let Ok(result) = &result {
+      let to_strings = |paths: &rustc_hash::FxHashSet<PathBuf>| {
+        paths
+          .iter()
+          .map(|path| path.to_string_lossy().to_string())
+          .collect::<Vec<_>>()
+      };
+      let resource = match result {
+        ResolveResult::Resource(resource) => Some(ResolvedResource {
+          path: resource.path.to_string(),
+          query: resource.query.clone(),
+          fragment: resource.fragment.clone(),
+          description_file: resource
+            .description_data
+            .as_ref()
+            .map(|data| data.path().to_string_lossy().to_string()),
+        }),
+        ResolveResult::Ignored => None,
+      };
+      // the resolver reads from the native file system
+      let options = &self.snapshot_options;
+      let mut snapshot = Snapshot::default();
+      snapshot.add_paths(
+        file_dependencies.iter(),
+        options.resolve,
+        options,
+        &NativeFileSystem,
+      );
+      snapshot.add_paths(
+        missing_dependencies.iter(),
+        options.resolve,
+        options,
+        &NativeFileSystem,
+      );
+      storage.set(
+        cache_key,
+        ResolveItem {
+          resource,
+          file_dependencies: to_strings(&file_dependencies),
+          missing_dependencies: to_strings(&missing_dependencies),
+          snapshot,
+        },
+      );
+    }
+
+    args_file_dependencies.extend(file_dependencies);
+    args_missing_dependencies.extend(missing_dependencies);
+    result
+  }
+}
+
+impl ResolveItem {
+  fn restore(&self, snapshot_options: &SnapshotOptions) -> Option<ResolveResult> {
+    if !self
+      .snapshot
+      .is_unchanged(snapshot_options, &NativeFileSystem)
+    {
+      return None;
+    }
+    let Some(resource) = &self.resource else {
+      return Some(ResolveResult::Ignored);
+    };
+    let description_data = match &resource.description_file {
+      Some(path) => {
+        let json = serde_json::from_slice(&fs::read(path).ok()?).ok()?;
+        Some(DescriptionData::new(PathBuf::from(path), Arc::new(json)))
+      }
+      None => None,
+    };
+    Some(ResolveResult::Resource(Resource {
+      path: Utf8PathBuf::from(resource.path.clone()),
+      query: resource.query.clone(),
+      fragment: resource.fragment.clone(),
+      description_data,
+    }))
+  }
+}
+
+impl Persistable for ResolveItem {
+  fn to_bytes(&self) -> Option<Vec<u8>> {
+    rspack_cacheable::to_bytes(self, &()).ok()
+  }
+
+  fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    rspack_cacheable::from_bytes(bytes, &()).ok()
+  }
+}
@@ -0,0 +1,580 @@
+use std::{
+  fs,
+  hash::Hasher,
+  sync::atomic::{AtomicBool, Ordering},
+  time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use dashmap::DashMap;
+use rspack_cacheable::{cacheable, from_bytes, to_bytes};
+use rspack_collections::{Identifier, IdentifierDashMap, IdentifierDashSet};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_paths::Utf8PathBuf;
+
+use super::{Persistable, Storage};
+use crate::{Context, FileSystemCacheOptions, SnapshotStrategy};
+
+/// Bump it when the layout of the pack file changes.
+const PACK_FORMAT_VERSION: &str = "3";
+/// One month in milliseconds, used when `max_age` is not set.
+const DEFAULT_MAX_AGE: u64 = 1000 * 60 * 60 * 24 * 30;
+
+#[cacheable]
+struct Pack {
+  /// Hash of the cache version and the build dependencies,
+  /// a pack with a different version is dropped on load.
+  version: String,
+  entries: Vec<PackEntry>,
+}
+
+#[cacheable]
+#[derive(Debug, Clone)]
+struct PackEntry {
+  key: String,
+  /// Milliseconds since unix epoch when the entry was last read or written.
+  last_used: u64,
+  data: Vec<u8>,
+}
+
+/// A storage which keeps hot items in memory and persists them into
+/// a pack file under the cache location when the compiler becomes idle.
+#[derive(Debug)]
+pub struct FileSystemStorage<Item> {
+  name: String,
+  pack_path: Utf8PathBuf,
+  version: String,
+  max_age: u64,
+  profile: bool,
+  /// Decoded items used by the current process.
+  memory: IdentifierDashMap<Item>,
+  /// Serialized entries, mirrors the content of the pack file.
+  persisted: IdentifierDashMap<PackEntry>,
+  /// Items set since the last flush, which need to be serialized.
+  pending: IdentifierDashSet,
+  dirty: AtomicBool,
+}
+
+impl<Item> FileSystemStorage<Item>
+where
+  Item: Persistable,
+{
+  pub fn new(
+    name: &str,
+    options: &FileSystemCacheOptions,
+    context: &Context,
+    build_dependencies_strategy: SnapshotStrategy,
+  ) -> Self {
+    let pack_path = cache_location(options, context).join(format!("{name}.pack"));
+    let version = pack_version(options, context, build_dependencies_strategy);
+    let max_age = if options.max_age == 0 {
+      DEFAULT_MAX_AGE
+    } else {
+      options.max_age as u64
+    };
+
+    let storage = Self {
+      name: name.to_string(),
+      pack_path,
+      version,
+      max_age,
+      profile: options.profile,
+      memory: DashMap::default(),
+      persisted: DashMap::default(),
+      pending: Default::default(),
+      dirty: AtomicBool::new(false),
+    };
+    storage.restore();
+    storage
+  }
+
+  fn restore(&self) {
+    let start = Instant::now();
+    let Ok(bytes) = fs::read(&self.pack_path) else {
+      return;
+    };
+    let pack = match from_bytes::<Pack, ()>(&bytes, &()) {
+      Ok(pack) => pack,
+      Err(err) => {
+        tracing::warn!("failed to restore cache pack {}: {err}", self.pack_path);
+        return;
+      }
+    };
+    if pack.version != self.version {
+      // build dependencies or cache version changed, the stale pack will be overwritten
+      self.dirty.store(true, Ordering::Relaxed);
+      return;
+    }
+
+    let now = now();
+    for entry in pack.entries {
+      if self.is_expired(&entry, now) {
+        self.dirty.store(true, Ordering::Relaxed);
+        continue;
+      }
+      self
+        .persisted
+        .insert(Identifier::from(entry.key.as_str()), entry);
+    }
+
+    if self.profile {
+      tracing::info!(
+        "restored {} cache entries of {} from {} in {:?}",
+        self.persisted.len(),
+        self.name,
+        self.pack_path,
+        start.elapsed()
+      );
+    }
+  }
+
+  fn store(&self) {
+    let start = Instant::now();
+    let now = now();
+
+    for id in self.pending.iter() {
+      let Some(item) = self.memory.get(&*id) else {
+        continue;
+      };
+      // items that can not be serialized only live in memory
+      if let Some(data) = item.to_bytes() {
+        self.persisted.insert(
+          *id,
+          PackEntry {
+            key: id.to_string(),
+            last_used: now,
+            data,
+          },
+        );
+      }
+    }
+    self.pending.clear();
+
+    let entries = self
+      .persisted
+      .iter()
+      .filter(|entry| !self.is_expired(entry, now))
+      .map(|entry| entry.clone())
+      .collect::<Vec<_>>();
+    let count = entries.len();
+    let pack = Pack {
+      version: self.version.clone(),
+      entries,
+    };
+
+    if let Err(err) = self.write_pack(&pack) {
+      tracing::warn!("failed to store cache pack {}: {err}", self.pack_path);
+      return;
+    }
+    self.dirty.store(false, Ordering::Relaxed);
+
+    if self.profile {
+      tracing::info!(
+        "stored {} cache entries of {} to {} in {:?}",
+        count,
+        self.name,
+        self.pack_path,
+        start.elapsed()
+      );
+    }
+  }
+
+  fn write_pack(&self, pack: &Pack) -> Result<(), String> {
+    let bytes = to_bytes(pack, &()).map_err(|e| e.to_string())?;
+    if let Some(dir) = self.pack_path.parent() {
+      fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    // write to a temporary file first, so an interrupted process never leaves a broken pack
+    let temp_path = self.pack_path.with_extension("pack.tmp");
+    fs::write(&temp_path, bytes).map_err(|e| e.to_string())?;
+    fs::rename(&temp_path, &self.pack_path).map_err(|e| e.to_string())
+  }
+
+  fn is_expired(&self, entry: &PackEntry, now: u64) -> bool {
+    now.saturating_sub(entry.last_used) > self.max_age
+  }
+
+  /// Records the read, which is written with the next flush caused by a change. Reads alone
+  /// only cause a flush when the entry is about to expire, so restoring from an unchanged
+  /// pack doesn't write it again.
+  fn touch(&self, id: &Identifier) {
+    if let Some(mut entry) = self.persisted.get_mut(id) {
+      let now = now();
+      if now.saturating_sub(entry.last_used) > self.max_age / 2 {
+        self.dirty.store(true, Ordering::Relaxed);
+      }
+      entry.last_used = now;
+    }
+  }
+}
+
+impl<Item> Storage<Item> for FileSystemStorage<Item>
+where
+  Item: Clone + std::fmt::Debug + Send + Sync + Persistable,
+{
+  fn get(&self, id: &Identifier) -> Option<Item> {
+    if let Some(item) = self.memory.get(id) {
+      self.touch(id);
+      return Some(item.clone());
+    }
+    let item = {
+      let entry = self.persisted.get(id)?;
+      Item::from_bytes(&entry.data)
+    };
+    match item {
+      Some(item) => {
+        self.memory.insert(*id, item.clone());
+        self.touch(id);
+        Some(item)
+      }
+      None => {
+        // the entry is broken or was written by an incompatible version
+        self.persisted.remove(id);
+        self.dirty.store(true, Ordering::Relaxed);
+        None
+      }
+    }
+  }
+
+  fn set(&self, id: Identifier, data: Item) {
+    self.memory.insert(id, data);
+    self.pending.insert(id);
+    self.dirty.store(true, Ordering::Relaxed);
+  }
+
+  fn remove(&self, id: &Identifier) {
+    self.memory.remove(id);
+    self.pending.remove(id);
+    if self.persisted.remove(id).is_some() {
+      self.dirty.store(true, Ordering::Relaxed);
+    }
+  }
+
+  fn begin_idle(&self, is_outdated: &dyn Fn(&Identifier) -> bool) {
+    // items set by the finished compilation are still pending and always kept
+    self
+      .memory
+      .retain(|id, _| self.pending.contains(id) || !is_outdated(id));
+    self.persisted.retain(|id, _| {
+      let keep = self.pending.contains(id) || !is_outdated(id);
+      if !keep {
+        self.dirty.store(true, Ordering::Relaxed);
+      }
+      keep
+    });
+    if self.dirty.load(Ordering::Relaxed) {
+      self.store();
+    }
+  }
+}
+
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|d| d.as_millis() as u64)
+    .unwrap_or_default()
+}
+
+fn cache_location(options: &FileSystemCacheOptions, context: &Context) -> Utf8PathBuf {
+  if !options.cache_location.is_empty() {
+    return context.as_path().join(&options.cache_location);
+  }
+  let directory = if options.cache_directory.is_empty() {
+    context.as_path().join("node_modules/.cache/rspack")
+  } else {
+    context.as_path().join(&options.cache_directory)
+  };
+  let name = if options.name.is_empty() {
+    "default"
+  } else {
+    options.name.as_str()
+  };
+  directory.join(name)
+}
+
+/// Hash the cache version together with the state of every build dependency,
+/// so that changing the config or the toolchain invalidates the whole pack.
+///
+/// Build dependencies are compared with the `snapshot.buildDependencies` strategy, which is
+/// part of the version as well.
+fn pack_version(
+  options: &FileSystemCacheOptions,
+  context: &Context,
+  strategy: SnapshotStrategy,
+) -> String {
+  let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+  hasher.write(PACK_FORMAT_VERSION.as_bytes());
+  hasher.write(options.version.as_bytes());
+  hasher.write(&[strategy.timestamp as u8, strategy.hash as u8]);
+
+  let mut build_dependencies = options.build_dependencies.clone();
+  build_dependencies.sort();
+  for dependency in build_dependencies {
+    let path = context.as_path().join(&dependency);
+    hasher.write(path.as_str().as_bytes());
+    let Ok(metadata) = fs::metadata(&path) else {
+      hasher.write(b"<missing>");
+      continue;
+    };
+    if strategy.timestamp
+      && let Ok(modified) = metadata.modified()
+    {
+      let timestamp = modified
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or_default();
+      hasher.write(&timestamp.to_le_bytes());
+    }
+    if strategy.hash
+      && let Ok(content) = fs::read(&path)
+    {
+      hasher.write(&content);
+    }
+  }
+
+  hasher.digest(&HashDigest::Hex).encoded().to_string()
+}
+
+#[cfg(test)]
+mod test {
+  use rspack_fs::NativeFileSystem;
+  use rspack_loader_runner::{Content, LoaderResult};
+  use rspack_sources::{RawSource, Source, SourceExt};
+
+  use super::*;
+  use crate::{
+    old_cache::occasion::{BuildModuleOccasion, CodeGenerateOccasion},
+    AssetInfo, CodeGenerationJob, CodeGenerationResult, RenderManifestEntry, RuntimeGlobals,
+    SnapshotOptions, SourceType,
+  };
+
+  fn options(cache_location: &str, version: &str) -> FileSystemCacheOptions {
+    FileSystemCacheOptions {
+      cache_location: cache_location.to_string(),
+      version: version.to_string(),
+      ..Default::default()
+    }
+  }
+
+  fn location(name: &str) -> String {
+    std::env::temp_dir()
+      .join(format!("rspack-old-cache-{name}-{}", std::process::id()))
+      .to_string_lossy()
+      .to_string()
+  }
+
+  #[test]
+  fn should_restore_items_from_pack() {
+    let location = location("restore");
+    let context = Context::from("/");
+    let id = Identifier::from("a.js|hash");
+    let new_storage = |version: &str, strategy: SnapshotStrategy| {
+      FileSystemStorage::<RuntimeGlobals>::new(
+        "test",
+        &options(&location, version),
+        &context,
+        strategy,
+      )
+    };
+
+    let storage = new_storage("1", SnapshotStrategy::TIMESTAMP_AND_HASH);
+    storage.set(id, RuntimeGlobals::REQUIRE | RuntimeGlobals::MODULE);
+    storage.begin_idle(&|_| false);
+
+    let storage = new_storage("1", SnapshotStrategy::TIMESTAMP_AND_HASH);
+    assert_eq!(
+      storage.get(&id),
+      Some(RuntimeGlobals::REQUIRE | RuntimeGlobals::MODULE)
+    );
+
+    // a different snapshot strategy of the build dependencies drops the whole pack
+    let storage = new_storage("1", SnapshotStrategy::HASH);
+    assert_eq!(storage.get(&id), None);
+
+    // a different cache version drops the whole pack
+    let storage = new_storage("2", SnapshotStrategy::TIMESTAMP_AND_HASH);
+    assert_eq!(storage.get(&id), None);
+
+    let _ = fs::remove_dir_all(&location);
+  }
+
+  #[test]
+  fn should_not_mark_pack_dirty_on_reads() {
+    let location = location("touch");
+    let context = Context::from("/");
+    let id = Identifier::from("a.js|hash");
+    let new_storage = || {
+      FileSystemStorage::<RuntimeGlobals>::new(
+        "test",
+        &options(&location, "1"),
+        &context,
+        SnapshotStrategy::TIMESTAMP_AND_HASH,
+      )
+    };
+
+    let storage = new_storage();
+    storage.set(id, RuntimeGlobals::REQUIRE);
+    storage.begin_idle(&|_| false);
+    assert!(!storage.dirty.load(Ordering::Relaxed));
+
+    let storage = new_storage();
+    assert_eq!(storage.get(&id), Some(RuntimeGlobals::REQUIRE));
+    assert_eq!(storage.get(&id), Some(RuntimeGlobals::REQUIRE));
+    assert!(!storage.dirty.load(Ordering::Relaxed));
+
+    // entries which are about to expire are written again on read
+    if let Some(mut entry) = storage.persisted.get_mut(&id) {
+      entry.last_used = now() - storage.max_age + 1000;
+    }
+    assert_eq!(storage.get(&id), Some(RuntimeGlobals::REQUIRE));
+    assert!(storage.dirty.load(Ordering::Relaxed));
+
+    let _ = fs::remove_dir_all(&location);
+  }
+
+  #[test]
+  fn should_reuse_loader_results_of_unchanged_modules() {
+    let location = location("build-module");
+    let context = Context::from("/");
+    let new_occasion = || {
+      BuildModuleOccasion::new(
+        Some(Box::new(FileSystemStorage::new(
+          "build-module",
+          &options(&location, "1"),
+          &context,
+          SnapshotStrategy::TIMESTAMP_AND_HASH,
+        ))),
+        // compare by content, the rewrite below may keep the modified time
+        SnapshotOptions {
+          module: SnapshotStrategy::HASH,
+          ..Default::default()
+        },
+      )
+    };
+    let resource = std::path::PathBuf::from(&location).join("a.js");
+    fs::create_dir_all(&location).expect("should create cache location");
+    fs::write(&resource, "a").expect("should write resource");
+    let module = Identifier::from(format!("javascript/auto|loader.js!{}", resource.display()));
+
+    let occasion = new_occasion();
+    assert!(occasion.get(&module, &NativeFileSystem).is_none());
+    occasion.set(
+      module,
+      &LoaderResult {
+        cacheable: true,
+        file_dependencies: [resource.clone()].into_iter().collect(),
+        context_dependencies: Default::default(),
+        missing_dependencies: Default::default(),
+        build_dependencies: Default::default(),
+        content: Content::String("transformed a".to_string()),
+        source_map: None,
+        additional_data: None,
+        parse_meta: Default::default(),
+      },
+      &NativeFileSystem,
+    );
+    occasion.begin_idle(&|_| false);
+
+    // the occasion of another compiler restores the result from the pack
+    let occasion = new_occasion();
+    let result = occasion
+      .get(&module, &NativeFileSystem)
+      .expect("should restore loader result");
+    assert_eq!(result.content.as_bytes(), b"transformed a");
+    assert!(result.file_dependencies.contains(&resource));
+
+    // a changed file dependency invalidates the result
+    fs::write(&resource, "b").expect("should write resource");
+    assert!(new_occasion().get(&module, &NativeFileSystem).is_none());
+
+    let _ = fs::remove_dir_all(&location);
+  }
+
+  #[test]
+  fn should_reuse_code_generation_results_across_instances() {
+    let location = location("code-generate");
+    let context = Context::from("/");
+    let new_occasion = || {
+      CodeGenerateOccasion::new(Some(Box::new(FileSystemStorage::new(
+        "code-generate",
+        &options(&location, "1"),
+        &context,
+        SnapshotStrategy::TIMESTAMP_AND_HASH,
+      ))))
+    };
+    let job = || CodeGenerationJob {
+      module: Identifier::from("javascript/auto|/src/a.js"),
+      hash: "abc".into(),
+      runtime: Default::default(),
+      runtimes: vec![],
+    };
+
+    let occasion = new_occasion();
+    let (_, _, from_cache) = occasion.use_cache(job(), |_, _| {
+      let mut result = CodeGenerationResult::default();
+      result.add(SourceType::JavaScript, RawSource::from("a").boxed());
+      result.runtime_requirements = RuntimeGlobals::REQUIRE;
+      Ok(result)
+    });
+    assert!(!from_cache);
+    occasion.begin_idle(&|_| false);
+
+    // the occasion of another compiler restores the result from the pack
+    let occasion = new_occasion();
+    let (result, _, from_cache) = occasion.use_cache(job(), |_, _| panic!("should read the cache"));
+    assert!(from_cache);
+    let result = result.expect("should have result");
+    assert_eq!(
+      result
+        .get(&SourceType::JavaScript)
+        .map(|source| source.source().to_string()),
+      Some("a".to_string())
+    );
+    assert_eq!(result.runtime_requirements, RuntimeGlobals::REQUIRE);
+
+    let _ = fs::remove_dir_all(&location);
+  }
+
+  #[test]
+  fn should_restore_chunk_assets_from_pack() {
+    let location = location("create-chunk-assets");
+    let context = Context::from("/");
+    let new_storage = || {
+      FileSystemStorage::<Vec<RenderManifestEntry>>::new(
+        "create-chunk-assets",
+        &options(&location, "1"),
+        &context,
+        SnapshotStrategy::TIMESTAMP_AND_HASH,
+      )
+    };
+    let id = Identifier::from("main|abc");
+
+    let storage = new_storage();
+    let info = AssetInfo {
+      immutable: Some(true),
+      content_hash: ["abc".to_string()].into_iter().collect(),
+      javascript_module: Some(false),
+      ..Default::default()
+    };
+    storage.set(
+      id,
+      vec![RenderManifestEntry::new(
+        RawSource::from("console.log(1)").boxed(),
+        "main.abc.js".to_string(),
+        info,
+        false,
+        false,
+      )],
+    );
+    storage.begin_idle(&|_| false);
+
+    let storage = new_storage();
+    let entries = storage.get(&id).expect("should restore chunk assets");
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].filename(), "main.abc.js");
+    assert_eq!(entries[0].source().source(), "console.log(1)");
+    assert_eq!(entries[0].info.immutable, Some(true));
+    assert!(entries[0].info.content_hash.contains("abc"));
+
+    let _ = fs::remove_dir_all(&location);
+  }
+}
@@ -1,3 +1,5 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
 use dashmap::DashMap;
 use rspack_collections::{Identifier, IdentifierDashMap};
 
@@ -5,13 +7,19 @@ use super::Storage;
 
 #[derive(Debug)]
 pub struct MemoryStorage<Item> {
-  data: IdentifierDashMap<Item>,
+  /// Items with the generation they were last used in.
+  data: IdentifierDashMap<(Item, u32)>,
+  generation: AtomicU32,
+  /// Unused items are evicted after this many generations, `0` means never.
+  max_generations: u32,
 }
 
 impl<Item> MemoryStorage<Item> {
-  pub fn new() -> Self {
+  pub fn new(max_generations: u32) -> Self {
     Self {
       data: DashMap::default(),
+      generation: AtomicU32::new(0),
+      max_generations,
     }
   }
 }
@@ -21,12 +29,68 @@ where
   Item: Clone + std::fmt::Debug + Send + Sync,
 {
   fn get(&self, id: &Identifier) -> Option<Item> {
-    self.data.get(id).map(|item| item.clone())
+    let mut item = self.data.get_mut(id)?;
+    item.1 = self.generation.load(Ordering::Relaxed);
+    Some(item.0.clone())
   }
   fn set(&self, id: Identifier, data: Item) {
-    self.data.insert(id, data);
+    self
+      .data
+      .insert(id, (data, self.generation.load(Ordering::Relaxed)));
   }
   fn remove(&self, id: &Identifier) {
     self.data.remove(id);
   }
+  fn begin_idle(&self, is_outdated: &dyn Fn(&Identifier) -> bool) {
+    let generation = self.generation.fetch_add(1, Ordering::Relaxed);
+    self.data.retain(|id, (_, used)| {
+      // items used by the finished compilation are always kept
+      if *used == generation {
+        return true;
+      }
+      if is_outdated(id) {
+        return false;
+      }
+      self.max_generations == 0 || generation - *used < self.max_generations
+    });
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn should_evict_unused_items() {
+    let storage = MemoryStorage::<u32>::new(2);
+    let a = Identifier::from("a");
+    let b = Identifier::from("b");
+    storage.set(a, 1);
+    storage.set(b, 2);
+    storage.begin_idle(&|_| false);
+
+    assert_eq!(storage.get(&a), Some(1));
+    storage.begin_idle(&|_| false);
+    storage.begin_idle(&|_| false);
+
+    // `b` has not been used for 2 generations
+    assert_eq!(storage.get(&b), None);
+    assert_eq!(storage.get(&a), Some(1));
+  }
+
+  #[test]
+  fn should_evict_outdated_items() {
+    let storage = MemoryStorage::<u32>::new(0);
+    let a = Identifier::from("javascript/auto|/src/a.js|hash1");
+    storage.set(a, 1);
+    storage.begin_idle(&|_| false);
+
+    let b = Identifier::from("javascript/auto|/src/a.js|hash2");
+    storage.set(b, 2);
+    storage.begin_idle(&|id| id.contains("/src/a.js"));
+
+    // the outdated item is removed while the item used by the last compilation is kept
+    assert_eq!(storage.get(&a), None);
+    assert_eq!(storage.get(&b), Some(2));
+  }
 }
@@ -2,26 +2,62 @@ use std::fmt::Debug;
 
 use rspack_collections::Identifier;
 
-use crate::CacheOptions;
+use crate::{CacheOptions, CompilerOptions};
 
+mod filesystem;
 mod memory;
+use filesystem::FileSystemStorage;
 use memory::MemoryStorage;
 
 pub trait Storage<Item>: Debug + Send + Sync {
   fn get(&self, id: &Identifier) -> Option<Item>;
   fn set(&self, id: Identifier, data: Item);
   fn remove(&self, id: &Identifier);
-  // fn begin_idle(&self);
+  /// Called when the compiler becomes idle. Storages evict items that were not used
+  /// by the finished compilation and are `is_outdated`, and may flush data here.
+  fn begin_idle(&self, _is_outdated: &dyn Fn(&Identifier) -> bool) {}
   // fn end_idle(&self);
   // fn clear(&self);
 }
 
-pub fn new_storage<Item>(options: &CacheOptions) -> Option<Box<dyn Storage<Item>>>
+/// Cache items which can be written to and restored from the persistent storage.
+///
+/// Returning `None` from `to_bytes` means the item can only live in memory.
+pub trait Persistable: Sized {
+  fn to_bytes(&self) -> Option<Vec<u8>>;
+  fn from_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+pub fn new_storage<Item>(options: &CompilerOptions, name: &str) -> Option<Box<dyn Storage<Item>>>
 where
-  Item: Debug + Clone + Send + Sync + 'static,
+  Item: Debug + Clone + Send + Sync + Persistable + 'static,
 {
-  match options {
+  match &options.cache {
     CacheOptions::Disabled => None,
-    _ => Some(Box::new(MemoryStorage::new())),
+    CacheOptions::Memory(memory_options) => {
+      Some(Box::new(MemoryStorage::new(memory_options.max_generations)))
+    }
+    CacheOptions::FileSystem(fs_options) => Some(Box::new(FileSystemStorage::new(
+      name,
+      fs_options,
+      &options.context,
+      options.snapshot.build_dependencies,
+    ))),
+  }
+}
+
+/// Like [new_storage], but only for the filesystem cache. Used by occasions which validate
+/// their items against the files on disk, which is not needed when the compiler only reuses
+/// the items of its own previous compilations.
+pub fn new_persistent_storage<Item>(
+  options: &CompilerOptions,
+  name: &str,
+) -> Option<Box<dyn Storage<Item>>>
+where
+  Item: Debug + Clone + Send + Sync + Persistable + 'static,
+{
+  match &options.cache {
+    CacheOptions::FileSystem(_) => new_storage(options, name),
+    _ => None,
   }
 }
@@ -1,3 +1,5 @@
+use rspack_paths::Utf8PathBuf;
+
 use crate::{
   CacheOptions, Context, Experiments, Mode, ModuleOptions, NodeOption, Optimization, OutputOptions,
   Resolve, SnapshotOptions, StatsOptions,
@@ -19,6 +21,10 @@ pub struct CompilerOptions {
   pub optimization: Optimization,
   pub profile: bool,
   pub bail: bool,
+  /// Read the module and chunk ids of the last compilation from this file.
+  pub records_input_path: Option<Utf8PathBuf>,
+  /// Write the module and chunk ids to this file.
+  pub records_output_path: Option<Utf8PathBuf>,
   pub __references: References,
 }
 
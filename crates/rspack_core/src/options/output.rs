@@ -101,8 +101,8 @@ impl From<ChunkLoading> for String {
   }
 }
 
-impl From<&ChunkLoading> for &str {
-  fn from(value: &ChunkLoading) -> Self {
+impl<'a> From<&'a ChunkLoading> for &'a str {
+  fn from(value: &'a ChunkLoading) -> Self {
     match value {
       ChunkLoading::Enable(ty) => ty.into(),
       ChunkLoading::Disable => "false",
@@ -117,7 +117,8 @@ pub enum ChunkLoadingType {
   Require,
   AsyncNode,
   Import,
-  // TODO: Custom
+  /// A chunk loading type provided by a plugin, see `CustomChunkLoadingPlugin` in `rspack_plugin_runtime`.
+  Custom(String),
 }
 
 impl From<&str> for ChunkLoadingType {
@@ -128,7 +129,7 @@ impl From<&str> for ChunkLoadingType {
       "require" => Self::Require,
       "async-node" => Self::AsyncNode,
       "import" => Self::Import,
-      _ => unimplemented!("custom chunkLoading in not supported yet"),
+      _ => Self::Custom(value.to_string()),
     }
   }
 }
@@ -139,19 +140,20 @@ impl From<ChunkLoadingType> for String {
   }
 }
 
-impl From<&ChunkLoadingType> for &str {
-  fn from(value: &ChunkLoadingType) -> Self {
+impl<'a> From<&'a ChunkLoadingType> for &'a str {
+  fn from(value: &'a ChunkLoadingType) -> Self {
     match value {
       ChunkLoadingType::Jsonp => "jsonp",
       ChunkLoadingType::ImportScripts => "import-scripts",
       ChunkLoadingType::Require => "require",
       ChunkLoadingType::AsyncNode => "async-node",
       ChunkLoadingType::Import => "import",
+      ChunkLoadingType::Custom(value) => value.as_str(),
     }
   }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum WasmLoading {
   Enable(WasmLoadingType),
   Disable,
@@ -166,11 +168,24 @@ impl From<&str> for WasmLoading {
   }
 }
 
-#[derive(Debug, Clone)]
+impl<'a> From<&'a WasmLoading> for &'a str {
+  fn from(value: &'a WasmLoading) -> Self {
+    match value {
+      WasmLoading::Enable(ty) => ty.into(),
+      WasmLoading::Disable => "false",
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum WasmLoadingType {
   Fetch,
   AsyncNode,
   AsyncNodeModule,
+  /// Uses `fetch` in browsers and workers, and reads the file with `fs` in node.
+  Universal,
+  /// A wasm loading type provided by a plugin, see `CustomWasmLoadingPlugin` in `rspack_plugin_wasm`.
+  Custom(String),
 }
 
 impl From<&str> for WasmLoadingType {
@@ -179,7 +194,20 @@ impl From<&str> for WasmLoadingType {
       "fetch" => Self::Fetch,
       "async-node" => Self::AsyncNode,
       "async-node-module" => Self::AsyncNodeModule,
-      _ => todo!(),
+      "universal" => Self::Universal,
+      _ => Self::Custom(value.to_string()),
+    }
+  }
+}
+
+impl<'a> From<&'a WasmLoadingType> for &'a str {
+  fn from(value: &'a WasmLoadingType) -> Self {
+    match value {
+      WasmLoadingType::Fetch => "fetch",
+      WasmLoadingType::AsyncNode => "async-node",
+      WasmLoadingType::AsyncNodeModule => "async-node-module",
+      WasmLoadingType::Universal => "universal",
+      WasmLoadingType::Custom(value) => value.as_str(),
     }
   }
 }
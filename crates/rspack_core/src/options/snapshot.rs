@@ -1,2 +1,80 @@
-#[derive(Debug, Clone, Default)]
-pub struct SnapshotOptions;
+use rspack_cacheable::cacheable;
+use rspack_regex::RspackRegex;
+
+/// How a file is compared with its previous state.
+#[cacheable]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SnapshotStrategy {
+  /// Compare the modified time of the file.
+  pub timestamp: bool,
+  /// Compare the content hash of the file.
+  pub hash: bool,
+}
+
+impl SnapshotStrategy {
+  pub const TIMESTAMP: Self = Self {
+    timestamp: true,
+    hash: false,
+  };
+  pub const HASH: Self = Self {
+    timestamp: false,
+    hash: true,
+  };
+  pub const TIMESTAMP_AND_HASH: Self = Self {
+    timestamp: true,
+    hash: true,
+  };
+}
+
+#[derive(Debug, Clone)]
+pub enum PathMatcher {
+  String(String),
+  Regexp(RspackRegex),
+}
+
+impl PathMatcher {
+  pub fn try_match(&self, path: &str) -> bool {
+    match self {
+      Self::String(prefix) => path.starts_with(prefix.as_str()),
+      Self::Regexp(regexp) => regexp.test(path),
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct SnapshotOptions {
+  /// Paths managed by a package manager, files in them are snapshotted by package version.
+  pub managed_paths: Vec<PathMatcher>,
+  /// Paths that never change, files in them are never checked again.
+  pub immutable_paths: Vec<PathMatcher>,
+  /// Snapshot strategy for build dependencies of the persistent cache.
+  pub build_dependencies: SnapshotStrategy,
+  /// Snapshot strategy for files that modules depend on.
+  pub module: SnapshotStrategy,
+  /// Snapshot strategy for files and directories touched by resolving.
+  pub resolve: SnapshotStrategy,
+}
+
+impl Default for SnapshotOptions {
+  fn default() -> Self {
+    Self {
+      managed_paths: vec![PathMatcher::Regexp(
+        RspackRegex::new(r"[\\/]node_modules[\\/]").expect("should be a valid regex"),
+      )],
+      immutable_paths: vec![],
+      build_dependencies: SnapshotStrategy::TIMESTAMP_AND_HASH,
+      module: SnapshotStrategy::TIMESTAMP,
+      resolve: SnapshotStrategy::TIMESTAMP,
+    }
+  }
+}
+
+impl SnapshotOptions {
+  pub fn is_managed_path(&self, path: &str) -> bool {
+    self.managed_paths.iter().any(|m| m.try_match(path))
+  }
+
+  pub fn is_immutable_path(&self, path: &str) -> bool {
+    self.immutable_paths.iter().any(|m| m.try_match(path))
+  }
+}
@@ -1,4 +1,90 @@
+use rspack_regex::RspackRegex;
+
 #[derive(Debug, Default)]
 pub struct StatsOptions {
   pub colors: bool,
 }
+
+/// Named stats presets, same as the string values of `stats` in the configuration.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StatsPreset {
+  None,
+  Summary,
+  ErrorsOnly,
+  ErrorsWarnings,
+  Minimal,
+  #[default]
+  Normal,
+  Detailed,
+  Verbose,
+}
+
+impl From<&str> for StatsPreset {
+  fn from(value: &str) -> Self {
+    match value {
+      "none" => StatsPreset::None,
+      "summary" => StatsPreset::Summary,
+      "errors-only" => StatsPreset::ErrorsOnly,
+      "errors-warnings" => StatsPreset::ErrorsWarnings,
+      "minimal" => StatsPreset::Minimal,
+      "detailed" => StatsPreset::Detailed,
+      "verbose" => StatsPreset::Verbose,
+      _ => StatsPreset::Normal,
+    }
+  }
+}
+
+/// A filter of `excludeModules` and `excludeAssets`.
+#[derive(Debug, Clone)]
+pub enum StatsFilter {
+  Bool(bool),
+  /// Matches a whole path segment of the identifier, e.g. `node_modules` matches
+  /// `./node_modules/foo/index.js` but not `./my_node_modules/index.js`.
+  String(String),
+  Regex(RspackRegex),
+}
+
+impl StatsFilter {
+  pub fn test(&self, ident: &str) -> bool {
+    match self {
+      StatsFilter::Bool(value) => *value,
+      StatsFilter::String(value) => {
+        !value.is_empty()
+          && ident.match_indices(value.as_str()).any(|(start, _)| {
+            let before = ident[..start].chars().next_back();
+            let after = ident[start + value.len()..].chars().next();
+            matches!(before, Some('/' | '\\'))
+              && matches!(after, None | Some('/' | '\\' | '!' | '?'))
+          })
+      }
+      StatsFilter::Regex(regex) => regex.test(ident),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn stats_filter_string_matches_path_segments() {
+    let filter = StatsFilter::String("node_modules".to_string());
+    assert!(filter.test("./node_modules/foo/index.js"));
+    assert!(filter.test("css-loader!./node_modules"));
+    assert!(!filter.test("./my_node_modules/index.js"));
+    assert!(!filter.test("node_modules/foo.js"));
+
+    let filter = StatsFilter::String("foo.js".to_string());
+    assert!(filter.test("./src/foo.js?raw"));
+    assert!(!filter.test("./src/foo.jsx"));
+  }
+
+  #[test]
+  fn stats_filter_regex_and_bool() {
+    let filter = StatsFilter::Regex(RspackRegex::new("\\.css$").expect("should be valid regex"));
+    assert!(filter.test("./src/index.css"));
+    assert!(!filter.test("./src/index.js"));
+    assert!(StatsFilter::Bool(true).test("./src/index.js"));
+    assert!(!StatsFilter::Bool(false).test("./src/index.js"));
+  }
+}
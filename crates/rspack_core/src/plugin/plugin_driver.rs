@@ -2,12 +2,13 @@ use std::sync::{Arc, Mutex};
 
 use derivative::Derivative;
 use rspack_error::Diagnostic;
+use rspack_hook::HookProfiler;
 use rspack_util::fx_hash::FxDashMap;
 
 use crate::{
   ApplyContext, BoxedParserAndGeneratorBuilder, CompilationHooks, CompilerHooks, CompilerOptions,
   ConcatenatedModuleHooks, ContextModuleFactoryHooks, ModuleType, NormalModuleFactoryHooks,
-  NormalModuleHooks, Plugin, PluginContext, ResolverFactory,
+  NormalModuleHooks, OptimizeAssetsStage, Plugin, PluginContext, ResolverFactory,
 };
 
 #[derive(Derivative)]
@@ -27,6 +28,8 @@ pub struct PluginDriver {
   pub context_module_factory_hooks: ContextModuleFactoryHooks,
   pub normal_module_hooks: NormalModuleHooks,
   pub concatenated_module_hooks: ConcatenatedModuleHooks,
+  /// Records the tap calls of the hooks above, only set to the hooks when `profile` is enabled
+  pub hook_profiler: HookProfiler,
 }
 
 impl PluginDriver {
@@ -56,6 +59,17 @@ impl PluginDriver {
         .apply(PluginContext::with_context(&mut apply_context), &options)
         .expect("TODO:");
     }
+    compilation_hooks.process_assets.tap(OptimizeAssetsStage);
+
+    let hook_profiler = HookProfiler::default();
+    if options.profile {
+      compiler_hooks.set_profiler(&hook_profiler);
+      compilation_hooks.set_profiler(&hook_profiler);
+      normal_module_factory_hooks.set_profiler(&hook_profiler);
+      context_module_factory_hooks.set_profiler(&hook_profiler);
+      normal_module_hooks.set_profiler(&hook_profiler);
+      concatenated_module_hooks.set_profiler(&hook_profiler);
+    }
 
     Arc::new(Self {
       options: options.clone(),
@@ -69,6 +83,7 @@ impl PluginDriver {
       context_module_factory_hooks,
       normal_module_hooks,
       concatenated_module_hooks,
+      hook_profiler,
     })
   }
 
@@ -250,6 +250,16 @@ bitflags! {
     const RSPACK_UNIQUE_ID = 1 << 65;
 
     const HAS_FETCH_PRIORITY = 1 << 66;
+
+    /**
+     * object with all WebAssembly.instance exports
+     */
+    const WASM_INSTANCES = 1 << 67;
+
+    /**
+     * the integrity hashes of the chunks, keyed by source type and chunk id
+     */
+    const SRI_HASHES = 1 << 68;
   }
 }
 
@@ -337,6 +347,8 @@ impl RuntimeGlobals {
       R::HAS_CSS_MODULES => "has css modules",
 
       R::HAS_FETCH_PRIORITY => "has fetch priority",
+      R::WASM_INSTANCES => "__webpack_require__.w",
+      R::SRI_HASHES => "__webpack_require__.sriHashes",
       _ => unreachable!(),
     }
   }
@@ -0,0 +1,367 @@
+use std::{
+  hash::Hasher,
+  path::{Component, Path, PathBuf},
+  time::UNIX_EPOCH,
+};
+
+use dashmap::DashMap;
+use rayon::prelude::*;
+use rspack_cacheable::{
+  cacheable,
+  with::{AsCacheable, AsMap, AsString},
+};
+use rspack_fs::ReadableFileSystem;
+use rspack_hash::{HashFunction, RspackHash};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use crate::{SnapshotOptions, SnapshotStrategy};
+
+#[cacheable]
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FileSnapshot {
+  /// The path did not exist when the snapshot was taken.
+  Missing,
+  /// The path is inside an immutable path and is never checked again.
+  Immutable,
+  /// The path is inside a managed path, identified by `name@version` of the owning package.
+  Managed(String),
+  /// The path is compared by its modified time and/or its content hash.
+  File {
+    timestamp: Option<u64>,
+    hash: Option<u64>,
+  },
+}
+
+#[cacheable]
+#[derive(Debug, Clone)]
+struct SnapshotEntry {
+  strategy: SnapshotStrategy,
+  state: FileSnapshot,
+}
+
+/// The state of a set of paths at some point in time.
+///
+/// Comparing a snapshot with the file system tells which paths were modified or removed
+/// since it was taken, without relying on a watcher.
+#[cacheable]
+#[derive(Debug, Default, Clone)]
+pub struct Snapshot {
+  #[cacheable(with=AsMap<AsString, AsCacheable>)]
+  entries: HashMap<PathBuf, SnapshotEntry>,
+}
+
+impl Snapshot {
+  pub fn is_empty(&self) -> bool {
+    self.entries.is_empty()
+  }
+
+  pub fn len(&self) -> usize {
+    self.entries.len()
+  }
+
+  /// Snapshot the paths with the given strategy, paths already in the snapshot are overridden.
+  pub fn add_paths<'a>(
+    &mut self,
+    paths: impl Iterator<Item = &'a PathBuf>,
+    strategy: SnapshotStrategy,
+    options: &SnapshotOptions,
+    fs: &dyn ReadableFileSystem,
+  ) {
+    let snapshotter = Snapshotter::new(options, fs);
+    let paths = paths.collect::<Vec<_>>();
+    let entries = paths
+      .into_par_iter()
+      .map(|path| {
+        let state = snapshotter.snapshot(path, strategy);
+        (path.clone(), SnapshotEntry { strategy, state })
+      })
+      .collect::<Vec<_>>();
+    self.entries.extend(entries);
+  }
+
+  /// Whether none of the paths were modified or removed since the snapshot was taken.
+  pub fn is_unchanged(&self, options: &SnapshotOptions, fs: &dyn ReadableFileSystem) -> bool {
+    let snapshotter = Snapshotter::new(options, fs);
+    self
+      .entries
+      .par_iter()
+      .all(|(path, entry)| snapshotter.check(path, entry).is_none())
+  }
+
+  /// Compare the snapshot with the file system, returns the modified paths and the removed paths.
+  pub fn check(
+    &self,
+    options: &SnapshotOptions,
+    fs: &dyn ReadableFileSystem,
+  ) -> (HashSet<PathBuf>, HashSet<PathBuf>) {
+    let snapshotter = Snapshotter::new(options, fs);
+    let changes = self
+      .entries
+      .par_iter()
+      .filter_map(|(path, entry)| {
+        snapshotter
+          .check(path, entry)
+          .map(|removed| (path.clone(), removed))
+      })
+      .collect::<Vec<_>>();
+
+    let mut modified_files = HashSet::default();
+    let mut removed_files = HashSet::default();
+    for (path, removed) in changes {
+      if removed {
+        removed_files.insert(path);
+      } else {
+        modified_files.insert(path);
+      }
+    }
+    (modified_files, removed_files)
+  }
+}
+
+struct Snapshotter<'a> {
+  options: &'a SnapshotOptions,
+  fs: &'a dyn ReadableFileSystem,
+  /// Package root to `name@version`, shared by every file of the package.
+  package_versions: DashMap<PathBuf, Option<String>>,
+}
+
+impl<'a> Snapshotter<'a> {
+  fn new(options: &'a SnapshotOptions, fs: &'a dyn ReadableFileSystem) -> Self {
+    Self {
+      options,
+      fs,
+      package_versions: Default::default(),
+    }
+  }
+
+  fn snapshot(&self, path: &Path, strategy: SnapshotStrategy) -> FileSnapshot {
+    let path_str = path.to_string_lossy();
+    if self.options.is_immutable_path(&path_str) {
+      return FileSnapshot::Immutable;
+    }
+    if self.options.is_managed_path(&path_str)
+      && let Some(version) = self.package_version(path)
+    {
+      return FileSnapshot::Managed(version);
+    }
+    self.file_snapshot(path, strategy, true)
+  }
+
+  fn file_snapshot(
+    &self,
+    path: &Path,
+    strategy: SnapshotStrategy,
+    with_hash: bool,
+  ) -> FileSnapshot {
+    let Ok(metadata) = self.fs.metadata(path) else {
+      return FileSnapshot::Missing;
+    };
+    // directories have no content to hash, they are always compared by timestamp
+    let timestamp = (strategy.timestamp || !metadata.is_file())
+      .then(|| {
+        metadata
+          .modified()
+          .ok()
+          .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+          .map(|duration| duration.as_millis() as u64)
+      })
+      .flatten();
+    let hash = (with_hash && strategy.hash && metadata.is_file())
+      .then(|| self.content_hash(path))
+      .flatten();
+    FileSnapshot::File { timestamp, hash }
+  }
+
+  /// Returns `Some(true)` when the path was removed, `Some(false)` when it was modified
+  /// and `None` when it is unchanged.
+  fn check(&self, path: &Path, entry: &SnapshotEntry) -> Option<bool> {
+    match &entry.state {
+      FileSnapshot::Immutable => None,
+      FileSnapshot::Missing => self.fs.metadata(path).is_ok().then_some(false),
+      FileSnapshot::Managed(version) => {
+        match self.package_version(path) {
+          Some(current) if &current == version => None,
+          // the package is no longer resolvable from this path, fallback to check the file itself
+          _ => self.fs.metadata(path).map_or(Some(true), |_| Some(false)),
+        }
+      }
+      FileSnapshot::File { timestamp, hash } => {
+        // compare timestamp first and only compute the hash when it differs,
+        // so touching a file without changing its content does not invalidate it
+        let current = self.file_snapshot(path, entry.strategy, false);
+        let FileSnapshot::File {
+          timestamp: current_timestamp,
+          ..
+        } = current
+        else {
+          return Some(true);
+        };
+        if entry.strategy.timestamp && current_timestamp == *timestamp {
+          return None;
+        }
+        if entry.strategy.hash && hash.is_some() {
+          return (self.content_hash(path) != *hash).then_some(false);
+        }
+        (current_timestamp != *timestamp).then_some(false)
+      }
+    }
+  }
+
+  fn content_hash(&self, path: &Path) -> Option<u64> {
+    let content = self.fs.read(path).ok()?;
+    let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+    hasher.write(&content);
+    Some(hasher.finish())
+  }
+
+  fn package_version(&self, path: &Path) -> Option<String> {
+    let root = package_root(path)?;
+    if let Some(version) = self.package_versions.get(&root) {
+      return version.clone();
+    }
+    let version = self
+      .fs
+      .read(&root.join("package.json"))
+      .ok()
+      .and_then(|content| serde_json::from_slice::<serde_json::Value>(&content).ok())
+      .and_then(|json| {
+        let name = json.get("name")?.as_str()?;
+        let version = json.get("version")?.as_str()?;
+        Some(format!("{name}@{version}"))
+      });
+    self.package_versions.insert(root, version.clone());
+    version
+  }
+}
+
+/// Find the root directory of the package which contains the path,
+/// e.g. `/a/node_modules/@scope/pkg` for `/a/node_modules/@scope/pkg/lib/index.js`.
+fn package_root(path: &Path) -> Option<PathBuf> {
+  let components = path.components().collect::<Vec<_>>();
+  let node_modules = components
+    .iter()
+    .rposition(|c| matches!(c, Component::Normal(name) if *name == "node_modules"))?;
+  let name = components.get(node_modules + 1)?;
+  let mut end = node_modules + 2;
+  if let Component::Normal(name) = name
+    && name.to_string_lossy().starts_with('@')
+  {
+    end += 1;
+  }
+  if end > components.len() {
+    return None;
+  }
+  Some(components[..end].iter().collect())
+}
+
+#[cfg(test)]
+mod test {
+  use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, UNIX_EPOCH},
+  };
+
+  use rspack_fs::NativeFileSystem;
+  use rustc_hash::FxHashSet as HashSet;
+
+  use super::{package_root, Snapshot};
+  use crate::{SnapshotOptions, SnapshotStrategy};
+
+  fn set_modified(path: &Path, secs: u64) {
+    fs::File::open(path)
+      .expect("should open")
+      .set_modified(UNIX_EPOCH + Duration::from_secs(secs))
+      .expect("should set modified time");
+  }
+
+  #[test]
+  fn should_check_snapshot_strategies() {
+    let dir = std::env::temp_dir().join(format!("rspack-snapshot-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("dir")).expect("should create dir");
+    let timestamp = dir.join("timestamp.js");
+    let hash = dir.join("hash.js");
+    let both = dir.join("both.js");
+    let sub_dir = dir.join("dir");
+    for file in [&timestamp, &hash, &both] {
+      fs::write(file, "a").expect("should write");
+      set_modified(file, 1000);
+    }
+    set_modified(&sub_dir, 1000);
+
+    let options = SnapshotOptions::default();
+    let native_fs = NativeFileSystem;
+    let mut snapshot = Snapshot::default();
+    snapshot.add_paths(
+      [timestamp.clone()].iter(),
+      SnapshotStrategy::TIMESTAMP,
+      &options,
+      &native_fs,
+    );
+    snapshot.add_paths(
+      [hash.clone(), sub_dir.clone()].iter(),
+      SnapshotStrategy::HASH,
+      &options,
+      &native_fs,
+    );
+    snapshot.add_paths(
+      [both.clone()].iter(),
+      SnapshotStrategy::TIMESTAMP_AND_HASH,
+      &options,
+      &native_fs,
+    );
+    assert_eq!(snapshot.len(), 4);
+    assert_eq!(
+      snapshot.check(&options, &native_fs),
+      (HashSet::default(), HashSet::default())
+    );
+
+    // touched without changing the content, only the timestamp strategy sees a change
+    for file in [&timestamp, &hash, &both] {
+      set_modified(file, 2000);
+    }
+    assert_eq!(
+      snapshot.check(&options, &native_fs),
+      (HashSet::from_iter([timestamp.clone()]), HashSet::default())
+    );
+
+    // content changed with the same timestamp, the timestamp is trusted when it is compared
+    for file in [&timestamp, &hash, &both] {
+      fs::write(file, "b").expect("should write");
+      set_modified(file, 1000);
+    }
+    assert_eq!(
+      snapshot.check(&options, &native_fs),
+      (HashSet::from_iter([hash.clone()]), HashSet::default())
+    );
+
+    // directories can't be hashed, they are compared by timestamp with the hash strategy
+    set_modified(&sub_dir, 2000);
+    fs::remove_file(&timestamp).expect("should remove");
+    assert_eq!(
+      snapshot.check(&options, &native_fs),
+      (
+        HashSet::from_iter([hash.clone(), sub_dir.clone()]),
+        HashSet::from_iter([timestamp.clone()])
+      )
+    );
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn should_find_package_root() {
+    assert_eq!(
+      package_root(Path::new("/a/node_modules/pkg/lib/index.js")),
+      Some(PathBuf::from("/a/node_modules/pkg"))
+    );
+    assert_eq!(
+      package_root(Path::new(
+        "/a/node_modules/b/node_modules/@scope/pkg/index.js"
+      )),
+      Some(PathBuf::from("/a/node_modules/b/node_modules/@scope/pkg"))
+    );
+    assert_eq!(package_root(Path::new("/a/src/index.js")), None);
+  }
+}
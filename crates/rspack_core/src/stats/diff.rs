@@ -0,0 +1,579 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use cow_utils::CowUtils;
+use rspack_error::{error, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{AssetInfo, Chunk, Compilation};
+
+/// The sizes and chunk membership of a build, which can be serialized and compared with
+/// another build by [StatsDiff].
+///
+/// Modules are keyed by their readable identifier and chunks by their name or id, so that
+/// snapshots of builds on different machines are comparable. Assets are keyed by their filename
+/// with the hashes replaced by placeholders, e.g. `main.[contenthash].js`, so that an asset is
+/// still the same asset when its content changed.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatsSnapshot {
+  #[serde(default)]
+  pub assets: BTreeMap<String, f64>,
+  /// Filenames of the assets whose keys in `assets` differ from them.
+  #[serde(default)]
+  pub filenames: BTreeMap<String, String>,
+  #[serde(default)]
+  pub entrypoints: BTreeMap<String, f64>,
+  #[serde(default)]
+  pub chunks: BTreeMap<String, f64>,
+  #[serde(default)]
+  pub modules: BTreeMap<String, StatsSnapshotModule>,
+  /// Sizes of the assets and entrypoints by compression (`gzip` or `brotli`), only known for
+  /// the assets the size limits plugin compared compressed.
+  #[serde(default)]
+  pub compressed: BTreeMap<String, StatsSnapshotSizes>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatsSnapshotSizes {
+  #[serde(default)]
+  pub assets: BTreeMap<String, f64>,
+  #[serde(default)]
+  pub entrypoints: BTreeMap<String, f64>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatsSnapshotModule {
+  pub size: f64,
+  #[serde(default)]
+  pub chunks: BTreeSet<String>,
+}
+
+/// Replaces the hashes of the asset in its filename with placeholders.
+fn get_asset_key(name: &str, info: &AssetInfo) -> String {
+  let mut hashes = [
+    (&info.content_hash, "[contenthash]"),
+    (&info.chunk_hash, "[chunkhash]"),
+    (&info.full_hash, "[fullhash]"),
+  ]
+  .into_iter()
+  .flat_map(|(hashes, placeholder)| hashes.iter().map(move |hash| (hash, placeholder)))
+  .filter(|(hash, _)| !hash.is_empty())
+  .collect::<Vec<_>>();
+  // shorter hashes may be prefixes of longer ones
+  hashes.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(b.0)));
+  let mut key = name.to_string();
+  for (hash, placeholder) in hashes {
+    key = key.cow_replace(hash.as_str(), placeholder).into_owned();
+  }
+  key
+}
+
+fn get_chunk_key(chunk: &Chunk) -> String {
+  chunk
+    .name
+    .clone()
+    .or_else(|| chunk.id.clone())
+    .unwrap_or_default()
+}
+
+impl StatsSnapshot {
+  pub fn new(compilation: &Compilation) -> Self {
+    let module_graph = compilation.get_module_graph();
+    let context = &compilation.options.context;
+
+    let sizes = compilation
+      .assets()
+      .iter()
+      .filter_map(|(name, asset)| {
+        let source = asset.get_source()?;
+        Some((name.clone(), source.size() as f64))
+      })
+      .collect::<BTreeMap<_, _>>();
+
+    let mut keys = compilation
+      .assets()
+      .iter()
+      .filter(|(name, _)| sizes.contains_key(*name))
+      .map(|(name, asset)| (name.clone(), get_asset_key(name, &asset.info)))
+      .collect::<BTreeMap<_, _>>();
+    // assets whose filenames only differ in their hashes can't be told apart by their keys
+    let mut key_counts: BTreeMap<String, usize> = BTreeMap::default();
+    for key in keys.values() {
+      *key_counts.entry(key.clone()).or_default() += 1;
+    }
+    for (name, key) in keys.iter_mut() {
+      if key_counts[key.as_str()] > 1 {
+        *key = name.clone();
+      }
+    }
+    let by_key = |sizes: &BTreeMap<String, f64>| {
+      sizes
+        .iter()
+        .map(|(name, size)| (keys[name].clone(), *size))
+        .collect::<BTreeMap<_, _>>()
+    };
+    let filenames = keys
+      .iter()
+      .filter(|(name, key)| name != key)
+      .map(|(name, key)| (key.clone(), name.clone()))
+      .collect();
+
+    let get_entrypoint_sizes = |assets: &BTreeMap<String, f64>| {
+      compilation
+        .entrypoints
+        .iter()
+        .map(|(name, ukey)| {
+          let entrypoint = compilation.chunk_group_by_ukey.expect_get(ukey);
+          let size = entrypoint
+            .get_files(&compilation.chunk_by_ukey)
+            .iter()
+            .filter_map(|file| assets.get(file))
+            .sum();
+          (name.clone(), size)
+        })
+        .collect::<BTreeMap<_, _>>()
+    };
+    let entrypoints = get_entrypoint_sizes(&sizes);
+    let assets = by_key(&sizes);
+
+    let mut compressed = BTreeMap::default();
+    for (compression, get_size) in [
+      (
+        "gzip",
+        (|info| info.gzip_size) as fn(&AssetInfo) -> Option<f64>,
+      ),
+      ("brotli", |info| info.brotli_size),
+    ] {
+      let sizes = compilation
+        .assets()
+        .iter()
+        .filter(|(name, _)| keys.contains_key(*name))
+        .filter_map(|(name, asset)| Some((name.clone(), get_size(&asset.info)?)))
+        .collect::<BTreeMap<_, _>>();
+      if !sizes.is_empty() {
+        compressed.insert(
+          compression.to_string(),
+          StatsSnapshotSizes {
+            assets: by_key(&sizes),
+            entrypoints: get_entrypoint_sizes(&sizes),
+          },
+        );
+      }
+    }
+
+    let chunks = compilation
+      .chunk_by_ukey
+      .values()
+      .map(|chunk| {
+        (
+          get_chunk_key(chunk),
+          compilation
+            .chunk_graph
+            .get_chunk_modules_size(&chunk.ukey, compilation),
+        )
+      })
+      .collect();
+
+    let modules = module_graph
+      .modules()
+      .values()
+      .map(|module| {
+        // modules without chunk graph module are not in any chunk, e.g. modules of a failed build
+        let chunks = compilation
+          .chunk_graph
+          .chunk_graph_module_by_module_identifier
+          .get(&module.identifier())
+          .map(|chunk_graph_module| {
+            chunk_graph_module
+              .chunks
+              .iter()
+              .map(|ukey| get_chunk_key(compilation.chunk_by_ukey.expect_get(ukey)))
+              .collect()
+          })
+          .unwrap_or_default();
+        (
+          module.readable_identifier(context).to_string(),
+          StatsSnapshotModule {
+            size: module.size(None, Some(compilation)),
+            chunks,
+          },
+        )
+      })
+      .collect();
+
+    Self {
+      assets,
+      filenames,
+      entrypoints,
+      chunks,
+      modules,
+      compressed,
+    }
+  }
+
+  /// A snapshot of the compressed sizes of the assets and entrypoints only, so that they can be
+  /// compared with the compressed sizes of another snapshot.
+  pub fn get_compressed(&self, compression: &str) -> Option<Self> {
+    let sizes = self.compressed.get(compression)?;
+    Some(Self {
+      assets: sizes.assets.clone(),
+      filenames: self.filenames.clone(),
+      entrypoints: sizes.entrypoints.clone(),
+      ..Default::default()
+    })
+  }
+
+  /// The filename of the asset with the key in `assets`.
+  pub fn get_asset_filename<'a>(&'a self, key: &'a str) -> &'a str {
+    self.filenames.get(key).map_or(key, String::as_str)
+  }
+
+  pub fn from_json(json: &str) -> Result<Self> {
+    serde_json::from_str(json).map_err(|e| error!("Failed to parse the stats snapshot: {e}"))
+  }
+
+  pub fn to_json(&self) -> Result<String> {
+    serde_json::to_string(self).map_err(|e| error!("Failed to serialize the stats snapshot: {e}"))
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StatsSizeDelta {
+  pub name: String,
+  pub before: Option<f64>,
+  pub after: Option<f64>,
+  pub delta: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsChunkMembershipChange {
+  pub module: String,
+  pub added_chunks: Vec<String>,
+  pub removed_chunks: Vec<String>,
+}
+
+/// An asset whose filename changed, e.g. because the hash in its filename changed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StatsRenamedAsset {
+  pub name: String,
+  pub before: String,
+  pub after: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StatsDuplicatedModule {
+  pub module: String,
+  pub chunks: Vec<String>,
+}
+
+/// The difference between two [StatsSnapshot]s, only changed items are recorded.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsDiff {
+  pub added_modules: Vec<String>,
+  pub removed_modules: Vec<String>,
+  pub assets: Vec<StatsSizeDelta>,
+  pub renamed_assets: Vec<StatsRenamedAsset>,
+  pub entrypoints: Vec<StatsSizeDelta>,
+  pub chunks: Vec<StatsSizeDelta>,
+  pub modules: Vec<StatsSizeDelta>,
+  pub chunk_membership: Vec<StatsChunkMembershipChange>,
+  /// Modules which are in more than one chunk now, but were not in the baseline.
+  pub duplicated_modules: Vec<StatsDuplicatedModule>,
+}
+
+fn diff_sizes<'a>(
+  before: impl Iterator<Item = (&'a String, f64)>,
+  after: impl Iterator<Item = (&'a String, f64)>,
+) -> Vec<StatsSizeDelta> {
+  let mut sizes: BTreeMap<&String, (Option<f64>, Option<f64>)> = BTreeMap::default();
+  for (name, size) in before {
+    sizes.entry(name).or_default().0 = Some(size);
+  }
+  for (name, size) in after {
+    sizes.entry(name).or_default().1 = Some(size);
+  }
+  sizes
+    .into_iter()
+    .filter(|(_, (before, after))| before != after)
+    .map(|(name, (before, after))| StatsSizeDelta {
+      name: name.clone(),
+      before,
+      after,
+      delta: after.unwrap_or_default() - before.unwrap_or_default(),
+    })
+    .collect()
+}
+
+impl StatsDiff {
+  pub fn new(baseline: &StatsSnapshot, current: &StatsSnapshot) -> Self {
+    let added_modules = current
+      .modules
+      .keys()
+      .filter(|name| !baseline.modules.contains_key(*name))
+      .cloned()
+      .collect();
+    let removed_modules = baseline
+      .modules
+      .keys()
+      .filter(|name| !current.modules.contains_key(*name))
+      .cloned()
+      .collect();
+
+    let mut chunk_membership = vec![];
+    let mut duplicated_modules = vec![];
+    for (name, module) in &current.modules {
+      let Some(baseline_module) = baseline.modules.get(name) else {
+        if module.chunks.len() > 1 {
+          duplicated_modules.push(StatsDuplicatedModule {
+            module: name.clone(),
+            chunks: module.chunks.iter().cloned().collect(),
+          });
+        }
+        continue;
+      };
+      if baseline_module.chunks != module.chunks {
+        chunk_membership.push(StatsChunkMembershipChange {
+          module: name.clone(),
+          added_chunks: module
+            .chunks
+            .difference(&baseline_module.chunks)
+            .cloned()
+            .collect(),
+          removed_chunks: baseline_module
+            .chunks
+            .difference(&module.chunks)
+            .cloned()
+            .collect(),
+        });
+      }
+      if module.chunks.len() > 1 && baseline_module.chunks.len() <= 1 {
+        duplicated_modules.push(StatsDuplicatedModule {
+          module: name.clone(),
+          chunks: module.chunks.iter().cloned().collect(),
+        });
+      }
+    }
+
+    let renamed_assets = current
+      .assets
+      .keys()
+      .filter(|name| baseline.assets.contains_key(*name))
+      .filter_map(|name| {
+        let before = baseline.get_asset_filename(name);
+        let after = current.get_asset_filename(name);
+        (before != after).then(|| StatsRenamedAsset {
+          name: name.clone(),
+          before: before.to_string(),
+          after: after.to_string(),
+        })
+      })
+      .collect();
+
+    Self {
+      added_modules,
+      removed_modules,
+      renamed_assets,
+      assets: diff_sizes(
+        baseline.assets.iter().map(|(name, size)| (name, *size)),
+        current.assets.iter().map(|(name, size)| (name, *size)),
+      ),
+      entrypoints: diff_sizes(
+        baseline
+          .entrypoints
+          .iter()
+          .map(|(name, size)| (name, *size)),
+        current.entrypoints.iter().map(|(name, size)| (name, *size)),
+      ),
+      chunks: diff_sizes(
+        baseline.chunks.iter().map(|(name, size)| (name, *size)),
+        current.chunks.iter().map(|(name, size)| (name, *size)),
+      ),
+      modules: diff_sizes(
+        baseline
+          .modules
+          .iter()
+          .map(|(name, module)| (name, module.size)),
+        current
+          .modules
+          .iter()
+          .map(|(name, module)| (name, module.size)),
+      ),
+      chunk_membership,
+      duplicated_modules,
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self == &Self::default()
+  }
+
+  pub fn to_json(&self) -> Result<String> {
+    serde_json::to_string_pretty(self)
+      .map_err(|e| error!("Failed to serialize the stats diff: {e}"))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn module(size: f64, chunks: &[&str]) -> StatsSnapshotModule {
+    StatsSnapshotModule {
+      size,
+      chunks: chunks.iter().map(|chunk| (*chunk).to_string()).collect(),
+    }
+  }
+
+  #[test]
+  fn stats_diff() {
+    let baseline = StatsSnapshot {
+      assets: BTreeMap::from([
+        ("main.js".into(), 100.0),
+        ("a.js".into(), 10.0),
+        ("vendor.[contenthash].js".into(), 50.0),
+      ]),
+      filenames: BTreeMap::from([("vendor.[contenthash].js".into(), "vendor.1a2b.js".into())]),
+      entrypoints: BTreeMap::from([("main".into(), 100.0)]),
+      chunks: BTreeMap::from([("main".into(), 80.0), ("a".into(), 5.0)]),
+      modules: BTreeMap::from([
+        ("./index.js".into(), module(50.0, &["main"])),
+        ("./shared.js".into(), module(30.0, &["main"])),
+        ("./a.js".into(), module(5.0, &["a"])),
+      ]),
+      ..Default::default()
+    };
+    let current = StatsSnapshot {
+      assets: BTreeMap::from([
+        ("main.js".into(), 120.0),
+        ("b.js".into(), 40.0),
+        ("vendor.[contenthash].js".into(), 50.0),
+      ]),
+      filenames: BTreeMap::from([("vendor.[contenthash].js".into(), "vendor.3c4d.js".into())]),
+      entrypoints: BTreeMap::from([("main".into(), 120.0)]),
+      chunks: BTreeMap::from([("main".into(), 80.0), ("b".into(), 35.0)]),
+      modules: BTreeMap::from([
+        ("./index.js".into(), module(50.0, &["main"])),
+        ("./shared.js".into(), module(30.0, &["b", "main"])),
+        ("./b.js".into(), module(5.0, &["b"])),
+      ]),
+      ..Default::default()
+    };
+
+    let diff = StatsDiff::new(&baseline, &current);
+    assert_eq!(diff.added_modules, vec!["./b.js".to_string()]);
+    assert_eq!(diff.removed_modules, vec!["./a.js".to_string()]);
+    assert_eq!(
+      diff.assets,
+      vec![
+        StatsSizeDelta {
+          name: "a.js".into(),
+          before: Some(10.0),
+          after: None,
+          delta: -10.0,
+        },
+        StatsSizeDelta {
+          name: "b.js".into(),
+          before: None,
+          after: Some(40.0),
+          delta: 40.0,
+        },
+        StatsSizeDelta {
+          name: "main.js".into(),
+          before: Some(100.0),
+          after: Some(120.0),
+          delta: 20.0,
+        },
+      ]
+    );
+    assert_eq!(
+      diff.renamed_assets,
+      vec![StatsRenamedAsset {
+        name: "vendor.[contenthash].js".into(),
+        before: "vendor.1a2b.js".into(),
+        after: "vendor.3c4d.js".into(),
+      }]
+    );
+    assert_eq!(diff.entrypoints.len(), 1);
+    assert_eq!(
+      diff
+        .chunks
+        .iter()
+        .map(|c| c.name.as_str())
+        .collect::<Vec<_>>(),
+      vec!["a", "b"]
+    );
+    assert_eq!(
+      diff
+        .modules
+        .iter()
+        .map(|m| m.name.as_str())
+        .collect::<Vec<_>>(),
+      vec!["./a.js", "./b.js"]
+    );
+    assert_eq!(
+      diff.chunk_membership,
+      vec![StatsChunkMembershipChange {
+        module: "./shared.js".into(),
+        added_chunks: vec!["b".into()],
+        removed_chunks: vec![],
+      }]
+    );
+    assert_eq!(
+      diff.duplicated_modules,
+      vec![StatsDuplicatedModule {
+        module: "./shared.js".into(),
+        chunks: vec!["b".into(), "main".into()],
+      }]
+    );
+
+    assert!(StatsDiff::new(&current, &current).is_empty());
+  }
+
+  #[test]
+  fn stats_asset_key() {
+    let info = AssetInfo {
+      content_hash: ["1a2b".into(), "1a2b3c4d".into()].into_iter().collect(),
+      full_hash: ["ffff".into()].into_iter().collect(),
+      ..Default::default()
+    };
+    assert_eq!(
+      get_asset_key("main.1a2b3c4d.js", &info),
+      "main.[contenthash].js"
+    );
+    assert_eq!(
+      get_asset_key("css/1a2b.ffff.css", &info),
+      "css/[contenthash].[fullhash].css"
+    );
+    assert_eq!(get_asset_key("index.html", &info), "index.html");
+  }
+
+  #[test]
+  fn stats_snapshot_json() {
+    let snapshot = StatsSnapshot {
+      assets: BTreeMap::from([("main.js".into(), 100.0)]),
+      modules: BTreeMap::from([("./index.js".into(), module(50.0, &["main"]))]),
+      compressed: BTreeMap::from([(
+        "gzip".into(),
+        StatsSnapshotSizes {
+          assets: BTreeMap::from([("main.js".into(), 40.0)]),
+          entrypoints: BTreeMap::from([("main".into(), 40.0)]),
+        },
+      )]),
+      ..Default::default()
+    };
+    let json = snapshot.to_json().expect("should serialize");
+    assert_eq!(
+      StatsSnapshot::from_json(&json).expect("should deserialize"),
+      snapshot
+    );
+    assert!(StatsSnapshot::from_json("{").is_err());
+
+    let gzip = snapshot
+      .get_compressed("gzip")
+      .expect("should have gzip sizes");
+    assert_eq!(gzip.assets, BTreeMap::from([("main.js".into(), 40.0)]));
+    assert!(gzip.modules.is_empty());
+    assert!(snapshot.get_compressed("brotli").is_none());
+  }
+}
@@ -6,11 +6,14 @@ use rayon::iter::{
 };
 use rspack_collections::IdentifierSet;
 use rspack_error::emitter::{
-  DiagnosticDisplay, DiagnosticDisplayer, StdioDiagnosticDisplay, StringDiagnosticDisplay,
+  DiagnosticDisplay, DiagnosticDisplayer, JsonDiagnosticDisplay, SarifDiagnosticDisplay,
+  StdioDiagnosticDisplay, StringDiagnosticDisplay,
 };
-use rspack_error::Result;
-use rustc_hash::FxHashMap as HashMap;
+use rspack_error::{Diagnostic, Result};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
+mod diff;
+pub use diff::*;
 mod utils;
 pub use utils::*;
 mod r#struct;
@@ -18,10 +21,47 @@ pub use r#struct::*;
 
 use crate::{
   BoxModule, BoxRuntimeModule, Chunk, ChunkGroupOrderKey, ChunkGroupUkey, ChunkUkey, Compilation,
-  ExecutedRuntimeModule, LogType, ModuleGraph, ModuleIdentifier, ProvidedExports, SourceType,
-  UsedExports,
+  ConnectionState, Context, ExecutedRuntimeModule, LogType, ModuleGraph, ModuleIdentifier,
+  ProvidedExports, SourceType, UsedExports,
 };
 
+/// The dependency with side effects of a module which has no side effects in its own source code,
+/// but still can't be skipped.
+fn get_side_effects_bailout(
+  module: &BoxModule,
+  module_graph: &ModuleGraph,
+  context: &Context,
+) -> Option<String> {
+  if module
+    .factory_meta()
+    .and_then(|meta| meta.side_effect_free)
+    .is_some()
+    || !module
+      .build_meta()
+      .and_then(|meta| meta.side_effect_free)
+      .unwrap_or_default()
+  {
+    return None;
+  }
+  let short_id = module.readable_identifier(context);
+  module.get_dependencies().iter().find_map(|dependency_id| {
+    let dependency = module_graph.dependency_by_id(dependency_id)?;
+    let mut module_chain = IdentifierSet::from_iter([module.identifier()]);
+    let state =
+      dependency.get_module_evaluation_side_effects_state(module_graph, &mut module_chain);
+    matches!(state, ConnectionState::Bool(true)).then(|| match dependency.loc() {
+      Some(loc) => format!(
+        "Dependency ({}) with side effects at {short_id}:{loc}",
+        dependency.dependency_type()
+      ),
+      None => format!(
+        "Dependency ({}) with side effects in {short_id}",
+        dependency.dependency_type()
+      ),
+    })
+  })
+}
+
 #[derive(Debug, Clone)]
 pub struct Stats<'compilation> {
   pub compilation: &'compilation Compilation,
@@ -44,10 +84,80 @@ impl<'compilation> Stats<'compilation> {
     let errors = displayer.emit_batch_diagnostic(self.compilation.get_errors())?;
     Ok(format!("{warnings}{errors}"))
   }
+
+  /// Snapshot the sizes and chunk membership of this build, see [StatsSnapshot].
+  pub fn get_snapshot(&self) -> StatsSnapshot {
+    StatsSnapshot::new(self.compilation)
+  }
+
+  /// Compare this build with a baseline snapshot, see [StatsDiff].
+  pub fn diff(&self, baseline: &StatsSnapshot) -> StatsDiff {
+    StatsDiff::new(baseline, &self.get_snapshot())
+  }
+
+  /// Emit advices, warnings and errors as a JSON array, see [JsonDiagnosticDisplay].
+  pub fn emit_diagnostics_json(&self) -> Result<String> {
+    let mut displayer = JsonDiagnosticDisplay::default().with_module_traces(self.module_traces());
+    displayer.emit_batch_diagnostic(self.diagnostics())
+  }
+
+  /// Emit advices, warnings and errors as a SARIF log, see [SarifDiagnosticDisplay].
+  pub fn emit_diagnostics_sarif(&self) -> Result<String> {
+    let mut displayer = SarifDiagnosticDisplay::default().with_module_traces(self.module_traces());
+    displayer.emit_batch_diagnostic(self.diagnostics())
+  }
+
+  fn diagnostics(&self) -> impl Iterator<Item = &Diagnostic> {
+    self
+      .compilation
+      .get_advices()
+      .chain(self.compilation.get_warnings())
+      .chain(self.compilation.get_errors())
+  }
+
+  /// Readable module traces of the modules reporting diagnostics, from the module to the entry.
+  /// An entry module has no issuer, its trace only contains itself.
+  fn module_traces(&self) -> std::collections::HashMap<ModuleIdentifier, Vec<String>> {
+    let module_graph = self.compilation.get_module_graph();
+    self
+      .diagnostics()
+      .filter_map(|diagnostic| diagnostic.module_identifier())
+      .unique()
+      .map(|module_identifier| {
+        let module_trace = get_module_trace(
+          Some(module_identifier),
+          &module_graph,
+          &self.compilation.chunk_graph,
+          &self.compilation.options,
+        );
+        let names = match module_trace.first() {
+          Some(trace) => std::iter::once(trace.module.name.clone())
+            .chain(module_trace.into_iter().map(|trace| trace.origin.name))
+            .collect(),
+          None => module_graph
+            .module_by_identifier(&module_identifier)
+            .map(|module| {
+              vec![module
+                .readable_identifier(&self.compilation.options.context)
+                .to_string()]
+            })
+            .unwrap_or_default(),
+        };
+        (module_identifier, names)
+      })
+      .collect()
+  }
 }
 
 impl Stats<'_> {
-  pub fn get_assets(&self) -> (Vec<StatsAsset>, Vec<StatsAssetsByChunkName>) {
+  pub fn get_assets(
+    &self,
+    options: &ExtendedStatsOptions,
+  ) -> (
+    Vec<StatsAsset>,
+    Vec<StatsAssetsByChunkName>,
+    Option<StatsExcluded>,
+  ) {
     let mut compilation_file_to_chunks: HashMap<&String, Vec<&Chunk>> = HashMap::default();
     let mut compilation_file_to_auxiliary_chunks: HashMap<&String, Vec<&Chunk>> =
       HashMap::default();
@@ -65,19 +175,26 @@ impl Stats<'_> {
       }
     }
 
+    let get_related_size = |related: Option<&String>| {
+      let source = self.compilation.assets().get(related?)?.get_source()?;
+      Some(source.size() as f64)
+    };
+
     let mut assets: HashMap<&String, StatsAsset> = self
       .compilation
       .assets()
       .par_iter()
       .filter_map(|(name, asset)| {
         asset.get_source().map(|source| {
-          let mut related = vec![];
-          if let Some(source_map) = &asset.info.related.source_map {
-            related.push(StatsAssetInfoRelated {
-              name: "sourceMap".into(),
-              value: [source_map.clone()].into(),
+          let related = asset
+            .info
+            .related
+            .iter()
+            .map(|(name, value)| StatsAssetInfoRelated {
+              name: name.into(),
+              value: [value.clone()].into(),
             })
-          }
+            .collect();
           (
             name,
             StatsAsset {
@@ -105,6 +222,14 @@ impl Stats<'_> {
                 is_over_size_limit: asset.info.is_over_size_limit,
               },
               emitted: self.compilation.emitted_assets.contains(name),
+              gzip_size: asset
+                .info
+                .gzip_size
+                .or_else(|| get_related_size(asset.info.related.gzipped.as_ref())),
+              brotli_size: asset
+                .info
+                .brotli_size
+                .or_else(|| get_related_size(asset.info.related.brotli_compressed.as_ref())),
             },
           )
         })
@@ -149,7 +274,21 @@ impl Stats<'_> {
         asset.auxiliary_chunk_id_hints.sort_unstable();
       }
     });
-    let mut assets: Vec<StatsAsset> = assets.into_values().collect();
+    // the related assets are listed under the asset they belong to, the js side filters them there
+    let related_assets = assets
+      .values()
+      .flat_map(|asset| asset.info.related.iter().flat_map(|related| &related.value))
+      .cloned()
+      .collect::<HashSet<_>>();
+    let (mut assets, excluded_assets): (Vec<StatsAsset>, Vec<StatsAsset>) =
+      assets.into_values().partition(|asset| {
+        related_assets.contains(&asset.name) || !options.is_asset_excluded(&asset.name)
+      });
+    let excluded_assets = (!excluded_assets.is_empty()).then(|| StatsExcluded {
+      count: excluded_assets.len(),
+      size: excluded_assets.iter().map(|asset| asset.size).sum(),
+      sizes: HashMap::default(),
+    });
     assets.sort_unstable_by(|a, b| {
       if b.size == a.size {
         // a to z
@@ -180,7 +319,7 @@ impl Stats<'_> {
       })
       .collect();
 
-    (assets, assets_by_chunk_name)
+    (assets, assets_by_chunk_name, excluded_assets)
   }
 
   #[allow(clippy::too_many_arguments)]
@@ -188,7 +327,7 @@ impl Stats<'_> {
     &self,
     options: &ExtendedStatsOptions,
     f: impl Fn(Vec<StatsModule>) -> T,
-  ) -> Result<T> {
+  ) -> Result<(T, StatsFilteredModules)> {
     let module_graph = self.compilation.get_module_graph();
     let executor_module_graph = &self
       .compilation
@@ -239,9 +378,69 @@ impl Stats<'_> {
       modules.extend(runtime_modules);
     }
 
+    let mut excluded = None;
+    if !options.exclude_modules.is_empty() {
+      let excluded_modules;
+      (modules, excluded_modules) = modules
+        .into_iter()
+        .partition(|module| !is_stats_module_excluded(module, options));
+      if !excluded_modules.is_empty() {
+        let mut sizes: HashMap<SourceType, f64> = HashMap::default();
+        for size in excluded_modules.iter().flat_map(|module| &module.sizes) {
+          *sizes.entry(size.source_type).or_default() += size.size;
+        }
+        excluded = Some(StatsExcluded {
+          count: excluded_modules.len(),
+          size: excluded_modules.iter().map(|module| module.size).sum(),
+          sizes,
+        });
+      }
+    }
+
     sort_modules(&mut modules);
 
-    Ok(f(modules))
+    let Some(modules_space) = options.modules_space else {
+      return Ok((
+        f(modules),
+        StatsFilteredModules {
+          excluded,
+          filtered: None,
+        },
+      ));
+    };
+
+    // the excluded modules take a line as a group of hidden modules, like the other groups
+    let mut items = Vec::with_capacity(modules.len() + 1);
+    if let Some(excluded) = excluded {
+      let count = excluded.count;
+      items.push(SpaceLimitedChild {
+        value: Either::Right(excluded),
+        children: None,
+        filtered_children: Some(count),
+      });
+    }
+    items.extend(
+      modules
+        .into_iter()
+        .map(|module| SpaceLimitedChild::item(Either::Left(module))),
+    );
+    let limited = space_limited(items, modules_space as isize, false);
+    let mut modules = vec![];
+    let mut excluded = None;
+    for child in limited.children.into_iter().flatten() {
+      match child.value {
+        Either::Left(module) => modules.push(module),
+        Either::Right(hidden) => excluded = Some(hidden),
+      }
+    }
+
+    Ok((
+      f(modules),
+      StatsFilteredModules {
+        excluded,
+        filtered: limited.filtered_children,
+      },
+    ))
   }
 
   #[allow(clippy::too_many_arguments)]
@@ -584,10 +783,20 @@ impl Stats<'_> {
   }
 
   pub fn get_warnings(&self) -> Vec<StatsWarning> {
+    self.get_stats_warnings(self.compilation.get_warnings_sorted())
+  }
+
+  /// Diagnostics with [rspack_error::RspackSeverity::Advice], like performance suggestions and deprecation notes.
+  pub fn get_advices(&self) -> Vec<StatsWarning> {
+    self.get_stats_warnings(self.compilation.get_advices())
+  }
+
+  fn get_stats_warnings<'a>(
+    &'a self,
+    diagnostics: impl Iterator<Item = &'a rspack_error::Diagnostic>,
+  ) -> Vec<StatsWarning<'a>> {
     let mut diagnostic_displayer = DiagnosticDisplayer::new(self.compilation.options.stats.colors);
-    self
-      .compilation
-      .get_warnings_sorted()
+    diagnostics
       .map(|d| {
         let module_identifier = d.module_identifier();
         let (module_name, module_id) = module_identifier
@@ -968,7 +1177,20 @@ impl Stats<'_> {
     }
 
     if options.optimization_bailout {
-      stats.optimization_bailout = Some(&mgm.optimization_bailout);
+      let mut optimization_bailout = mgm.optimization_bailout.clone();
+      // computed here instead of when optimizing, as it's only needed by the stats
+      if self
+        .compilation
+        .options
+        .optimization
+        .side_effects
+        .is_enable()
+        && let Some(bailout) =
+          get_side_effects_bailout(module, module_graph, &self.compilation.options.context)
+      {
+        optimization_bailout.push(bailout);
+      }
+      stats.optimization_bailout = Some(optimization_bailout);
     }
 
     // 'depth' is used for sorting in the JavaScript side, so it should always be computed.
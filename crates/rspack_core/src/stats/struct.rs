@@ -6,7 +6,10 @@ use rspack_sources::Source;
 use rspack_util::atom::Atom;
 use rustc_hash::FxHashMap as HashMap;
 
-use crate::{ChunkGroupOrderKey, ModuleIdentifier, ModuleType, RuntimeSpec, SourceType};
+use crate::{
+  ChunkGroupOrderKey, ModuleIdentifier, ModuleType, RuntimeSpec, SourceType, StatsFilter,
+  StatsPreset,
+};
 
 pub enum EntrypointsStatsOption {
   Bool(bool),
@@ -36,6 +39,108 @@ pub struct ExtendedStatsOptions {
   pub source: bool,
   pub used_exports: bool,
   pub warnings: bool,
+  pub exclude_modules: Vec<StatsFilter>,
+  pub exclude_assets: Vec<StatsFilter>,
+  /// The max number of top level modules, `None` means unlimited.
+  pub modules_space: Option<usize>,
+}
+
+impl ExtendedStatsOptions {
+  /// Resolve the options of a named preset, aligned with `DefaultStatsPresetPlugin` of the js side.
+  pub fn from_preset(preset: StatsPreset, for_to_string: bool) -> Self {
+    // only `normal`, `detailed` and `verbose` leave `all` unset, other presets set `all: false`
+    let normal_on = matches!(
+      preset,
+      StatsPreset::Normal | StatsPreset::Detailed | StatsPreset::Verbose
+    );
+    let off_for_to_string = normal_on && !for_to_string;
+
+    let mut options = Self {
+      assets: normal_on,
+      cached_modules: off_for_to_string,
+      chunks: off_for_to_string,
+      chunk_group_auxiliary: off_for_to_string,
+      chunk_group_children: off_for_to_string,
+      chunk_groups: off_for_to_string,
+      chunk_modules: normal_on,
+      chunk_relations: off_for_to_string,
+      depth: off_for_to_string,
+      entrypoints: match (normal_on, for_to_string) {
+        (false, _) => EntrypointsStatsOption::Bool(false),
+        (true, true) => EntrypointsStatsOption::String("auto".to_string()),
+        (true, false) => EntrypointsStatsOption::Bool(true),
+      },
+      errors: normal_on,
+      hash: off_for_to_string,
+      ids: off_for_to_string,
+      modules: normal_on,
+      module_assets: off_for_to_string,
+      nested_modules: off_for_to_string,
+      optimization_bailout: off_for_to_string,
+      provided_exports: off_for_to_string,
+      reasons: off_for_to_string,
+      source: false,
+      used_exports: off_for_to_string,
+      warnings: normal_on,
+      exclude_modules: vec![],
+      exclude_assets: vec![],
+      modules_space: for_to_string.then_some(15),
+    };
+
+    match preset {
+      StatsPreset::Verbose | StatsPreset::Detailed => {
+        let verbose = matches!(preset, StatsPreset::Verbose);
+        options.hash = true;
+        options.entrypoints = EntrypointsStatsOption::Bool(true);
+        options.chunk_groups = true;
+        options.ids = true;
+        options.chunks = true;
+        options.chunk_relations = true;
+        options.chunk_modules = verbose;
+        options.depth = true;
+        options.used_exports = true;
+        options.provided_exports = true;
+        options.optimization_bailout = true;
+        if verbose {
+          options.modules = false;
+          options.reasons = true;
+          options.modules_space = None;
+        } else {
+          options.modules_space = Some(1000);
+        }
+      }
+      StatsPreset::Minimal => {
+        options.modules = true;
+        options.modules_space = Some(0);
+        options.assets = true;
+        options.errors = true;
+        options.warnings = true;
+      }
+      StatsPreset::ErrorsOnly => {
+        options.errors = true;
+      }
+      StatsPreset::ErrorsWarnings => {
+        options.errors = true;
+        options.warnings = true;
+      }
+      StatsPreset::Normal | StatsPreset::Summary | StatsPreset::None => {}
+    }
+
+    // `modules` is hidden when chunk modules are printed in the chunks
+    if for_to_string && options.chunks && options.chunk_modules {
+      options.modules = false;
+    }
+
+    options
+  }
+
+  pub fn is_module_excluded(&self, name: &str) -> bool {
+    self.exclude_modules.iter().any(|filter| filter.test(name))
+  }
+
+  pub fn is_asset_excluded(&self, name: &str) -> bool {
+    self.exclude_assets.iter().any(|filter| filter.test(name))
+  }
 }
 
 #[derive(Debug)]
@@ -100,6 +205,10 @@ pub struct StatsAsset {
   pub auxiliary_chunk_names: Vec<String>,
   pub auxiliary_chunk_id_hints: Vec<String>,
   pub auxiliary_chunks: Vec<Option<String>>,
+  /// the compressed sizes computed by the size limits check, or the sizes of the precompressed
+  /// related assets
+  pub gzip_size: Option<f64>,
+  pub brotli_size: Option<f64>,
 }
 
 #[derive(Debug)]
@@ -155,7 +264,7 @@ pub struct StatsModule<'s> {
   pub orphan: Option<bool>,
   pub provided_exports: Option<Vec<Atom>>,
   pub used_exports: Option<StatsUsedExports>,
-  pub optimization_bailout: Option<&'s [String]>,
+  pub optimization_bailout: Option<Vec<String>>,
   pub depth: Option<usize>,
   pub pre_order_index: Option<u32>,
   pub post_order_index: Option<u32>,
@@ -289,3 +398,74 @@ pub struct StatsSourceTypeSize {
   pub source_type: SourceType,
   pub size: f64,
 }
+
+/// The modules or assets hidden by `excludeModules` or `excludeAssets`, which are printed as a
+/// group by the js side.
+#[derive(Debug, Default)]
+pub struct StatsExcluded {
+  pub count: usize,
+  pub size: f64,
+  pub sizes: HashMap<SourceType, f64>,
+}
+
+/// The top level modules which are not in the modules returned by `get_modules`.
+#[derive(Debug, Default)]
+pub struct StatsFilteredModules {
+  /// The modules hidden by `excludeModules`, `None` when there are none or the group has been
+  /// collapsed into `filtered` by `modulesSpace`.
+  pub excluded: Option<StatsExcluded>,
+  /// The number of modules left out by `modulesSpace`.
+  pub filtered: Option<usize>,
+}
+
+/// An item of a list limited by `space_limited`, it takes one line when it has neither children
+/// nor filtered children, otherwise it is a group which takes a line for its header.
+#[derive(Debug)]
+pub struct SpaceLimitedChild<T> {
+  pub value: T,
+  pub children: Option<Vec<SpaceLimitedChild<T>>>,
+  pub filtered_children: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct SpaceLimited<T> {
+  pub children: Option<Vec<SpaceLimitedChild<T>>>,
+  pub filtered_children: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn stats_presets() {
+    let normal = ExtendedStatsOptions::from_preset(StatsPreset::Normal, true);
+    assert!(normal.assets && normal.modules && normal.errors && normal.warnings);
+    assert!(!normal.chunks && !normal.reasons && !normal.used_exports);
+    assert_eq!(normal.modules_space, Some(15));
+
+    let normal_json = ExtendedStatsOptions::from_preset(StatsPreset::Normal, false);
+    assert!(normal_json.chunks && normal_json.reasons && normal_json.chunk_relations);
+    assert_eq!(normal_json.modules_space, None);
+
+    let verbose = ExtendedStatsOptions::from_preset(StatsPreset::Verbose, true);
+    assert!(verbose.chunks && verbose.chunk_modules && verbose.reasons && verbose.depth);
+    assert!(!verbose.modules);
+    assert_eq!(verbose.modules_space, None);
+
+    let detailed = ExtendedStatsOptions::from_preset(StatsPreset::Detailed, true);
+    assert!(detailed.modules && detailed.provided_exports && !detailed.reasons);
+    assert_eq!(detailed.modules_space, Some(1000));
+
+    let minimal = ExtendedStatsOptions::from_preset(StatsPreset::Minimal, true);
+    assert!(minimal.assets && minimal.modules && minimal.errors && minimal.warnings);
+    assert!(!minimal.chunks && !minimal.chunk_modules);
+    assert_eq!(minimal.modules_space, Some(0));
+
+    let errors_only = ExtendedStatsOptions::from_preset(StatsPreset::from("errors-only"), false);
+    assert!(errors_only.errors && !errors_only.warnings && !errors_only.modules);
+
+    let none = ExtendedStatsOptions::from_preset(StatsPreset::None, false);
+    assert!(!none.assets && !none.modules && !none.errors && !none.warnings);
+  }
+}
@@ -1,11 +1,14 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, cmp::Ordering};
 
 use itertools::Itertools;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use rspack_collections::Identifier;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
-use super::{Stats, StatsChunkGroup, StatsErrorModuleTraceModule, StatsModule, StatsModuleTrace};
+use super::{
+  ExtendedStatsOptions, SpaceLimited, SpaceLimitedChild, Stats, StatsChunkGroup,
+  StatsErrorModuleTraceModule, StatsModule, StatsModuleTrace,
+};
 use crate::{
   get_chunk_from_ukey, get_chunk_group_from_ukey, BoxModule, Chunk, ChunkByUkey, ChunkGraph,
   ChunkGroupByUkey, ChunkGroupOrderKey, ChunkGroupUkey, Compilation, CompilerOptions, ModuleGraph,
@@ -37,6 +40,183 @@ pub fn sort_modules(modules: &mut [StatsModule]) {
   });
 }
 
+pub fn is_stats_module_excluded(module: &StatsModule, options: &ExtendedStatsOptions) -> bool {
+  module
+    .name
+    .as_ref()
+    .is_some_and(|name| options.is_module_excluded(name))
+}
+
+impl<T> SpaceLimitedChild<T> {
+  pub fn item(value: T) -> Self {
+    Self {
+      value,
+      children: None,
+      filtered_children: None,
+    }
+  }
+
+  fn has_filtered_children(&self) -> bool {
+    self.filtered_children.is_some_and(|count| count > 0)
+  }
+
+  fn is_item(&self) -> bool {
+    self.children.is_none() && !self.has_filtered_children()
+  }
+
+  /// Each item takes 1 line, plus the size of the children, plus 1 extra line when it has
+  /// children and filtered children.
+  fn size(&self) -> usize {
+    match &self.children {
+      None => 1,
+      Some(children) if self.has_filtered_children() => 2 + get_total_size(children),
+      Some(children) => 1 + get_total_size(children),
+    }
+  }
+}
+
+fn get_total_size<T>(children: &[SpaceLimitedChild<T>]) -> usize {
+  children.iter().map(SpaceLimitedChild::size).sum()
+}
+
+fn get_total_items<T>(children: &[SpaceLimitedChild<T>]) -> usize {
+  children
+    .iter()
+    .map(|child| {
+      if child.is_item() {
+        1
+      } else {
+        child.children.as_deref().map_or(0, get_total_items) + child.filtered_children.unwrap_or(0)
+      }
+    })
+    .sum()
+}
+
+/// Collapses the groups, so that each child takes exactly one line.
+fn collapse<T>(children: Vec<SpaceLimitedChild<T>>) -> Vec<SpaceLimitedChild<T>> {
+  children
+    .into_iter()
+    .map(|mut child| {
+      if let Some(children) = child.children.take() {
+        child.filtered_children =
+          Some(child.filtered_children.unwrap_or(0) + get_total_items(&children));
+      }
+      child
+    })
+    .collect()
+}
+
+/// Limits the lines taken by the items and groups to `max`, aligned with `spaceLimited` of
+/// webpack's `DefaultStatsFactoryPlugin`.
+pub fn space_limited<T>(
+  items_and_groups: Vec<SpaceLimitedChild<T>>,
+  max: isize,
+  filtered_children_line_reserved: bool,
+) -> SpaceLimited<T> {
+  if max < 1 {
+    return SpaceLimited {
+      children: None,
+      filtered_children: Some(get_total_items(&items_and_groups)),
+    };
+  }
+  let max = max as usize;
+  let total_items = get_total_items(&items_and_groups);
+  let mut filtered_children = None;
+  // the groups take 1+ lines each, the items take 1 line each
+  let mut groups = vec![];
+  let mut group_sizes = vec![];
+  let mut items = vec![];
+  let mut groups_size = 0;
+
+  for item_or_group in items_and_groups {
+    if item_or_group.is_item() {
+      items.push(item_or_group);
+    } else {
+      let size = item_or_group.size();
+      group_sizes.push(size);
+      groups_size += size;
+      groups.push(item_or_group);
+    }
+  }
+
+  let children = if groups_size + items.len() <= max {
+    // the total size fits into the max, keep all
+    groups.extend(items);
+    Some(groups)
+  } else if groups.is_empty() {
+    // slice the items to max, the line of the filtered children may be already reserved
+    let limit = max - usize::from(!filtered_children_line_reserved);
+    filtered_children = Some(items.len() - limit);
+    items.truncate(limit);
+    Some(items)
+  } else {
+    // the size when all groups are collapsed
+    let limit = groups.len() + usize::from(!filtered_children_line_reserved && !items.is_empty());
+    match limit.cmp(&max) {
+      Ordering::Less => {
+        // collapse some groups until the total fits into the max, keep the items
+        loop {
+          let filtered_line = usize::from(
+            filtered_children.is_some_and(|count| count > 0) && !filtered_children_line_reserved,
+          );
+          let size = groups_size + items.len() + filtered_line;
+          if size <= max {
+            break;
+          }
+          let oversize = size - max;
+          // find the largest group and process only this one
+          let max_group_size = group_sizes.iter().copied().max().unwrap_or_default();
+          if max_group_size < items.len() {
+            filtered_children = Some(items.len());
+            items.clear();
+            continue;
+          }
+          let index = group_sizes
+            .iter()
+            .position(|size| *size == max_group_size)
+            .expect("should have the largest group");
+          let groups_count = groups.len();
+          let group = &mut groups[index];
+          // limit the size of the children to the current size - oversize / number of groups, so
+          // that it always ends up smaller
+          let header_size = if group.has_filtered_children() { 2 } else { 1 };
+          let limited = space_limited(
+            group.children.take().unwrap_or_default(),
+            max_group_size as isize
+              - oversize.div_ceil(groups_count) as isize
+              - header_size as isize,
+            header_size == 2,
+          );
+          group.children = limited.children;
+          if let Some(count) = limited.filtered_children.filter(|count| *count > 0) {
+            group.filtered_children = Some(group.filtered_children.unwrap_or(0) + count);
+          }
+          let new_size = group.size();
+          groups_size = groups_size + new_size - max_group_size;
+          group_sizes[index] = new_size;
+        }
+        groups.extend(items);
+        Some(groups)
+      }
+      Ordering::Equal => {
+        // there is only space for one line per group and one line for the filtered items
+        filtered_children = Some(items.len());
+        Some(collapse(groups))
+      }
+      Ordering::Greater => {
+        // there is no space, collapse everything
+        filtered_children = Some(total_items);
+        None
+      }
+    }
+  };
+
+  SpaceLimited {
+    children,
+    filtered_children,
+  }
+}
+
 pub fn get_stats_module_name_and_id<'s, 'c>(
   module: &'s BoxModule,
   compilation: &'c Compilation,
@@ -199,3 +379,71 @@ pub fn get_module_trace(
 
   module_trace
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn items(values: std::ops::Range<usize>) -> Vec<SpaceLimitedChild<usize>> {
+    values.map(SpaceLimitedChild::item).collect()
+  }
+
+  fn values(children: &[SpaceLimitedChild<usize>]) -> Vec<usize> {
+    children.iter().map(|child| child.value).collect()
+  }
+
+  #[test]
+  fn space_limited_items() {
+    let limited = space_limited(items(0..3), 5, false);
+    assert_eq!(
+      values(&limited.children.expect("should have children")),
+      [0, 1, 2]
+    );
+    assert_eq!(limited.filtered_children, None);
+
+    // a line is taken by the filtered items
+    let limited = space_limited(items(0..20), 15, false);
+    assert_eq!(limited.children.expect("should have children").len(), 14);
+    assert_eq!(limited.filtered_children, Some(6));
+
+    let limited = space_limited(items(0..20), 0, false);
+    assert!(limited.children.is_none());
+    assert_eq!(limited.filtered_children, Some(20));
+  }
+
+  #[test]
+  fn space_limited_groups() {
+    let hidden = SpaceLimitedChild {
+      value: 100,
+      children: None,
+      filtered_children: Some(3),
+    };
+    let mut children = vec![hidden];
+    children.extend(items(0..20));
+    // the items are collapsed before the groups
+    let limited = space_limited(children, 15, false);
+    assert_eq!(
+      values(&limited.children.expect("should have children")),
+      [100]
+    );
+    assert_eq!(limited.filtered_children, Some(20));
+
+    let group = SpaceLimitedChild {
+      value: 100,
+      children: Some(items(0..10)),
+      filtered_children: None,
+    };
+    let mut children = vec![group];
+    children.extend(items(10..12));
+    // the children of the largest group are limited
+    let limited = space_limited(children, 6, false);
+    let children = limited.children.expect("should have children");
+    assert_eq!(values(&children), [100, 10, 11]);
+    assert_eq!(
+      values(children[0].children.as_ref().expect("should have children")),
+      [0, 1]
+    );
+    assert_eq!(children[0].filtered_children, Some(8));
+    assert_eq!(limited.filtered_children, None);
+  }
+}
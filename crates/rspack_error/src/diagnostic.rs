@@ -13,6 +13,8 @@ pub enum RspackSeverity {
   #[default]
   Error,
   Warn,
+  /// Non-blocking information like performance suggestions and deprecation notes.
+  Advice,
 }
 
 pub type Severity = RspackSeverity;
@@ -22,6 +24,7 @@ impl From<RspackSeverity> for miette::Severity {
     match value {
       RspackSeverity::Error => miette::Severity::Error,
       RspackSeverity::Warn => miette::Severity::Warning,
+      RspackSeverity::Advice => miette::Severity::Advice,
     }
   }
 }
@@ -31,7 +34,7 @@ impl From<miette::Severity> for RspackSeverity {
     match value {
       miette::Severity::Error => RspackSeverity::Error,
       miette::Severity::Warning => RspackSeverity::Warn,
-      miette::Severity::Advice => unimplemented!("Not supported miette severity"),
+      miette::Severity::Advice => RspackSeverity::Advice,
     }
   }
 }
@@ -41,6 +44,7 @@ impl From<&str> for RspackSeverity {
     let s = value.cow_to_ascii_lowercase();
     match s.as_ref() {
       "warning" => RspackSeverity::Warn,
+      "advice" => RspackSeverity::Advice,
       _ => RspackSeverity::Error,
     }
   }
@@ -54,6 +58,7 @@ impl fmt::Display for RspackSeverity {
       match self {
         RspackSeverity::Error => "error",
         RspackSeverity::Warn => "warning",
+        RspackSeverity::Advice => "advice",
       }
     )
   }
@@ -146,6 +151,23 @@ impl Diagnostic {
     }
   }
 
+  pub fn advice(title: String, message: String) -> Self {
+    Self {
+      inner: Error::from(
+        MietteDiagnostic::new(message)
+          .with_code(title)
+          .with_severity(miette::Severity::Advice),
+      )
+      .into(),
+      module_identifier: None,
+      loc: None,
+      file: None,
+      hide_stack: None,
+      chunk: None,
+      stack: None,
+    }
+  }
+
   pub fn error(title: String, message: String) -> Self {
     Self {
       inner: Error::from(
@@ -244,20 +266,37 @@ impl Diagnostic {
   pub fn details(&self) -> Option<String> {
     let hide_stack = self.hide_stack.unwrap_or_default();
     if hide_stack {
-      // TODO: generate detail content for typed error
-      self.stack()
+      self.stack().or_else(|| self.typed_details())
     } else {
       None
     }
   }
+
+  /// Detail content of a typed error, which has no stack, generated from its url and related diagnostics.
+  fn typed_details(&self) -> Option<String> {
+    let mut details = vec![];
+    if let Some(url) = self.inner.url() {
+      details.push(format!("see: {url}"));
+    }
+    if let Some(related) = self.inner.related() {
+      details.extend(related.map(|diagnostic| {
+        let severity = RspackSeverity::from(diagnostic.severity().unwrap_or_default());
+        format!("{severity}: {diagnostic}")
+      }));
+    }
+    (!details.is_empty()).then(|| details.join("\n"))
+  }
 }
 
 pub trait Diagnosable {
-  fn add_diagnostic(&self, _diagnostic: Diagnostic) {
-    unimplemented!("`<T as Diagnosable>::add_diagnostic` is not implemented")
-  }
-  fn add_diagnostics(&self, _diagnostics: Vec<Diagnostic>) {
-    unimplemented!("`<T as Diagnosable>::add_diagnostics` is not implemented")
+  /// Add a diagnostic to current [Diagnosable].
+  /// Diagnostics are dropped by default for types that do not collect diagnostics.
+  fn add_diagnostic(&self, _diagnostic: Diagnostic) {}
+  /// Add diagnostics to current [Diagnosable], each of them is added by [Diagnosable::add_diagnostic].
+  fn add_diagnostics(&self, diagnostics: Vec<Diagnostic>) {
+    for diagnostic in diagnostics {
+      self.add_diagnostic(diagnostic);
+    }
   }
   /// Clone diagnostics from current [Diagnosable].
   /// This does not drain the diagnostics from the current one.
@@ -279,23 +318,59 @@ pub trait Diagnosable {
 #[macro_export]
 macro_rules! impl_empty_diagnosable_trait {
   ($ty:ty) => {
-    impl $crate::Diagnosable for $ty {
-      fn add_diagnostic(&self, _diagnostic: $crate::Diagnostic) {
-        unimplemented!(
-          "`<{ty} as Diagnosable>::add_diagnostic` is not implemented",
-          ty = stringify!($ty)
-        )
-      }
-      fn add_diagnostics(&self, _diagnostics: Vec<$crate::Diagnostic>) {
-        unimplemented!(
-          "`<{ty} as Diagnosable>::add_diagnostics` is not implemented",
-          ty = stringify!($ty)
-        )
-      }
-    }
+    impl $crate::Diagnosable for $ty {}
   };
 }
 
 pub fn errors_to_diagnostics(errs: Vec<Error>) -> Vec<Diagnostic> {
   errs.into_iter().map(Diagnostic::from).collect()
 }
+
+#[cfg(test)]
+mod tests {
+  use miette::MietteDiagnostic;
+
+  use super::{Diagnosable, Diagnostic, RspackSeverity};
+
+  #[test]
+  fn advice_severity() {
+    let diagnostic = Diagnostic::advice(
+      "Performance".to_string(),
+      "Consider splitting the chunk".to_string(),
+    );
+    assert_eq!(diagnostic.severity(), RspackSeverity::Advice);
+    assert_eq!(
+      RspackSeverity::from(miette::Severity::from(RspackSeverity::Advice)),
+      RspackSeverity::Advice
+    );
+    assert_eq!(RspackSeverity::from("advice"), RspackSeverity::Advice);
+    assert_eq!(RspackSeverity::Advice.to_string(), "advice");
+  }
+
+  #[test]
+  fn diagnosable_defaults_should_not_panic() {
+    struct Empty;
+    impl Diagnosable for Empty {}
+
+    let empty = Empty;
+    empty.add_diagnostic(Diagnostic::warn("Warn".to_string(), "warn".to_string()));
+    empty.add_diagnostics(vec![Diagnostic::error(
+      "Error".to_string(),
+      "error".to_string(),
+    )]);
+    assert!(empty.take_diagnostics().is_empty());
+  }
+
+  #[test]
+  fn typed_error_details() {
+    let diagnostic = Diagnostic::from(miette::Error::from(
+      MietteDiagnostic::new("Invalid configuration").with_url("https://rspack.dev/config/"),
+    ))
+    .with_hide_stack(Some(true));
+    assert_eq!(
+      diagnostic.details().as_deref(),
+      Some("see: https://rspack.dev/config/")
+    );
+    assert_eq!(diagnostic.with_hide_stack(None).details(), None);
+  }
+}
@@ -1,11 +1,17 @@
-use std::io::Write;
+use std::{
+  collections::{BTreeSet, HashMap},
+  io::Write,
+};
 
 use anyhow::Context;
 use miette::IntoDiagnostic;
+use rspack_collections::Identifier;
+use serde::Serialize;
+use serde_json::json;
 use termcolor::{Buffer, ColorSpec, StandardStreamLock, WriteColor};
 use termcolor::{ColorChoice, StandardStream};
 
-use crate::Diagnostic;
+use crate::{Diagnostic, Severity};
 
 pub trait FlushDiagnostic {
   fn flush_diagnostic(&mut self) {}
@@ -200,3 +206,328 @@ impl DiagnosticDisplay for DiagnosticDisplayer {
     }
   }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct JsonDiagnosticPosition {
+  /// 1-based line number.
+  pub line: usize,
+  /// 1-based column number.
+  pub column: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct JsonDiagnosticSpan {
+  pub start: JsonDiagnosticPosition,
+  pub end: JsonDiagnosticPosition,
+}
+
+/// Machine-readable representation of a [Diagnostic].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonDiagnostic {
+  pub severity: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub code: Option<String>,
+  pub message: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub help: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub file: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub span: Option<JsonDiagnosticSpan>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub loc: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub module_identifier: Option<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub module_trace: Vec<String>,
+}
+
+impl JsonDiagnostic {
+  pub fn new(diagnostic: &Diagnostic, module_trace: Vec<String>) -> Self {
+    let (name, span) = get_diagnostic_span(diagnostic).unzip();
+    Self {
+      severity: diagnostic.severity().to_string(),
+      code: diagnostic.code().map(|code| code.to_string()),
+      message: diagnostic.message(),
+      help: get_diagnostic_help(diagnostic.as_ref()),
+      file: diagnostic
+        .file()
+        .map(|file| file.to_string())
+        .or(name.flatten()),
+      span,
+      loc: diagnostic.loc(),
+      module_identifier: diagnostic
+        .module_identifier()
+        .map(|identifier| identifier.to_string()),
+      module_trace,
+    }
+  }
+}
+
+/// Help of the diagnostic, or of the first diagnostic in its cause chain which has one,
+/// as the graphical report shows the help of the whole chain.
+fn get_diagnostic_help(diagnostic: &dyn miette::Diagnostic) -> Option<String> {
+  diagnostic
+    .help()
+    .map(|help| help.to_string())
+    .or_else(|| diagnostic.diagnostic_source().and_then(get_diagnostic_help))
+}
+
+fn get_diagnostic_span(diagnostic: &Diagnostic) -> Option<(Option<String>, JsonDiagnosticSpan)> {
+  let source_code = diagnostic.source_code()?;
+  let label = diagnostic.labels()?.next()?;
+  let start = source_code.read_span(label.inner(), 0, 0).ok()?;
+  let end = source_code
+    .read_span(&(label.offset() + label.len(), 0).into(), 0, 0)
+    .ok()?;
+  Some((
+    start.name().map(|name| name.to_string()),
+    JsonDiagnosticSpan {
+      start: JsonDiagnosticPosition {
+        line: start.line() + 1,
+        column: start.column() + 1,
+      },
+      end: JsonDiagnosticPosition {
+        line: end.line() + 1,
+        column: end.column() + 1,
+      },
+    },
+  ))
+}
+
+/// Emit diagnostics as a JSON array of [JsonDiagnostic], for CI annotations and editor integrations.
+#[derive(Debug, Clone, Default)]
+pub struct JsonDiagnosticDisplay {
+  module_traces: HashMap<Identifier, Vec<String>>,
+}
+
+impl JsonDiagnosticDisplay {
+  /// Module traces of the diagnostics, keyed by the module identifier of a diagnostic.
+  pub fn with_module_traces(self, module_traces: HashMap<Identifier, Vec<String>>) -> Self {
+    Self { module_traces }
+  }
+
+  fn to_json_diagnostic(&self, diagnostic: &Diagnostic) -> JsonDiagnostic {
+    let module_trace = diagnostic
+      .module_identifier()
+      .and_then(|identifier| self.module_traces.get(&identifier))
+      .cloned()
+      .unwrap_or_default();
+    JsonDiagnostic::new(diagnostic, module_trace)
+  }
+}
+
+impl DiagnosticDisplay for JsonDiagnosticDisplay {
+  type Output = crate::Result<String>;
+
+  fn emit_batch_diagnostic(
+    &mut self,
+    diagnostics: impl Iterator<Item = &Diagnostic>,
+  ) -> Self::Output {
+    let diagnostics = diagnostics
+      .map(|diagnostic| self.to_json_diagnostic(diagnostic))
+      .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&diagnostics).into_diagnostic()
+  }
+
+  fn emit_diagnostic(&mut self, diagnostic: &Diagnostic) -> Self::Output {
+    serde_json::to_string_pretty(&self.to_json_diagnostic(diagnostic)).into_diagnostic()
+  }
+}
+
+/// Emit diagnostics as a [SARIF](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) log
+/// with a single run.
+#[derive(Debug, Clone, Default)]
+pub struct SarifDiagnosticDisplay {
+  json: JsonDiagnosticDisplay,
+}
+
+impl SarifDiagnosticDisplay {
+  pub fn with_module_traces(self, module_traces: HashMap<Identifier, Vec<String>>) -> Self {
+    Self {
+      json: self.json.with_module_traces(module_traces),
+    }
+  }
+
+  fn to_sarif_result(&self, diagnostic: &Diagnostic) -> serde_json::Value {
+    let JsonDiagnostic {
+      code,
+      message,
+      help,
+      file,
+      span,
+      module_identifier,
+      module_trace,
+      ..
+    } = self.json.to_json_diagnostic(diagnostic);
+    let level = match diagnostic.severity() {
+      Severity::Error => "error",
+      Severity::Warn => "warning",
+      Severity::Advice => "note",
+    };
+    let mut result = json!({
+      "ruleId": code.unwrap_or_else(|| "rspack".to_string()),
+      "level": level,
+      "message": { "text": message },
+      "properties": {
+        "help": help,
+        "moduleIdentifier": module_identifier,
+        "moduleTrace": module_trace,
+      },
+    });
+    if let Some(file) = file {
+      let mut physical_location = json!({ "artifactLocation": { "uri": file } });
+      if let Some(span) = span {
+        physical_location["region"] = json!({
+          "startLine": span.start.line,
+          "startColumn": span.start.column,
+          "endLine": span.end.line,
+          "endColumn": span.end.column,
+        });
+      }
+      result["locations"] = json!([{ "physicalLocation": physical_location }]);
+    }
+    result
+  }
+}
+
+impl DiagnosticDisplay for SarifDiagnosticDisplay {
+  type Output = crate::Result<String>;
+
+  fn emit_batch_diagnostic(
+    &mut self,
+    diagnostics: impl Iterator<Item = &Diagnostic>,
+  ) -> Self::Output {
+    let results = diagnostics
+      .map(|diagnostic| self.to_sarif_result(diagnostic))
+      .collect::<Vec<_>>();
+    let rules = results
+      .iter()
+      .filter_map(|result| result["ruleId"].as_str())
+      .collect::<BTreeSet<_>>()
+      .into_iter()
+      .map(|id| json!({ "id": id }))
+      .collect::<Vec<_>>();
+    let log = json!({
+      "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+      "version": "2.1.0",
+      "runs": [{
+        "tool": {
+          "driver": {
+            "name": "rspack",
+            "informationUri": "https://rspack.dev",
+            "rules": rules,
+          },
+        },
+        "results": results,
+      }],
+    });
+    serde_json::to_string_pretty(&log).into_diagnostic()
+  }
+
+  fn emit_diagnostic(&mut self, diagnostic: &Diagnostic) -> Self::Output {
+    self.emit_batch_diagnostic(std::iter::once(diagnostic))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use rspack_collections::Identifier;
+
+  use super::{DiagnosticDisplay, JsonDiagnosticDisplay, SarifDiagnosticDisplay};
+  use crate::{Diagnostic, TraceableError};
+
+  fn create_diagnostic() -> Diagnostic {
+    let source = "const a = 1;\nconst b = a.foo;\n".to_string();
+    let start = source.find("a.foo").expect("should have a.foo");
+    Diagnostic::from(miette::Error::new(
+      TraceableError::from_file(
+        source,
+        start,
+        start + "a.foo".len(),
+        "Module parse warning".to_string(),
+        "Unknown property".to_string(),
+      )
+      .with_severity(miette::Severity::Warning)
+      .with_help(Some("Remove the property access")),
+    ))
+    .with_file(Some("/src/index.js".into()))
+    .with_module_identifier(Some("/src/index.js".into()))
+  }
+
+  #[test]
+  fn json_diagnostic_display() {
+    let mut display = JsonDiagnosticDisplay::default().with_module_traces(HashMap::from([(
+      Identifier::from("/src/index.js"),
+      vec!["./src/index.js".to_string(), "./src/main.js".to_string()],
+    )]));
+    let output = display
+      .emit_batch_diagnostic([create_diagnostic()].iter())
+      .expect("should emit json");
+    let value: serde_json::Value = serde_json::from_str(&output).expect("should be valid json");
+    let diagnostic = &value[0];
+    assert_eq!(diagnostic["severity"], "warning");
+    assert_eq!(diagnostic["file"], "/src/index.js");
+    assert_eq!(diagnostic["moduleIdentifier"], "/src/index.js");
+    assert_eq!(diagnostic["help"], "Remove the property access");
+    assert_eq!(
+      diagnostic["span"],
+      serde_json::json!({
+        "start": { "line": 2, "column": 11 },
+        "end": { "line": 2, "column": 16 },
+      })
+    );
+    assert_eq!(
+      diagnostic["moduleTrace"],
+      serde_json::json!(["./src/index.js", "./src/main.js"])
+    );
+  }
+
+  #[derive(Debug, thiserror::Error, miette::Diagnostic)]
+  #[error("Module advice")]
+  #[diagnostic(severity(Advice))]
+  struct ModuleAdvice {
+    #[diagnostic_source]
+    source: miette::MietteDiagnostic,
+  }
+
+  #[test]
+  fn json_diagnostic_display_advice() {
+    let diagnostic = Diagnostic::from(miette::Error::new(ModuleAdvice {
+      source: miette::MietteDiagnostic::new("Unused export").with_help("Remove the export"),
+    }))
+    .with_module_identifier(Some("/src/main.js".into()));
+    let mut display = JsonDiagnosticDisplay::default().with_module_traces(HashMap::from([(
+      Identifier::from("/src/main.js"),
+      vec!["./src/main.js".to_string()],
+    )]));
+    let output = display
+      .emit_diagnostic(&diagnostic)
+      .expect("should emit json");
+    let value: serde_json::Value = serde_json::from_str(&output).expect("should be valid json");
+    assert_eq!(value["severity"], "advice");
+    assert_eq!(value["help"], "Remove the export");
+    assert_eq!(value["moduleTrace"], serde_json::json!(["./src/main.js"]));
+  }
+
+  #[test]
+  fn sarif_diagnostic_display() {
+    let mut display = SarifDiagnosticDisplay::default();
+    let output = display
+      .emit_diagnostic(&create_diagnostic())
+      .expect("should emit sarif");
+    let value: serde_json::Value = serde_json::from_str(&output).expect("should be valid json");
+    assert_eq!(value["version"], "2.1.0");
+    let result = &value["runs"][0]["results"][0];
+    assert_eq!(result["level"], "warning");
+    assert_eq!(result["properties"]["help"], "Remove the property access");
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "/src/index.js");
+    assert_eq!(location["region"]["startLine"], 2);
+    assert_eq!(location["region"]["startColumn"], 11);
+    assert_eq!(location["region"]["endColumn"], 16);
+  }
+}
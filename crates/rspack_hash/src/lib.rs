@@ -3,23 +3,35 @@ use std::{
   hash::{Hash, Hasher},
 };
 
-use data_encoding::HEXLOWER_PERMISSIVE;
+use data_encoding::{BASE64, BASE64URL_NOPAD, HEXLOWER_PERMISSIVE};
 use md4::Digest;
 use smol_str::SmolStr;
-use xxhash_rust::xxh64::Xxh64;
+use xxhash_rust::{xxh3::Xxh3, xxh64::Xxh64};
 
 #[derive(Debug, Clone, Copy)]
 pub enum HashFunction {
   Xxhash64,
+  Xxhash3_128,
   MD4,
+  MD5,
+  SHA256,
+  SHA512,
 }
 
-impl From<&str> for HashFunction {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashFunction {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> rspack_error::Result<Self> {
     match value {
-      "xxhash64" => HashFunction::Xxhash64,
-      "md4" => HashFunction::MD4,
-      _ => unimplemented!("{}", value),
+      "xxhash64" => Ok(HashFunction::Xxhash64),
+      "xxhash3-128" => Ok(HashFunction::Xxhash3_128),
+      "md4" => Ok(HashFunction::MD4),
+      "md5" => Ok(HashFunction::MD5),
+      "sha256" => Ok(HashFunction::SHA256),
+      "sha512" => Ok(HashFunction::SHA512),
+      _ => Err(rspack_error::error!(
+        "Unsupported hash function: '{value}', expected one of 'xxhash64', 'xxhash3-128', 'md4', 'md5', 'sha256' or 'sha512'"
+      )),
     }
   }
 }
@@ -27,13 +39,23 @@ impl From<&str> for HashFunction {
 #[derive(Debug, Clone, Copy)]
 pub enum HashDigest {
   Hex,
+  Base64,
+  Base64Url,
+  Base58,
 }
 
-impl From<&str> for HashDigest {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashDigest {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> rspack_error::Result<Self> {
     match value {
-      "hex" => HashDigest::Hex,
-      _ => unimplemented!(),
+      "hex" => Ok(HashDigest::Hex),
+      "base64" => Ok(HashDigest::Base64),
+      "base64url" => Ok(HashDigest::Base64Url),
+      "base58" => Ok(HashDigest::Base58),
+      _ => Err(rspack_error::error!(
+        "Unsupported hash digest: '{value}', expected one of 'hex', 'base64', 'base64url' or 'base58'"
+      )),
     }
   }
 }
@@ -56,14 +78,22 @@ impl From<Option<String>> for HashSalt {
 #[derive(Clone)]
 pub enum RspackHash {
   Xxhash64(Box<Xxh64>),
+  Xxhash3_128(Box<Xxh3>),
   MD4(Box<md4::Md4>),
+  MD5(Box<md5::Md5>),
+  SHA256(Box<sha2::Sha256>),
+  SHA512(Box<sha2::Sha512>),
 }
 
 impl fmt::Debug for RspackHash {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     match self {
       Self::Xxhash64(_) => write!(f, "RspackHash(Xxhash64)"),
+      Self::Xxhash3_128(_) => write!(f, "RspackHash(Xxhash3_128)"),
       Self::MD4(_) => write!(f, "RspackHash(MD4)"),
+      Self::MD5(_) => write!(f, "RspackHash(MD5)"),
+      Self::SHA256(_) => write!(f, "RspackHash(SHA256)"),
+      Self::SHA512(_) => write!(f, "RspackHash(SHA512)"),
     }
   }
 }
@@ -72,7 +102,11 @@ impl RspackHash {
   pub fn new(function: &HashFunction) -> Self {
     match function {
       HashFunction::Xxhash64 => Self::Xxhash64(Box::new(Xxh64::new(0))),
+      HashFunction::Xxhash3_128 => Self::Xxhash3_128(Box::new(Xxh3::new())),
       HashFunction::MD4 => Self::MD4(Box::new(md4::Md4::new())),
+      HashFunction::MD5 => Self::MD5(Box::new(md5::Md5::new())),
+      HashFunction::SHA256 => Self::SHA256(Box::new(sha2::Sha256::new())),
+      HashFunction::SHA512 => Self::SHA512(Box::new(sha2::Sha512::new())),
     }
   }
 
@@ -87,7 +121,11 @@ impl RspackHash {
   pub fn digest(self, digest: &HashDigest) -> RspackHashDigest {
     let inner = match self {
       RspackHash::Xxhash64(hasher) => hasher.finish().to_be_bytes().to_vec(),
+      RspackHash::Xxhash3_128(hasher) => hasher.digest128().to_be_bytes().to_vec(),
       RspackHash::MD4(hash) => hash.finalize().to_vec(),
+      RspackHash::MD5(hash) => hash.finalize().to_vec(),
+      RspackHash::SHA256(hash) => hash.finalize().to_vec(),
+      RspackHash::SHA512(hash) => hash.finalize().to_vec(),
     };
     RspackHashDigest::new(inner, digest)
   }
@@ -97,30 +135,34 @@ impl Hasher for RspackHash {
   fn finish(&self) -> u64 {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.finish(),
-      RspackHash::MD4(hasher) => {
-        // finalize take ownership, so we need to clone it
-        let hash = hasher.clone().finalize();
-        let msb_u64: u64 = ((hash[0] as u64) << 56)
-          | ((hash[1] as u64) << 48)
-          | ((hash[2] as u64) << 40)
-          | ((hash[3] as u64) << 32)
-          | ((hash[4] as u64) << 24)
-          | ((hash[5] as u64) << 16)
-          | ((hash[6] as u64) << 8)
-          | (hash[7] as u64);
-        msb_u64
-      }
+      RspackHash::Xxhash3_128(hasher) => (hasher.digest128() >> 64) as u64,
+      // finalize take ownership, so we need to clone it
+      RspackHash::MD4(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::MD5(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::SHA256(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::SHA512(hasher) => msb_u64(&hasher.clone().finalize()),
     }
   }
 
   fn write(&mut self, bytes: &[u8]) {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.write(bytes),
+      RspackHash::Xxhash3_128(hasher) => hasher.update(bytes),
       RspackHash::MD4(hasher) => hasher.update(bytes),
+      RspackHash::MD5(hasher) => hasher.update(bytes),
+      RspackHash::SHA256(hasher) => hasher.update(bytes),
+      RspackHash::SHA512(hasher) => hasher.update(bytes),
     }
   }
 }
 
+/// The most significant 8 bytes of a digest as a big endian `u64`.
+fn msb_u64(hash: &[u8]) -> u64 {
+  let mut bytes = [0u8; 8];
+  bytes.copy_from_slice(&hash[..8]);
+  u64::from_be_bytes(bytes)
+}
+
 #[derive(Debug, Clone, Eq)]
 pub struct RspackHashDigest {
   encoded: SmolStr,
@@ -138,6 +180,9 @@ impl RspackHashDigest {
   pub fn new(inner: Vec<u8>, digest: &HashDigest) -> Self {
     let encoded = match digest {
       HashDigest::Hex => HEXLOWER_PERMISSIVE.encode(&inner).into(),
+      HashDigest::Base64 => BASE64.encode(&inner).into(),
+      HashDigest::Base64Url => BASE64URL_NOPAD.encode(&inner).into(),
+      HashDigest::Base58 => encode_base58(&inner).into(),
     };
     Self { encoded }
   }
@@ -163,3 +208,73 @@ impl PartialEq for RspackHashDigest {
     self.encoded == other.encoded
   }
 }
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Encode bytes with the bitcoin base58 alphabet, leading zero bytes are encoded as `1`.
+fn encode_base58(input: &[u8]) -> String {
+  let zeros = input.iter().take_while(|b| **b == 0).count();
+  // little endian digits in base 58
+  let mut digits: Vec<u8> = Vec::with_capacity(input.len() * 138 / 100 + 1);
+  for byte in &input[zeros..] {
+    let mut carry = *byte as u32;
+    for digit in digits.iter_mut() {
+      carry += (*digit as u32) << 8;
+      *digit = (carry % 58) as u8;
+      carry /= 58;
+    }
+    while carry > 0 {
+      digits.push((carry % 58) as u8);
+      carry /= 58;
+    }
+  }
+  std::iter::repeat(BASE58_ALPHABET[0])
+    .take(zeros)
+    .chain(digits.iter().rev().map(|d| BASE58_ALPHABET[*d as usize]))
+    .map(char::from)
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+  use std::hash::Hasher;
+
+  use super::*;
+
+  fn hash(function: &str, digest: &str, content: &str) -> String {
+    let mut hasher = RspackHash::new(&HashFunction::try_from(function).expect("should be valid"));
+    hasher.write(content.as_bytes());
+    hasher
+      .digest(&HashDigest::try_from(digest).expect("should be valid"))
+      .encoded()
+      .to_string()
+  }
+
+  #[test]
+  fn should_digest_with_all_functions() {
+    assert_eq!(
+      hash("md5", "hex", "hello"),
+      "5d41402abc4b2a76b9719d911017c592"
+    );
+    assert_eq!(
+      hash("sha256", "hex", "hello"),
+      "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+    );
+    assert_eq!(hash("sha512", "hex", "hello").len(), 128);
+    assert_eq!(hash("xxhash3-128", "hex", "hello").len(), 32);
+  }
+
+  #[test]
+  fn should_encode_with_all_digests() {
+    assert_eq!(hash("md5", "base64", "hello"), "XUFAKrxLKna5cZ2REBfFkg==");
+    assert_eq!(hash("md5", "base64url", "hello"), "XUFAKrxLKna5cZ2REBfFkg");
+    assert_eq!(encode_base58(b"hello world"), "StV1DL6CwTryKyV");
+    assert_eq!(encode_base58(&[0, 0, 1]), "112");
+  }
+
+  #[test]
+  fn should_reject_unknown_values() {
+    assert!(HashFunction::try_from("sha1").is_err());
+    assert!(HashDigest::try_from("latin1").is_err());
+  }
+}
@@ -1,3 +1,5 @@
+mod profile;
+
 use async_trait::async_trait;
 use rspack_error::Result;
 use rustc_hash::FxHashSet;
@@ -21,6 +23,9 @@ pub trait Hook {
   fn intercept(&mut self, interceptor: impl Interceptor<Self> + Send + Sync + 'static)
   where
     Self: Sized;
+
+  /// Records the calls of the taps of this hook to the profiler, when it is enabled.
+  fn set_profiler(&mut self, profiler: HookProfiler);
 }
 
 // pub trait Plugin<HookContainer> {
@@ -35,4 +40,5 @@ pub mod __macro_helper {
   pub use rustc_hash::FxHashSet;
 }
 
+pub use profile::{HookProfiler, TapCall};
 pub use rspack_macros::{define_hook, plugin, plugin_hook};
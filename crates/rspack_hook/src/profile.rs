@@ -0,0 +1,88 @@
+use std::{
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+  },
+  time::{Duration, Instant},
+};
+
+/// A call of a tap, recorded when the hook profiling is enabled.
+#[derive(Debug, Clone)]
+pub struct TapCall {
+  /// The plugin of the tap, or the type name of the tap for taps which do not name their plugin.
+  pub plugin: String,
+  pub hook: &'static str,
+  pub stage: i32,
+  pub start: Instant,
+  pub duration: Duration,
+}
+
+#[derive(Debug, Default)]
+struct HookProfilerInner {
+  enabled: AtomicBool,
+  calls: Mutex<Vec<TapCall>>,
+}
+
+/// Records the calls of the taps of the hooks it is set to by [crate::Hook::set_profiler].
+///
+/// Each compiler owns its profiler, so the calls of compilers running in the same process are
+/// recorded separately.
+#[derive(Debug, Clone, Default)]
+pub struct HookProfiler(Arc<HookProfilerInner>);
+
+impl HookProfiler {
+  /// Enables or disables recording the calls of the taps.
+  pub fn enable(&self, enabled: bool) {
+    self.0.enabled.store(enabled, Ordering::Relaxed);
+  }
+
+  pub fn is_enabled(&self) -> bool {
+    self.0.enabled.load(Ordering::Relaxed)
+  }
+
+  /// Takes the tap calls recorded since the last time this was called.
+  pub fn take_calls(&self) -> Vec<TapCall> {
+    std::mem::take(&mut *self.0.calls.lock().expect("should lock tap calls"))
+  }
+
+  #[doc(hidden)]
+  pub fn start_tap(&self, plugin: &str, hook: &'static str, stage: i32) -> Option<TapTimer<'_>> {
+    self.is_enabled().then(|| TapTimer {
+      profiler: self,
+      plugin: plugin.to_string(),
+      hook,
+      stage,
+      start: Instant::now(),
+    })
+  }
+}
+
+/// Records the duration of a tap call when dropped, so that taps returning an error are also
+/// recorded.
+#[doc(hidden)]
+pub struct TapTimer<'a> {
+  profiler: &'a HookProfiler,
+  plugin: String,
+  hook: &'static str,
+  stage: i32,
+  start: Instant,
+}
+
+impl Drop for TapTimer<'_> {
+  fn drop(&mut self) {
+    let call = TapCall {
+      plugin: std::mem::take(&mut self.plugin),
+      hook: self.hook,
+      stage: self.stage,
+      start: self.start,
+      duration: self.start.elapsed(),
+    };
+    self
+      .profiler
+      .0
+      .calls
+      .lock()
+      .expect("should lock tap calls")
+      .push(call);
+  }
+}
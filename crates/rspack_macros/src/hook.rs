@@ -86,7 +86,8 @@ impl DefineHookInput {
         _ => Err(Error::new_spanned(arg, "unexpected arg")),
       })
       .collect::<Result<Punctuated<&Ident, Comma>>>()?;
-    let call_body = exec_kind.body(arg_names);
+    let trait_name_lit_str = LitStr::new(&trait_name.to_string(), trait_name.span());
+    let call_body = exec_kind.body(arg_names, &trait_name_lit_str);
     let call_fn = quote! {
       fn call(&self, #args) -> #ret {
         #call_body
@@ -106,11 +107,17 @@ impl DefineHookInput {
         fn stage(&self) -> i32 {
           0
         }
+
+        /// The name of the plugin this tap belongs to, which is used by the hook profiling.
+        fn plugin_name(&self) -> &str {
+          std::any::type_name::<Self>()
+        }
       }
 
       pub struct #hook_name {
         taps: Vec<Box<dyn #trait_name + Send + Sync>>,
         interceptors: Vec<Box<dyn rspack_hook::Interceptor<Self> + Send + Sync>>,
+        profiler: Option<rspack_hook::HookProfiler>,
       }
 
       impl rspack_hook::Hook for #hook_name {
@@ -123,6 +130,10 @@ impl DefineHookInput {
         fn intercept(&mut self, interceptor: impl rspack_hook::Interceptor<Self> + Send + Sync + 'static) {
           self.interceptors.push(Box::new(interceptor));
         }
+
+        fn set_profiler(&mut self, profiler: rspack_hook::HookProfiler) {
+          self.profiler = Some(profiler);
+        }
       }
 
       impl std::fmt::Debug for #hook_name {
@@ -136,6 +147,7 @@ impl DefineHookInput {
           Self {
             taps: Default::default(),
             interceptors: Default::default(),
+            profiler: None,
           }
         }
       }
@@ -215,13 +227,20 @@ impl ExecKind {
     }
   }
 
-  pub fn body(&self, args: Punctuated<&Ident, Comma>) -> TokenStream {
+  pub fn body(&self, args: Punctuated<&Ident, Comma>, hook_name: &LitStr) -> TokenStream {
     let additional_taps = self.additional_taps();
+    let start_tap = quote! {
+      let _timer = self
+        .profiler
+        .as_ref()
+        .and_then(|profiler| profiler.start_tap(tap.plugin_name(), #hook_name, tap.stage()));
+    };
     match self {
       Self::AsyncSeries => {
         quote! {
           #additional_taps
           for tap in all_taps {
+            #start_tap
             tap.run(#args).await?;
           }
           Ok(())
@@ -231,6 +250,7 @@ impl ExecKind {
         quote! {
           #additional_taps
           for tap in all_taps {
+            #start_tap
             if let Some(res) = tap.run(#args).await? {
               return Ok(Some(res));
             }
@@ -243,6 +263,7 @@ impl ExecKind {
           #additional_taps
           let mut data = #args;
           for tap in all_taps {
+            #start_tap
             data = tap.run(data).await?
           }
           Ok(data)
@@ -251,7 +272,13 @@ impl ExecKind {
       Self::AsyncParallel => {
         quote! {
           #additional_taps
-          let futs: std::vec::Vec<_> = all_taps.iter().map(|t| t.run(#args)).collect();
+          let futs: std::vec::Vec<_> = all_taps
+            .iter()
+            .map(|tap| async move {
+              #start_tap
+              tap.run(#args).await
+            })
+            .collect();
           futures_concurrency::vec::TryJoin(futs).await?;
           Ok(())
         }
@@ -260,6 +287,7 @@ impl ExecKind {
         quote! {
           #additional_taps
           for tap in all_taps {
+            #start_tap
             tap.run(#args)?;
           }
           Ok(())
@@ -269,6 +297,7 @@ impl ExecKind {
         quote! {
           #additional_taps
           for tap in all_taps {
+            #start_tap
             if let Some(res) = tap.run(#args)? {
               return Ok(Some(res));
             }
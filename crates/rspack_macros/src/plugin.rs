@@ -200,6 +200,8 @@ pub fn expand_fn(args: HookArgs, input: syn::ItemFn) -> proc_macro::TokenStream
     }
   });
 
+  let plugin_name = syn::LitStr::new(&name.to_string(), Span::call_site());
+
   let attr = if is_async {
     Some(quote! { #[::rspack_hook::__macro_helper::async_trait] })
   } else {
@@ -246,6 +248,10 @@ pub fn expand_fn(args: HookArgs, input: syn::ItemFn) -> proc_macro::TokenStream
       }
 
       #stage_fn
+
+      fn plugin_name(&self) -> &str {
+        #plugin_name
+      }
     }
   };
   expanded.into()
@@ -2,6 +2,8 @@ use rspack_error::Result;
 use rspack_hook::{define_hook, plugin, plugin_hook};
 
 mod simple {
+  use rspack_hook::{Hook, HookProfiler};
+
   use super::*;
 
   define_hook!(Render: AsyncSeriesBail(compilation: &Compilation, source: &mut Source) -> bool);
@@ -45,4 +47,47 @@ mod simple {
     assert_eq!(source.content, "plugin.render0");
     Ok(())
   }
+
+  #[tokio::test]
+  async fn profile() -> Result<()> {
+    let mut compilation = Compilation {
+      id: 1,
+      render_hook: RenderHook::default(),
+    };
+    let mut source = Source {
+      content: String::new(),
+    };
+    let plugin = MyRenderPlugin::default();
+    compilation.render_hook.tap(render::new(&plugin));
+    let profiler = HookProfiler::default();
+    compilation.render_hook.set_profiler(profiler.clone());
+    compilation
+      .render_hook
+      .call(&compilation, &mut source)
+      .await?;
+    assert!(profiler.take_calls().is_empty());
+
+    profiler.enable(true);
+    compilation
+      .render_hook
+      .call(&compilation, &mut source)
+      .await?;
+    profiler.enable(false);
+    let calls = profiler.take_calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].plugin, "MyRenderPlugin");
+    assert_eq!(calls[0].hook, "Render");
+    assert_eq!(calls[0].stage, 0);
+
+    // another hook does not record to this profiler
+    let other_profiler = HookProfiler::default();
+    other_profiler.enable(true);
+    let mut other_hook = RenderHook::default();
+    other_hook.tap(render::new(&plugin));
+    other_hook.set_profiler(other_profiler.clone());
+    other_hook.call(&compilation, &mut source).await?;
+    assert!(profiler.take_calls().is_empty());
+    assert_eq!(other_profiler.take_calls().len(), 1);
+    Ok(())
+  }
 }
@@ -6,6 +6,9 @@ mod mock_hook {
     fn stage(&self) -> i32 {
       0
     }
+    fn plugin_name(&self) -> &str {
+      std::any::type_name::<Self>()
+    }
   }
 }
 
@@ -59,6 +62,8 @@ mod named_struct {
     assert_eq!(r, "process_assets aa 0");
     let s = mock_hook::AsyncSeries::stage(hook3);
     assert_eq!(s, 0);
+    let n = mock_hook::AsyncSeries::plugin_name(hook3);
+    assert_eq!(n, "Plugin");
   }
 }
 
@@ -102,5 +107,7 @@ mod unit_struct {
     assert_eq!(r, "process_assets");
     let s = mock_hook::AsyncSeries::stage(hook3);
     assert_eq!(s, 0);
+    let n = mock_hook::AsyncSeries::plugin_name(hook3);
+    assert_eq!(n, "Plugin");
   }
 }
@@ -0,0 +1,275 @@
+#![feature(let_chains)]
+
+use std::{io::Write, str::FromStr};
+
+use flate2::{write::GzEncoder, Compression};
+use rayon::prelude::*;
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  AssetInfo, Compilation, CompilationAsset, CompilationProcessAssets, Logger, Plugin,
+  PluginContext,
+};
+use rspack_error::{error, Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_util::asset_condition::AssetConditions;
+
+const PLUGIN_NAME: &str = "rspack.CompressionRspackPlugin";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionAlgorithm {
+  Gzip,
+  Brotli,
+  Zstd,
+}
+
+impl FromStr for CompressionAlgorithm {
+  type Err = rspack_error::Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    match s {
+      "gzip" => Ok(Self::Gzip),
+      "brotliCompress" => Ok(Self::Brotli),
+      "zstdCompress" => Ok(Self::Zstd),
+      _ => Err(error!(
+        "Expect algorithm of CompressionRspackPlugin to be one of 'gzip', 'brotliCompress' or 'zstdCompress', but got '{s}'"
+      )),
+    }
+  }
+}
+
+impl CompressionAlgorithm {
+  pub fn extension(&self) -> &'static str {
+    match self {
+      Self::Gzip => "gz",
+      Self::Brotli => "br",
+      Self::Zstd => "zst",
+    }
+  }
+
+  /// Compresses the content with the highest level, as the assets are compressed once and
+  /// served many times.
+  pub fn compress(&self, content: &[u8]) -> std::io::Result<Vec<u8>> {
+    match self {
+      Self::Gzip => {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+        encoder.write_all(content)?;
+        encoder.finish()
+      }
+      Self::Brotli => {
+        let mut output = Vec::new();
+        brotli::BrotliCompress(
+          &mut &content[..],
+          &mut output,
+          &brotli::enc::BrotliEncoderParams::default(),
+        )?;
+        Ok(output)
+      }
+      Self::Zstd => zstd::encode_all(content, 19),
+    }
+  }
+
+  fn set_related(&self, info: &mut AssetInfo, filename: String) {
+    let related = &mut info.related;
+    match self {
+      Self::Gzip => related.gzipped = Some(filename),
+      Self::Brotli => related.brotli_compressed = Some(filename),
+      Self::Zstd => related.zstd_compressed = Some(filename),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct CompressionRspackPluginOptions {
+  pub test: Option<AssetConditions>,
+  pub include: Option<AssetConditions>,
+  pub exclude: Option<AssetConditions>,
+  pub algorithms: Vec<CompressionAlgorithm>,
+  /// Only assets whose size is not less than this are compressed.
+  pub threshold: f64,
+  /// Only compressed assets whose size divided by the size of the original asset is less than
+  /// this are emitted.
+  pub min_ratio: f64,
+}
+
+impl CompressionRspackPluginOptions {
+  fn is_matched(&self, filename: &str) -> bool {
+    if let Some(condition) = &self.test
+      && !condition.try_match(filename)
+    {
+      return false;
+    }
+    if let Some(condition) = &self.include
+      && !condition.try_match(filename)
+    {
+      return false;
+    }
+    if let Some(condition) = &self.exclude
+      && condition.try_match(filename)
+    {
+      return false;
+    }
+    true
+  }
+}
+
+/// Emits precompressed `.gz`, `.br` and `.zst` siblings of the assets, which are linked from
+/// the original assets by `AssetInfo.related`.
+#[plugin]
+#[derive(Debug)]
+pub struct CompressionRspackPlugin {
+  options: CompressionRspackPluginOptions,
+}
+
+impl CompressionRspackPlugin {
+  pub fn new(options: CompressionRspackPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+struct CompressedAsset {
+  original: String,
+  algorithm: CompressionAlgorithm,
+  filename: String,
+  content: Vec<u8>,
+}
+
+#[plugin_hook(CompilationProcessAssets for CompressionRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_TRANSFER)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let logger = compilation.get_logger(PLUGIN_NAME);
+  let start = logger.time("compress assets");
+  let options = &self.options;
+
+  let extensions = options
+    .algorithms
+    .iter()
+    .map(|algorithm| format!(".{}", algorithm.extension()))
+    .collect::<Vec<_>>();
+
+  let results = compilation
+    .assets()
+    .par_iter()
+    .filter(|(filename, asset)| {
+      asset.get_source().is_some()
+        // assets which are already compressed
+        && !extensions.iter().any(|ext| filename.ends_with(ext.as_str()))
+        && options.is_matched(filename)
+    })
+    .flat_map(|(filename, asset)| {
+      let content = asset.get_source().expect("should have source").buffer();
+      if (content.len() as f64) < options.threshold {
+        return vec![];
+      }
+      let path = filename.split('?').next().expect("Should have filename");
+      options
+        .algorithms
+        .par_iter()
+        .filter_map(|algorithm| {
+          let compressed = match algorithm.compress(&content) {
+            Ok(compressed) => compressed,
+            Err(e) => return Some(Err((filename.clone(), e))),
+          };
+          let ratio = compressed.len() as f64 / content.len() as f64;
+          (ratio < options.min_ratio).then(|| {
+            Ok(CompressedAsset {
+              original: filename.clone(),
+              algorithm: *algorithm,
+              filename: format!("{path}.{}", algorithm.extension()),
+              content: compressed,
+            })
+          })
+        })
+        .collect::<Vec<_>>()
+    })
+    .collect::<Vec<_>>();
+
+  for result in results {
+    let compressed = match result {
+      Ok(compressed) => compressed,
+      Err((filename, e)) => {
+        compilation.push_diagnostic(Diagnostic::error(
+          PLUGIN_NAME.to_string(),
+          format!("Failed to compress {filename}: {e}"),
+        ));
+        continue;
+      }
+    };
+    let original_info = compilation.assets()[&compressed.original].get_info();
+    let info = AssetInfo {
+      immutable: original_info.immutable,
+      ..Default::default()
+    };
+    compilation.update_asset(&compressed.original, |source, mut info| {
+      compressed
+        .algorithm
+        .set_related(&mut info, compressed.filename.clone());
+      Ok((source, info))
+    })?;
+    compilation.emit_asset(
+      compressed.filename,
+      CompilationAsset::new(Some(RawSource::from(compressed.content).boxed()), info),
+    );
+  }
+
+  logger.time_end(start);
+  Ok(())
+}
+
+impl Plugin for CompressionRspackPlugin {
+  fn name(&self) -> &'static str {
+    PLUGIN_NAME
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::io::Read;
+
+  use super::*;
+
+  #[test]
+  fn compression_round_trip() {
+    let content = "console.log('hello world');\n".repeat(100);
+
+    let gzipped = CompressionAlgorithm::Gzip
+      .compress(content.as_bytes())
+      .expect("should compress");
+    let mut decoded = String::new();
+    flate2::read::GzDecoder::new(&gzipped[..])
+      .read_to_string(&mut decoded)
+      .expect("should decompress");
+    assert_eq!(decoded, content);
+
+    let brotli_compressed = CompressionAlgorithm::Brotli
+      .compress(content.as_bytes())
+      .expect("should compress");
+    let mut decoded = String::new();
+    brotli::Decompressor::new(&brotli_compressed[..], 4096)
+      .read_to_string(&mut decoded)
+      .expect("should decompress");
+    assert_eq!(decoded, content);
+
+    let zstd_compressed = CompressionAlgorithm::Zstd
+      .compress(content.as_bytes())
+      .expect("should compress");
+    assert_eq!(
+      zstd::decode_all(&zstd_compressed[..]).expect("should decompress"),
+      content.as_bytes()
+    );
+
+    assert!(zstd_compressed.len() < content.len());
+    assert!("deflate".parse::<CompressionAlgorithm>().is_err());
+  }
+}
@@ -1,6 +1,6 @@
 use rspack_core::{
-  AsContextDependency, AsDependencyTemplate, Dependency, DependencyCategory, DependencyId,
-  DependencyRange, DependencyType, ModuleDependency,
+  create_resource_identifier_for_module_dependency, AsContextDependency, AsDependencyTemplate,
+  Dependency, DependencyCategory, DependencyId, DependencyRange, DependencyType, ModuleDependency,
 };
 
 #[derive(Debug, Clone)]
@@ -8,14 +8,21 @@ pub struct CssComposeDependency {
   id: DependencyId,
   request: String,
   range: DependencyRange,
+  resource_identifier: String,
 }
 
 impl CssComposeDependency {
   pub fn new(request: String, range: DependencyRange) -> Self {
+    let resource_identifier = create_resource_identifier_for_module_dependency(
+      &DependencyCategory::CssCompose,
+      &DependencyType::CssCompose,
+      &request,
+    );
     Self {
       id: DependencyId::new(),
       request,
       range,
+      resource_identifier,
     }
   }
 }
@@ -25,6 +32,10 @@ impl Dependency for CssComposeDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn category(&self) -> &DependencyCategory {
     &DependencyCategory::CssCompose
   }
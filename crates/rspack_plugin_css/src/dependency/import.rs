@@ -1,24 +1,151 @@
 use rspack_core::{
+  create_resource_identifier_for_module_dependency,
+  rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt},
   AsContextDependency, Compilation, Dependency, DependencyCategory, DependencyId, DependencyRange,
   DependencyTemplate, DependencyType, ModuleDependency, RuntimeSpec, TemplateContext,
   TemplateReplaceSource,
 };
 
+/// The `layer(...)`, `supports(...)` and media query qualifiers of an `@import` rule.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CssImportConditions {
+  /// `Some("")` for an anonymous `layer`
+  pub layer: Option<String>,
+  pub supports: Option<String>,
+  pub media: Option<String>,
+}
+
+impl CssImportConditions {
+  pub fn is_empty(&self) -> bool {
+    self.layer.is_none() && self.supports.is_none() && self.media.is_none()
+  }
+
+  /// Wraps the content of the imported module in the matching at-rules,
+  /// `@layer` is the outermost and `@media` the innermost one.
+  pub fn wrap(&self, source: BoxSource) -> BoxSource {
+    let mut source = source;
+    if let Some(media) = &self.media {
+      source = wrap_in_block(format!("@media {media} {{\n"), source);
+    }
+    if let Some(supports) = &self.supports {
+      source = wrap_in_block(format!("@supports ({supports}) {{\n"), source);
+    }
+    if let Some(layer) = &self.layer {
+      let start = if layer.is_empty() {
+        "@layer {\n".to_string()
+      } else {
+        format!("@layer {layer} {{\n")
+      };
+      source = wrap_in_block(start, source);
+    }
+    source
+  }
+
+  /// The qualifiers written after the url of an `@import` rule, used for external imports
+  /// which can't be nested in blocks.
+  pub fn to_import_qualifiers(&self) -> String {
+    let mut qualifiers = String::new();
+    if let Some(layer) = &self.layer {
+      if layer.is_empty() {
+        qualifiers.push_str(" layer");
+      } else {
+        qualifiers.push_str(&format!(" layer({layer})"));
+      }
+    }
+    if let Some(supports) = &self.supports {
+      qualifiers.push_str(&format!(" supports({supports})"));
+    }
+    if let Some(media) = &self.media {
+      qualifiers.push(' ');
+      qualifiers.push_str(media);
+    }
+    qualifiers
+  }
+}
+
+fn wrap_in_block(start: String, source: BoxSource) -> BoxSource {
+  ConcatSource::new([
+    RawSource::from(start).boxed(),
+    source,
+    RawSource::from("}\n").boxed(),
+  ])
+  .boxed()
+}
+
 #[derive(Debug, Clone)]
 pub struct CssImportDependency {
   id: DependencyId,
   request: String,
   range: DependencyRange,
+  conditions: CssImportConditions,
+  /// The conditions of the `@import` chain the importing module is reached through
+  inheritance: Vec<CssImportConditions>,
+  /// The resources of the importing module and the modules of its `@import` chain
+  issuers: Vec<String>,
+  resource_identifier: String,
 }
 
 impl CssImportDependency {
-  pub fn new(request: String, range: DependencyRange) -> Self {
+  pub fn new(
+    request: String,
+    range: DependencyRange,
+    conditions: CssImportConditions,
+    inheritance: Vec<CssImportConditions>,
+    issuers: Vec<String>,
+  ) -> Self {
+    let resource_identifier = create_resource_identifier(&request, &conditions, &inheritance);
     Self {
       id: DependencyId::new(),
       request,
       range,
+      conditions,
+      inheritance,
+      issuers,
+      resource_identifier,
     }
   }
+
+  pub fn conditions(&self) -> &CssImportConditions {
+    &self.conditions
+  }
+
+  /// The whole `@import` chain the imported module is reached through, from the innermost
+  /// to the outermost `@import`, without the ones that have no conditions.
+  pub fn import_conditions(&self) -> Vec<CssImportConditions> {
+    std::iter::once(&self.conditions)
+      .filter(|conditions| !conditions.is_empty())
+      .chain(&self.inheritance)
+      .cloned()
+      .collect()
+  }
+
+  pub fn issuers(&self) -> &[String] {
+    &self.issuers
+  }
+
+  /// Whether the imported resource is already in the `@import` chain, browsers ignore such
+  /// a cyclic `@import`.
+  pub fn is_cyclic(&self, resource: &str) -> bool {
+    self.issuers.iter().any(|issuer| issuer == resource)
+  }
+}
+
+fn create_resource_identifier(
+  request: &str,
+  conditions: &CssImportConditions,
+  inheritance: &[CssImportConditions],
+) -> String {
+  // imports with different conditions must not share a factorize result
+  let mut resource_identifier = create_resource_identifier_for_module_dependency(
+    &DependencyCategory::CssImport,
+    &DependencyType::CssImport,
+    request,
+  );
+  for conditions in std::iter::once(conditions).chain(inheritance) {
+    resource_identifier.push('|');
+    resource_identifier.push_str(&conditions.to_import_qualifiers());
+  }
+  resource_identifier
 }
 
 impl Dependency for CssImportDependency {
@@ -26,6 +153,10 @@ impl Dependency for CssImportDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn category(&self) -> &DependencyCategory {
     &DependencyCategory::CssImport
   }
@@ -53,6 +184,8 @@ impl ModuleDependency for CssImportDependency {
   }
 
   fn set_request(&mut self, request: String) {
+    self.resource_identifier =
+      create_resource_identifier(&request, &self.conditions, &self.inheritance);
     self.request = request;
   }
 }
@@ -10,6 +10,7 @@ use crate::utils::escape_css;
 #[derive(Debug, Clone)]
 pub struct CssLocalIdentDependency {
   id: DependencyId,
+  name: String,
   local_ident: String,
   convention_names: Vec<String>,
   start: u32,
@@ -17,9 +18,16 @@ pub struct CssLocalIdentDependency {
 }
 
 impl CssLocalIdentDependency {
-  pub fn new(local_ident: String, convention_names: Vec<String>, start: u32, end: u32) -> Self {
+  pub fn new(
+    name: String,
+    local_ident: String,
+    convention_names: Vec<String>,
+    start: u32,
+    end: u32,
+  ) -> Self {
     Self {
       id: DependencyId::new(),
+      name,
       local_ident,
       convention_names,
       start,
@@ -75,7 +83,8 @@ impl DependencyTemplate for CssLocalIdentDependency {
       self.start,
       self.end,
       &escape_css(&self.local_ident, false),
-      None,
+      // keep the original name in the source map
+      Some(&self.name),
     );
   }
 
@@ -1,8 +1,8 @@
 use rspack_core::{
-  AsContextDependency, CodeGenerationDataFilename, CodeGenerationDataUrl, Compilation, Dependency,
-  DependencyCategory, DependencyId, DependencyRange, DependencyTemplate, DependencyType,
-  ModuleDependency, ModuleIdentifier, PublicPath, RuntimeSpec, TemplateContext,
-  TemplateReplaceSource,
+  create_resource_identifier_for_module_dependency, AsContextDependency,
+  CodeGenerationDataFilename, CodeGenerationDataUrl, Compilation, Dependency, DependencyCategory,
+  DependencyId, DependencyRange, DependencyTemplate, DependencyType, ModuleDependency,
+  ModuleIdentifier, PublicPath, RuntimeSpec, TemplateContext, TemplateReplaceSource,
 };
 
 use crate::utils::{css_escape_string, AUTO_PUBLIC_PATH_PLACEHOLDER};
@@ -13,15 +13,22 @@ pub struct CssUrlDependency {
   request: String,
   range: DependencyRange,
   replace_function: bool,
+  resource_identifier: String,
 }
 
 impl CssUrlDependency {
   pub fn new(request: String, range: DependencyRange, replace_function: bool) -> Self {
+    let resource_identifier = create_resource_identifier_for_module_dependency(
+      &DependencyCategory::Url,
+      &DependencyType::CssUrl,
+      &request,
+    );
     Self {
       request,
       range,
       id: DependencyId::new(),
       replace_function,
+      resource_identifier,
     }
   }
 
@@ -56,6 +63,10 @@ impl Dependency for CssUrlDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn category(&self) -> &DependencyCategory {
     &DependencyCategory::Url
   }
@@ -26,8 +26,8 @@ use crate::utils::{css_modules_exports_to_string, escape_css, LocalIdentOptions}
 use crate::utils::{export_locals_convention, unescape};
 use crate::{
   dependency::{
-    CssComposeDependency, CssExportDependency, CssImportDependency, CssLocalIdentDependency,
-    CssUrlDependency,
+    CssComposeDependency, CssExportDependency, CssImportConditions, CssImportDependency,
+    CssLocalIdentDependency, CssUrlDependency,
   },
   utils::{
     css_modules_exports_to_concatenate_module_string, css_parsing_traceable_error, normalize_url,
@@ -73,6 +73,12 @@ pub struct CssParserAndGenerator {
   pub named_exports: bool,
   pub es_module: bool,
   pub exports: Option<CssExports>,
+  /// The conditions of the `@import` chain this module is created for, from the innermost
+  /// to the outermost `@import`
+  pub import_conditions: Vec<CssImportConditions>,
+  /// The resources of the modules in the `@import` chain this module is created for, from the
+  /// innermost to the outermost `@import`
+  pub import_issuers: Vec<String>,
 }
 
 impl ParserAndGenerator for CssParserAndGenerator {
@@ -167,7 +173,13 @@ impl ParserAndGenerator for CssParserAndGenerator {
           dependencies.push(dep.clone());
           code_generation_dependencies.push(dep);
         }
-        css_module_lexer::Dependency::Import { request, range, .. } => {
+        css_module_lexer::Dependency::Import {
+          request,
+          range,
+          layer,
+          supports,
+          media,
+        } => {
           if request.is_empty() {
             presentational_dependencies.push(Box::new(ConstDependency::new(
               range.start,
@@ -184,9 +196,23 @@ impl ParserAndGenerator for CssParserAndGenerator {
             range.start,
             range.end,
           );
+          let conditions = CssImportConditions {
+            layer: layer.map(|layer| layer.trim().to_string()),
+            supports: supports
+              .map(|supports| supports.trim().to_string())
+              .filter(|supports| !supports.is_empty()),
+            media: media
+              .map(|media| media.trim().to_string())
+              .filter(|media| !media.is_empty()),
+          };
           dependencies.push(Box::new(CssImportDependency::new(
             request.to_string(),
             DependencyRange::new(range.start, range.end),
+            conditions,
+            self.import_conditions.clone(),
+            std::iter::once(resource_data.resource.clone())
+              .chain(self.import_issuers.iter().cloned())
+              .collect(),
           )));
         }
         css_module_lexer::Dependency::Replace { content, range } => presentational_dependencies
@@ -226,6 +252,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
             );
           }
           dependencies.push(Box::new(CssLocalIdentDependency::new(
+            name.to_string(),
             local_ident,
             convention_names,
             range.start + 1,
@@ -261,6 +288,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
             );
           }
           dependencies.push(Box::new(CssLocalIdentDependency::new(
+            name.to_string(),
             local_ident.clone(),
             convention_names,
             range.start,
@@ -565,6 +593,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
     _runtime: Option<&RuntimeSpec>,
   ) -> Result<()> {
     self.es_module.dyn_hash(hasher);
+    self.import_conditions.dyn_hash(hasher);
     Ok(())
   }
 }
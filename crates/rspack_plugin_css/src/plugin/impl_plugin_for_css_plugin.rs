@@ -7,9 +7,10 @@ use rayon::prelude::*;
 use rspack_core::rspack_sources::ReplaceSource;
 use rspack_core::{
   get_css_chunk_filename_template,
-  rspack_sources::{ConcatSource, RawSource, Source, SourceExt},
-  Chunk, ChunkKind, Module, ModuleType, ParserAndGenerator, PathData, Plugin, RenderManifestEntry,
-  SourceType,
+  rspack_sources::{BoxSource, ConcatSource, RawSource, Source, SourceExt},
+  BoxModule, Chunk, ChunkKind, Module, ModuleFactoryCreateData, ModuleGraph, ModuleIdentifier,
+  ModuleType, NormalModule, NormalModuleCreateData, NormalModuleFactoryAfterResolve,
+  NormalModuleFactoryModule, ParserAndGenerator, PathData, Plugin, RenderManifestEntry, SourceType,
 };
 use rspack_core::{
   ChunkLoading, ChunkLoadingType, ChunkUkey, Compilation, CompilationContentHash,
@@ -23,6 +24,7 @@ use rspack_hook::plugin_hook;
 use rspack_plugin_runtime::is_enabled_for_chunk;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
+use crate::dependency::{CssImportConditions, CssImportDependency};
 use crate::parser_and_generator::{
   CodeGenerationDataUnusedLocalIdent, CssParserAndGenerator, CssUsedExports,
 };
@@ -63,6 +65,7 @@ impl CssPlugin {
   ) -> rspack_error::Result<ConcatSource> {
     let mut meta_data = vec![];
     let with_compression = compilation.options.output.css_head_data_compression;
+    let module_graph = compilation.get_module_graph();
     let module_sources = ordered_css_modules
       .iter()
       .map(|module| {
@@ -74,11 +77,12 @@ impl CssPlugin {
           meta_data.push(meta_data_str.0.as_str());
         }
 
-        Ok(
-          code_gen_result
-            .get(&SourceType::Css)
-            .map(|source| (CssModuleDebugInfo { module: *module }, source)),
-        )
+        Ok(code_gen_result.get(&SourceType::Css).map(|source| {
+          (
+            CssModuleDebugInfo { module: *module },
+            Self::render_module_with_import_conditions(&module_graph, *module, source),
+          )
+        }))
       })
       .collect::<Result<Vec<_>>>()?;
 
@@ -93,7 +97,7 @@ impl CssPlugin {
         |mut acc, (debug_info, cur_source)| {
           let (start, end) = Self::render_module_debug_info(compilation, &debug_info);
           acc.add(start);
-          acc.add(cur_source.clone());
+          acc.add(cur_source);
           acc.add(RawSource::from("\n"));
           acc.add(end);
           acc
@@ -124,6 +128,75 @@ impl CssPlugin {
     Ok(source)
   }
 
+  /// Collects the distinct conditions of the `@import` rules an external css module is
+  /// imported with, `None` means it's also imported without conditions.
+  fn get_external_import_conditions(
+    module_graph: &ModuleGraph,
+    module_identifier: &ModuleIdentifier,
+  ) -> Vec<Option<CssImportConditions>> {
+    let mut conditions = module_graph
+      .get_incoming_connections(module_identifier)
+      .into_iter()
+      .map(|connection| {
+        module_graph
+          .dependency_by_id(&connection.dependency_id)
+          .and_then(|dependency| dependency.downcast_ref::<CssImportDependency>())
+          .map(|dependency| dependency.conditions())
+          .filter(|conditions| !conditions.is_empty())
+          .cloned()
+      })
+      .collect::<Vec<_>>();
+    conditions.sort_unstable();
+    conditions.dedup();
+    conditions
+  }
+
+  /// Wraps the module in the conditions of the `@import` chain it's created for, the same
+  /// css file imported with other conditions is a different module. External `@import`
+  /// rules can't be nested in blocks, so they are repeated with the conditions of every
+  /// direct `@import` instead.
+  fn render_module_with_import_conditions(
+    module_graph: &ModuleGraph,
+    module: &dyn Module,
+    source: &BoxSource,
+  ) -> BoxSource {
+    if module.source_types().contains(&SourceType::CssImport) {
+      let import_conditions =
+        Self::get_external_import_conditions(module_graph, &module.identifier());
+      if let [None] = import_conditions.as_slice() {
+        return source.clone();
+      }
+      let rule = source.source();
+      let rule = rule.trim_end().trim_end_matches(';');
+      let mut rendered = ConcatSource::default();
+      for conditions in import_conditions {
+        let qualifiers = conditions
+          .map(|conditions| conditions.to_import_qualifiers())
+          .unwrap_or_default();
+        rendered.add(RawSource::from(format!("{rule}{qualifiers};\n")));
+      }
+      return rendered.boxed();
+    }
+
+    let Some(import_conditions) = module
+      .downcast_ref::<NormalModule>()
+      .and_then(|module| {
+        module
+          .parser_and_generator()
+          .downcast_ref::<CssParserAndGenerator>()
+      })
+      .map(|parser_and_generator| &parser_and_generator.import_conditions)
+      .filter(|import_conditions| !import_conditions.is_empty())
+    else {
+      return source.clone();
+    };
+    let mut wrapped = ConcatSource::new([source.clone(), RawSource::from("\n").boxed()]).boxed();
+    for conditions in import_conditions {
+      wrapped = conditions.wrap(wrapped);
+    }
+    wrapped
+  }
+
   fn render_module_debug_info(
     compilation: &Compilation,
     debug_info: &CssModuleDebugInfo,
@@ -178,6 +251,69 @@ async fn compilation(
   Ok(())
 }
 
+fn get_import_dependency(data: &ModuleFactoryCreateData) -> Option<&CssImportDependency> {
+  data
+    .dependencies
+    .first()
+    .and_then(|dependency| dependency.downcast_ref::<CssImportDependency>())
+}
+
+#[plugin_hook(NormalModuleFactoryAfterResolve for CssPlugin)]
+async fn nmf_after_resolve(
+  &self,
+  data: &mut ModuleFactoryCreateData,
+  create_data: &mut NormalModuleCreateData,
+) -> Result<Option<bool>> {
+  let Some(dependency) = get_import_dependency(data) else {
+    return Ok(None);
+  };
+  let import_conditions = dependency.import_conditions();
+  if import_conditions.is_empty() {
+    return Ok(None);
+  }
+  // every round of a cyclic `@import` would create a module with a longer condition chain
+  if dependency.is_cyclic(&create_data.resource_resolve_data.resource) {
+    return Ok(Some(false));
+  }
+  // like webpack, a css file imported with other `layer`, `supports` or `media` conditions
+  // is a different module
+  let mut suffix = String::new();
+  for conditions in &import_conditions {
+    suffix.push('|');
+    suffix.push_str(conditions.to_import_qualifiers().trim_start());
+  }
+  create_data.identifier_suffix = Some(suffix);
+  Ok(None)
+}
+
+#[plugin_hook(NormalModuleFactoryModule for CssPlugin)]
+async fn nmf_module(
+  &self,
+  data: &mut ModuleFactoryCreateData,
+  _create_data: &mut NormalModuleCreateData,
+  module: &mut BoxModule,
+) -> Result<()> {
+  let Some(dependency) = get_import_dependency(data) else {
+    return Ok(());
+  };
+  let import_conditions = dependency.import_conditions();
+  if import_conditions.is_empty() {
+    return Ok(());
+  }
+  let Some(module) = module.downcast_mut::<NormalModule>() else {
+    return Ok(());
+  };
+  let Some(parser_and_generator) = module
+    .parser_and_generator_mut()
+    .downcast_mut::<CssParserAndGenerator>()
+  else {
+    return Ok(());
+  };
+  parser_and_generator.import_conditions = import_conditions;
+  parser_and_generator.import_issuers = dependency.issuers().to_vec();
+  Ok(())
+}
+
 #[plugin_hook(CompilationRuntimeRequirementInTree for CssPlugin)]
 fn runtime_requirements_in_tree(
   &self,
@@ -423,6 +559,16 @@ impl Plugin for CssPlugin {
       .compilation_hooks
       .render_manifest
       .tap(render_manifest::new(self));
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .after_resolve
+      .tap(nmf_after_resolve::new(self));
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .module
+      .tap(nmf_module::new(self));
 
     ctx.context.register_parser_and_generator_builder(
       ModuleType::Css,
@@ -435,6 +581,8 @@ impl Plugin for CssPlugin {
           .expect("should have CssGeneratorOptions");
         Box::new(CssParserAndGenerator {
           exports: None,
+          import_conditions: vec![],
+          import_issuers: vec![],
           convention: None,
           local_ident_name: None,
           exports_only: g.exports_only.expect("should have exports_only"),
@@ -454,6 +602,8 @@ impl Plugin for CssPlugin {
           .expect("should have CssModuleGeneratorOptions");
         Box::new(CssParserAndGenerator {
           exports: None,
+          import_conditions: vec![],
+          import_issuers: vec![],
           convention: Some(
             g.exports_convention
               .expect("should have exports_convention"),
@@ -480,6 +630,8 @@ impl Plugin for CssPlugin {
           .expect("should have CssAutoGeneratorOptions");
         Box::new(CssParserAndGenerator {
           exports: None,
+          import_conditions: vec![],
+          import_issuers: vec![],
           convention: Some(
             g.exports_convention
               .expect("should have exports_convention"),
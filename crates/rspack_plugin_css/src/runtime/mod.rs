@@ -8,7 +8,9 @@ use rspack_core::{
   BooleanMatcher, ChunkUkey, Compilation, CrossOriginLoading, RuntimeGlobals, RuntimeModule,
   RuntimeModuleStage,
 };
-use rspack_plugin_runtime::{chunk_has_css, get_chunk_runtime_requirements, stringify_chunks};
+use rspack_plugin_runtime::{
+  chunk_has_css, get_chunk_runtime_requirements, get_sri_integrity_code, stringify_chunks,
+};
 use rustc_hash::FxHashSet as HashSet;
 
 #[impl_runtime_module]
@@ -79,7 +81,7 @@ impl RuntimeModule for CssLoadingRuntimeModule {
         &stringify_chunks(&initial_chunk_ids_without_css, 0)
       )));
 
-      let cross_origin_content = if let CrossOriginLoading::Enable(cross_origin) =
+      let mut cross_origin_content = if let CrossOriginLoading::Enable(cross_origin) =
         &compilation.options.output.cross_origin_loading
       {
         if cross_origin == "use-credentials" {
@@ -96,6 +98,12 @@ impl RuntimeModule for CssLoadingRuntimeModule {
       } else {
         "".to_string()
       };
+      cross_origin_content.push_str(&get_sri_integrity_code(
+        compilation,
+        runtime_requirements,
+        "link",
+        "css",
+      ));
 
       let chunk_load_timeout = compilation.options.output.chunk_load_timeout.to_string();
       let environment = &compilation.options.output.environment;
@@ -373,6 +373,7 @@ pub fn css_parsing_traceable_error(
     match severity {
       RspackSeverity::Error => "CSS parsing error".to_string(),
       RspackSeverity::Warn => "CSS parsing warning".to_string(),
+      RspackSeverity::Advice => "CSS parsing advice".to_string(),
     },
     message.into(),
   )
@@ -1,3 +1,4 @@
+use std::hash::Hasher;
 use std::path::{Component, PathBuf};
 use std::sync::LazyLock;
 use std::{borrow::Cow, path::Path};
@@ -9,11 +10,12 @@ use itertools::Itertools;
 use rayon::prelude::*;
 use regex::Regex;
 use rspack_core::{
-  rspack_sources::{ConcatSource, MapOptions, RawSource, Source, SourceExt},
+  rspack_sources::{ConcatSource, MapOptions, RawSource, Source, SourceExt, SourceMap},
   AssetInfo, Chunk, ChunkUkey, Compilation, CompilationAsset, CompilationProcessAssets,
   FilenameTemplate, Logger, ModuleIdentifier, PathData, Plugin, PluginContext, SourceType,
 };
 use rspack_error::{error, miette::IntoDiagnostic, Result};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::{
   identifier::make_paths_absolute, infallible::ResultInfallibleExt, path::relative,
@@ -56,6 +58,8 @@ pub struct SourceMapDevToolPluginOptions {
   pub append: Option<Append>,
   // Indicates whether column mappings should be used (defaults to true).
   pub columns: bool,
+  // Injects a debug id derived from the content of the asset into the asset and its SourceMap.
+  pub debug_ids: bool,
   // Generator string or function to create identifiers of modules for the 'sources' array in the SourceMap used only if 'moduleFilenameTemplate' would result in a conflict.
   #[derivative(Debug = "ignore")]
   pub fallback_module_filename_template: Option<ModuleFilenameTemplate>,
@@ -79,6 +83,9 @@ pub struct SourceMapDevToolPluginOptions {
   // Include or exclude source maps for modules based on their extension (defaults to .js and .css).
   #[derivative(Debug = "ignore")]
   pub test: Option<TestFn>,
+  // Marks the sources matching this filter in the 'ignoreList' of the SourceMap, e.g. third-party sources.
+  #[derivative(Debug = "ignore")]
+  pub ignore_list: Option<TestFn>,
 }
 
 enum SourceMappingUrlComment {
@@ -117,6 +124,9 @@ pub struct SourceMapDevToolPlugin {
   source_root: Option<String>,
   #[derivative(Debug = "ignore")]
   test: Option<TestFn>,
+  debug_ids: bool,
+  #[derivative(Debug = "ignore")]
+  ignore_list: Option<TestFn>,
   mapped_assets_cache: MappedAssetsCache,
 }
 
@@ -160,6 +170,8 @@ impl SourceMapDevToolPlugin {
       options.module,
       options.source_root,
       options.test,
+      options.debug_ids,
+      options.ignore_list,
       MappedAssetsCache::new(),
     )
   }
@@ -324,6 +336,29 @@ impl SourceMapDevToolPlugin {
       used_names_set.insert(source_name);
     }
 
+    let ignored_source_names = match &self.ignore_list {
+      Some(ignore_list) => {
+        let source_names = module_to_source_name
+          .values()
+          .unique()
+          .cloned()
+          .collect::<Vec<_>>();
+        let features = source_names
+          .iter()
+          .map(|source_name| ignore_list(source_name.clone()));
+        let matches = join_all(features)
+          .await
+          .into_iter()
+          .collect::<Result<Vec<_>>>()?;
+        source_names
+          .into_iter()
+          .zip(matches)
+          .filter_map(|(source_name, is_match)| is_match.then_some(source_name))
+          .collect::<HashSet<_>>()
+      }
+      None => HashSet::default(),
+    };
+
     for (filename, _asset, source_map) in mapped_sources.iter_mut() {
       if let Some(source_map) = source_map {
         source_map.set_file(Some(filename.clone()));
@@ -354,9 +389,22 @@ impl SourceMapDevToolPlugin {
       .into_iter()
       .map(|(filename, source, source_map)| {
         async {
-          let source_map_json = match source_map {
-            Some(map) => Some(map.to_json().into_diagnostic()?),
-            None => None,
+          let (source_map_json, debug_id) = match source_map {
+            Some(map) => {
+              let debug_id = self
+                .debug_ids
+                .then(|| generate_debug_id(&filename, &source.buffer()));
+              let ignore_list = map
+                .sources()
+                .iter()
+                .positions(|source| ignored_source_names.contains(source.as_ref()))
+                .collect::<Vec<_>>();
+              (
+                Some(source_map_to_json(map, debug_id.as_deref(), &ignore_list)?),
+                debug_id,
+              )
+            }
+            None => (None, None),
           };
 
           let mut asset = compilation
@@ -376,6 +424,18 @@ impl SourceMapDevToolPlugin {
             });
           };
           let css_extension_detected = CSS_EXTENSION_DETECT_REGEXP.is_match(&filename);
+          // the debug id comment goes before the source mapping url comment
+          let source = match debug_id {
+            Some(debug_id) => {
+              let comment = if css_extension_detected {
+                format!("\n/*# debugId={debug_id}*/")
+              } else {
+                format!("\n//# debugId={debug_id}")
+              };
+              ConcatSource::new([source.clone(), RawSource::from(comment).boxed()]).boxed()
+            }
+            None => source.clone(),
+          };
           let current_source_mapping_url_comment = match &self.source_mapping_url_comment {
             Some(SourceMappingUrlComment::String(s)) => {
               let s = if css_extension_detected {
@@ -515,6 +575,48 @@ impl SourceMapDevToolPlugin {
   }
 }
 
+/// Generates a deterministic debug id in the format of a UUID v4 from the content and the
+/// filename of an asset, the same as webpack.
+fn generate_debug_id(filename: &str, content: &[u8]) -> String {
+  let mut hasher = RspackHash::new(&HashFunction::MD4);
+  hasher.write(content);
+  hasher.write(filename.as_bytes());
+  let hash = hasher.digest(&HashDigest::Hex);
+  let hash = hash.encoded();
+  let variant = u8::from_str_radix(&hash[15..16], 16).unwrap_or_default() & 3 | 8;
+  format!(
+    "{}-{}-4{}-{:x}{}-{}",
+    &hash[0..8],
+    &hash[8..12],
+    &hash[12..15],
+    variant,
+    &hash[17..20],
+    &hash[20..32]
+  )
+}
+
+/// Serializes the source map with the fields unknown to `rspack_sources`, which are inserted into
+/// the parsed JSON object of the map as [SourceMap] can't hold them.
+fn source_map_to_json(
+  map: SourceMap,
+  debug_id: Option<&str>,
+  ignore_list: &[usize],
+) -> Result<String> {
+  let json = map.to_json().into_diagnostic()?;
+  if debug_id.is_none() && ignore_list.is_empty() {
+    return Ok(json);
+  }
+  let mut fields: serde_json::Map<String, serde_json::Value> =
+    serde_json::from_str(&json).into_diagnostic()?;
+  if let Some(debug_id) = debug_id {
+    fields.insert("debugId".to_string(), debug_id.into());
+  }
+  if !ignore_list.is_empty() {
+    fields.insert("ignoreList".to_string(), ignore_list.into());
+  }
+  serde_json::to_string(&fields).into_diagnostic()
+}
+
 #[plugin_hook(CompilationProcessAssets for SourceMapDevToolPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_DEV_TOOLING)]
 async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
   let logger = compilation.get_logger("rspack.SourceMapDevToolPlugin");
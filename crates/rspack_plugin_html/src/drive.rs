@@ -1,4 +1,4 @@
-use rspack_hook::define_hook;
+use rspack_hook::{define_hook, Hook, HookProfiler};
 
 use crate::{
   asset::{HtmlPluginAssetTags, HtmlPluginAssets},
@@ -61,3 +61,16 @@ pub struct HtmlPluginHooks {
   pub before_emit: HtmlPluginBeforeEmitHook,
   pub after_emit: HtmlPluginAfterEmitHook,
 }
+
+impl HtmlPluginHooks {
+  pub(crate) fn set_profiler(&mut self, profiler: &HookProfiler) {
+    self
+      .before_asset_tag_generation
+      .set_profiler(profiler.clone());
+    self.alter_asset_tags.set_profiler(profiler.clone());
+    self.alter_asset_tag_groups.set_profiler(profiler.clone());
+    self.after_template_execution.set_profiler(profiler.clone());
+    self.before_emit.set_profiler(profiler.clone());
+    self.after_emit.set_profiler(profiler.clone());
+  }
+}
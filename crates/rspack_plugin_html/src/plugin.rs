@@ -48,7 +48,12 @@ impl HtmlRspackPlugin {
   pub fn get_compilation_hooks_mut(
     compilation: &Compilation,
   ) -> dashmap::mapref::one::RefMut<'_, CompilationId, Box<HtmlPluginHooks>, BuildFxHasher> {
-    COMPILATION_HOOKS_MAP.entry(compilation.id()).or_default()
+    let mut hooks = COMPILATION_HOOKS_MAP.entry(compilation.id()).or_default();
+    // the taps are only added by this, so the hooks tapped by plugins always have the profiler
+    if compilation.options.profile {
+      hooks.set_profiler(&compilation.plugin_driver.hook_profiler);
+    }
+    hooks
   }
 }
 
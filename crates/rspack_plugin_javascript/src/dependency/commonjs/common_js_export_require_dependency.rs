@@ -1,11 +1,11 @@
 use itertools::Itertools;
 use rspack_core::{
-  module_raw, process_export_info, property_access, AsContextDependency, Compilation, Dependency,
-  DependencyCategory, DependencyId, DependencyRange, DependencyTemplate, DependencyType,
-  ExportInfoProvided, ExportNameOrSpec, ExportSpec, ExportsOfExportsSpec, ExportsSpec, ExportsType,
-  ExtendedReferencedExport, ModuleDependency, ModuleGraph, ModuleIdentifier, Nullable,
-  ReferencedExport, RuntimeGlobals, RuntimeSpec, TemplateContext, TemplateReplaceSource,
-  UsageState, UsedName,
+  create_resource_identifier_for_module_dependency, module_raw, process_export_info,
+  property_access, AsContextDependency, Compilation, Dependency, DependencyCategory, DependencyId,
+  DependencyRange, DependencyTemplate, DependencyType, ExportInfoProvided, ExportNameOrSpec,
+  ExportSpec, ExportsOfExportsSpec, ExportsSpec, ExportsType, ExtendedReferencedExport,
+  ModuleDependency, ModuleGraph, ModuleIdentifier, Nullable, ReferencedExport, RuntimeGlobals,
+  RuntimeSpec, TemplateContext, TemplateReplaceSource, UsageState, UsedName,
 };
 use rustc_hash::FxHashSet;
 use swc_core::atoms::Atom;
@@ -23,6 +23,7 @@ pub struct CommonJsExportRequireDependency {
   names: Vec<Atom>,
   ids: Vec<Atom>,
   result_used: bool,
+  resource_identifier: String,
 }
 
 impl CommonJsExportRequireDependency {
@@ -34,6 +35,11 @@ impl CommonJsExportRequireDependency {
     names: Vec<Atom>,
     result_used: bool,
   ) -> Self {
+    let resource_identifier = create_resource_identifier_for_module_dependency(
+      &DependencyCategory::CommonJS,
+      &DependencyType::CjsExportRequire,
+      &request,
+    );
     Self {
       id: DependencyId::new(),
       request,
@@ -43,6 +49,7 @@ impl CommonJsExportRequireDependency {
       names,
       ids: vec![],
       result_used,
+      resource_identifier,
     }
   }
 }
@@ -164,6 +171,10 @@ impl Dependency for CommonJsExportRequireDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn category(&self) -> &DependencyCategory {
     &DependencyCategory::CommonJS
   }
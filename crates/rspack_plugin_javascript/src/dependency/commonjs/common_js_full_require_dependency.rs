@@ -1,6 +1,7 @@
 use rspack_core::{
-  module_id, property_access, to_normal_comment, Compilation, DependencyRange, ExportsType,
-  ExtendedReferencedExport, ModuleGraph, RuntimeGlobals, RuntimeSpec, UsedName,
+  create_resource_identifier_for_module_dependency, module_id, property_access, to_normal_comment,
+  Compilation, DependencyRange, ExportsType, ExtendedReferencedExport, ModuleGraph, RuntimeGlobals,
+  RuntimeSpec, UsedName,
 };
 use rspack_core::{AsContextDependency, Dependency, DependencyCategory};
 use rspack_core::{DependencyId, DependencyTemplate};
@@ -17,6 +18,7 @@ pub struct CommonJsFullRequireDependency {
   is_call: bool,
   optional: bool,
   asi_safe: bool,
+  resource_identifier: String,
 }
 
 impl CommonJsFullRequireDependency {
@@ -28,6 +30,11 @@ impl CommonJsFullRequireDependency {
     optional: bool,
     asi_safe: bool,
   ) -> Self {
+    let resource_identifier = create_resource_identifier_for_module_dependency(
+      &DependencyCategory::CommonJS,
+      &DependencyType::CjsRequire,
+      &request,
+    );
     Self {
       id: DependencyId::new(),
       request,
@@ -36,6 +43,7 @@ impl CommonJsFullRequireDependency {
       is_call,
       optional,
       asi_safe,
+      resource_identifier,
     }
   }
 }
@@ -45,6 +53,10 @@ impl Dependency for CommonJsFullRequireDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn category(&self) -> &DependencyCategory {
     &DependencyCategory::CommonJS
   }
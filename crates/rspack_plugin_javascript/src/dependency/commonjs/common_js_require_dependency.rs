@@ -1,4 +1,7 @@
-use rspack_core::{module_id, Compilation, DependencyRange, RuntimeSpec};
+use rspack_core::{
+  create_resource_identifier_for_module_dependency, module_id, Compilation, DependencyRange,
+  RuntimeSpec,
+};
 use rspack_core::{AsContextDependency, Dependency, DependencyCategory};
 use rspack_core::{DependencyId, DependencyTemplate};
 use rspack_core::{DependencyType, ModuleDependency};
@@ -11,6 +14,7 @@ pub struct CommonJsRequireDependency {
   optional: bool,
   range: DependencyRange,
   range_expr: Option<DependencyRange>,
+  resource_identifier: String,
 }
 
 impl CommonJsRequireDependency {
@@ -20,12 +24,18 @@ impl CommonJsRequireDependency {
     range_expr: Option<DependencyRange>,
     optional: bool,
   ) -> Self {
+    let resource_identifier = create_resource_identifier_for_module_dependency(
+      &DependencyCategory::CommonJS,
+      &DependencyType::CjsRequire,
+      &request,
+    );
     Self {
       id: DependencyId::new(),
       request,
       optional,
       range,
       range_expr,
+      resource_identifier,
     }
   }
 }
@@ -35,6 +45,10 @@ impl Dependency for CommonJsRequireDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn loc(&self) -> Option<String> {
     Some(self.range.to_string())
   }
@@ -1,6 +1,7 @@
 use rspack_core::{
-  AffectType, AsContextDependency, AsDependencyTemplate, Dependency, DependencyCategory,
-  DependencyId, DependencyRange, DependencyType, ModuleDependency,
+  create_resource_identifier_for_module_dependency, AffectType, AsContextDependency,
+  AsDependencyTemplate, Dependency, DependencyCategory, DependencyId, DependencyRange,
+  DependencyType, ModuleDependency,
 };
 use rspack_util::atom::Atom;
 
@@ -9,19 +10,30 @@ pub struct RequireEnsureItemDependency {
   id: DependencyId,
   request: Atom,
   range: DependencyRange,
+  resource_identifier: String,
 }
 
 impl RequireEnsureItemDependency {
   pub fn new(request: Atom, range: DependencyRange) -> Self {
+    let resource_identifier = create_resource_identifier_for_module_dependency(
+      &DependencyCategory::CommonJS,
+      &DependencyType::RequireEnsureItem,
+      &request,
+    );
     Self {
       id: DependencyId::new(),
       request,
       range,
+      resource_identifier,
     }
   }
 }
 
 impl Dependency for RequireEnsureItemDependency {
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn id(&self) -> &rspack_core::DependencyId {
     &self.id
   }
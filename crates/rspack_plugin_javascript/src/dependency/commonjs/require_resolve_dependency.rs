@@ -1,7 +1,8 @@
 use rspack_core::{
-  module_id, AsContextDependency, Compilation, Dependency, DependencyCategory, DependencyId,
-  DependencyRange, DependencyTemplate, DependencyType, ExtendedReferencedExport, ModuleDependency,
-  ModuleGraph, RuntimeSpec, TemplateContext, TemplateReplaceSource,
+  create_resource_identifier_for_module_dependency, module_id, AsContextDependency, Compilation,
+  Dependency, DependencyCategory, DependencyId, DependencyRange, DependencyTemplate,
+  DependencyType, ExtendedReferencedExport, ModuleDependency, ModuleGraph, RuntimeSpec,
+  TemplateContext, TemplateReplaceSource,
 };
 
 #[derive(Debug, Clone)]
@@ -11,16 +12,23 @@ pub struct RequireResolveDependency {
   pub weak: bool,
   range: DependencyRange,
   optional: bool,
+  resource_identifier: String,
 }
 
 impl RequireResolveDependency {
   pub fn new(request: String, range: DependencyRange, weak: bool, optional: bool) -> Self {
+    let resource_identifier = create_resource_identifier_for_module_dependency(
+      &DependencyCategory::CommonJS,
+      &DependencyType::RequireResolve,
+      &request,
+    );
     Self {
       range,
       request,
       weak,
       optional,
       id: DependencyId::new(),
+      resource_identifier,
     }
   }
 }
@@ -30,6 +38,10 @@ impl Dependency for RequireResolveDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn category(&self) -> &DependencyCategory {
     &DependencyCategory::CommonJS
   }
@@ -1,7 +1,8 @@
 use itertools::Itertools;
 use rspack_core::{
-  create_exports_object_referenced, module_raw, Compilation, DependencyRange, DependencyType,
-  ExtendedReferencedExport, ModuleGraph, NormalInitFragment, RuntimeSpec, UsedName,
+  create_exports_object_referenced, create_resource_identifier_for_module_dependency, module_raw,
+  Compilation, DependencyRange, DependencyType, ExtendedReferencedExport, ModuleGraph,
+  NormalInitFragment, RuntimeSpec, UsedName,
 };
 use rspack_core::{AsContextDependency, Dependency, InitFragmentKey, InitFragmentStage};
 use rspack_core::{DependencyCategory, DependencyId, DependencyTemplate};
@@ -16,16 +17,23 @@ pub struct ProvideDependency {
   identifier: String,
   ids: Vec<Atom>,
   range: DependencyRange,
+  resource_identifier: String,
 }
 
 impl ProvideDependency {
   pub fn new(range: DependencyRange, request: Atom, identifier: String, ids: Vec<Atom>) -> Self {
+    let resource_identifier = create_resource_identifier_for_module_dependency(
+      &DependencyCategory::Esm,
+      &DependencyType::Provided,
+      &request,
+    );
     Self {
       range,
       request,
       identifier,
       ids,
       id: DependencyId::new(),
+      resource_identifier,
     }
   }
 }
@@ -35,6 +43,10 @@ impl Dependency for ProvideDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn loc(&self) -> Option<String> {
     Some(self.range.to_string())
   }
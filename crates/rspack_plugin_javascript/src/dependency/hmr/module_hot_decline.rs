@@ -1,7 +1,7 @@
 use rspack_core::{
-  module_id, AsContextDependency, Compilation, Dependency, DependencyCategory, DependencyId,
-  DependencyRange, DependencyTemplate, DependencyType, ModuleDependency, RuntimeSpec,
-  TemplateContext, TemplateReplaceSource,
+  create_resource_identifier_for_module_dependency, module_id, AsContextDependency, Compilation,
+  Dependency, DependencyCategory, DependencyId, DependencyRange, DependencyTemplate,
+  DependencyType, ModuleDependency, RuntimeSpec, TemplateContext, TemplateReplaceSource,
 };
 use swc_core::ecma::atoms::Atom;
 
@@ -10,14 +10,21 @@ pub struct ModuleHotDeclineDependency {
   id: DependencyId,
   request: Atom,
   range: DependencyRange,
+  resource_identifier: String,
 }
 
 impl ModuleHotDeclineDependency {
   pub fn new(request: Atom, range: DependencyRange) -> Self {
+    let resource_identifier = create_resource_identifier_for_module_dependency(
+      &DependencyCategory::CommonJS,
+      &DependencyType::ModuleHotDecline,
+      &request,
+    );
     Self {
       id: DependencyId::new(),
       request,
       range,
+      resource_identifier,
     }
   }
 }
@@ -27,6 +34,10 @@ impl Dependency for ModuleHotDeclineDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn category(&self) -> &DependencyCategory {
     &DependencyCategory::CommonJS
   }
@@ -1,7 +1,7 @@
 use rspack_core::{
-  AsContextDependency, Compilation, Dependency, DependencyId, DependencyTemplate, DependencyType,
-  ExtendedReferencedExport, ModuleDependency, ModuleGraph, RuntimeSpec, TemplateContext,
-  TemplateReplaceSource,
+  create_resource_identifier_for_module_dependency, AsContextDependency, Compilation, Dependency,
+  DependencyCategory, DependencyId, DependencyTemplate, DependencyType, ExtendedReferencedExport,
+  ModuleDependency, ModuleGraph, RuntimeSpec, TemplateContext, TemplateReplaceSource,
 };
 
 #[derive(Debug, Clone)]
@@ -10,15 +10,22 @@ pub struct WebpackIsIncludedDependency {
   pub end: u32,
   pub id: DependencyId,
   pub request: String,
+  resource_identifier: String,
 }
 
 impl WebpackIsIncludedDependency {
   pub fn new(start: u32, end: u32, request: String) -> Self {
+    let resource_identifier = create_resource_identifier_for_module_dependency(
+      &DependencyCategory::Unknown,
+      &DependencyType::WebpackIsIncluded,
+      &request,
+    );
     Self {
       start,
       end,
       id: DependencyId::default(),
       request,
+      resource_identifier,
     }
   }
 }
@@ -34,6 +41,10 @@ impl Dependency for WebpackIsIncludedDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn get_referenced_exports(
     &self,
     _module_graph: &ModuleGraph,
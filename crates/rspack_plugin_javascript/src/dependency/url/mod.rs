@@ -1,8 +1,8 @@
 use rspack_core::{
-  get_dependency_used_by_exports_condition, module_id, AsContextDependency, Compilation,
-  Dependency, DependencyCategory, DependencyCondition, DependencyId, DependencyRange,
-  DependencyTemplate, DependencyType, ModuleDependency, RuntimeGlobals, RuntimeSpec,
-  TemplateContext, TemplateReplaceSource, UsedByExports,
+  create_resource_identifier_for_module_dependency, get_dependency_used_by_exports_condition,
+  module_id, AsContextDependency, Compilation, Dependency, DependencyCategory, DependencyCondition,
+  DependencyId, DependencyRange, DependencyTemplate, DependencyType, ModuleDependency,
+  RuntimeGlobals, RuntimeSpec, TemplateContext, TemplateReplaceSource, UsedByExports,
 };
 use swc_core::ecma::atoms::Atom;
 
@@ -14,6 +14,7 @@ pub struct URLDependency {
   range_url: DependencyRange,
   used_by_exports: Option<UsedByExports>,
   relative: bool,
+  resource_identifier: String,
 }
 
 impl URLDependency {
@@ -23,6 +24,11 @@ impl URLDependency {
     range_url: DependencyRange,
     relative: bool,
   ) -> Self {
+    let resource_identifier = create_resource_identifier_for_module_dependency(
+      &DependencyCategory::Url,
+      &DependencyType::NewUrl,
+      &request,
+    );
     Self {
       id: DependencyId::new(),
       request,
@@ -30,6 +36,7 @@ impl URLDependency {
       range_url,
       used_by_exports: None,
       relative,
+      resource_identifier,
     }
   }
 }
@@ -39,6 +46,10 @@ impl Dependency for URLDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn category(&self) -> &DependencyCategory {
     &DependencyCategory::Url
   }
@@ -1,10 +1,10 @@
 mod create_script_url_dependency;
 pub use create_script_url_dependency::CreateScriptUrlDependency;
 use rspack_core::{
-  get_chunk_from_ukey, AsContextDependency, Compilation, Dependency, DependencyCategory,
-  DependencyId, DependencyRange, DependencyTemplate, DependencyType, ExtendedReferencedExport,
-  ModuleDependency, ModuleGraph, RuntimeGlobals, RuntimeSpec, TemplateContext,
-  TemplateReplaceSource,
+  create_resource_identifier_for_module_dependency, get_chunk_from_ukey, AsContextDependency,
+  Compilation, Dependency, DependencyCategory, DependencyId, DependencyRange, DependencyTemplate,
+  DependencyType, ExtendedReferencedExport, ModuleDependency, ModuleGraph, RuntimeGlobals,
+  RuntimeSpec, TemplateContext, TemplateReplaceSource,
 };
 use rspack_util::ext::DynHash;
 
@@ -15,6 +15,7 @@ pub struct WorkerDependency {
   public_path: String,
   range: DependencyRange,
   range_path: DependencyRange,
+  resource_identifier: String,
 }
 
 impl WorkerDependency {
@@ -24,12 +25,18 @@ impl WorkerDependency {
     range: DependencyRange,
     range_path: DependencyRange,
   ) -> Self {
+    let resource_identifier = create_resource_identifier_for_module_dependency(
+      &DependencyCategory::Worker,
+      &DependencyType::NewWorker,
+      &request,
+    );
     Self {
       id: DependencyId::new(),
       request,
       public_path,
       range,
       range_path,
+      resource_identifier,
     }
   }
 }
@@ -39,6 +46,10 @@ impl Dependency for WorkerDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn category(&self) -> &DependencyCategory {
     &DependencyCategory::Worker
   }
@@ -112,10 +112,7 @@ impl JavascriptParserPlugin for RequireEnsureDependenciesBlockParserPlugin {
         None
       },
     ))];
-    /* TODO:
-     * 1. Webpack calls `parser.in_scope`.
-     * 2. Webpack sets `parser.state.current = depBlock`, but rspack doesn't support nested block yet.
-     */
+    // TODO: Webpack calls `parser.in_scope`.
     for item in dependencies_items.iter() {
       if let Some(item) = item.as_string() {
         deps.push(Box::new(RequireEnsureItemDependency::new(
@@ -126,6 +123,10 @@ impl JavascriptParserPlugin for RequireEnsureDependenciesBlockParserPlugin {
         return None;
       }
     }
+    // the dependencies and blocks of the success callback belong to the ensure block,
+    // like `parser.state.current = depBlock` in webpack
+    let parent_dependencies = std::mem::take(&mut parser.dependencies);
+    let parent_blocks = std::mem::take(&mut parser.blocks);
     if let Some(success_expr) = &success_expr {
       match success_expr.func {
         Either::Left(func) => {
@@ -140,6 +141,11 @@ impl JavascriptParserPlugin for RequireEnsureDependenciesBlockParserPlugin {
       }
     }
 
+    deps.extend(std::mem::replace(
+      &mut parser.dependencies,
+      parent_dependencies,
+    ));
+
     let mut block = AsyncDependenciesBlock::new(
       *parser.module_identifier,
       Some(DependencyLocation::Real(
@@ -152,6 +158,9 @@ impl JavascriptParserPlugin for RequireEnsureDependenciesBlockParserPlugin {
     block.set_group_options(GroupOptions::ChunkGroup(
       ChunkGroupOptions::default().name_optional(chunk_name),
     ));
+    for nested_block in std::mem::replace(&mut parser.blocks, parent_blocks) {
+      block.add_block(*nested_block);
+    }
     parser.blocks.push(Box::new(block));
 
     if success_expr.is_none() {
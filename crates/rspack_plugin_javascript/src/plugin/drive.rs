@@ -3,7 +3,7 @@ use rspack_core::{
   ModuleIdentifier,
 };
 use rspack_hash::RspackHash;
-use rspack_hook::define_hook;
+use rspack_hook::{define_hook, Hook, HookProfiler};
 
 define_hook!(JavascriptModulesRenderChunk: SyncSeries(compilation: &Compilation, chunk_ukey: &ChunkUkey, source: &mut RenderSource));
 define_hook!(JavascriptModulesRender: SyncSeries(compilation: &Compilation, chunk_ukey: &ChunkUkey, source: &mut RenderSource));
@@ -26,6 +26,21 @@ pub struct JavascriptModulesPluginHooks {
   pub strict_runtime_bailout: JavascriptModulesStrictRuntimeBailoutHook,
 }
 
+impl JavascriptModulesPluginHooks {
+  pub(crate) fn set_profiler(&mut self, profiler: &HookProfiler) {
+    self.render_chunk.set_profiler(profiler.clone());
+    self.render.set_profiler(profiler.clone());
+    self.render_startup.set_profiler(profiler.clone());
+    self.render_module_content.set_profiler(profiler.clone());
+    self.chunk_hash.set_profiler(profiler.clone());
+    self
+      .inline_in_runtime_bailout
+      .set_profiler(profiler.clone());
+    self.embed_in_runtime_bailout.set_profiler(profiler.clone());
+    self.strict_runtime_bailout.set_profiler(profiler.clone());
+  }
+}
+
 #[derive(Debug)]
 pub struct RenderSource {
   pub source: BoxSource,
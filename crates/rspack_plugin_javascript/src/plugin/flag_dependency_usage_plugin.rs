@@ -14,6 +14,8 @@ use rspack_hook::{plugin, plugin_hook};
 use rspack_util::{queue::Queue, swc::join_atom};
 use rustc_hash::FxHashMap as HashMap;
 
+const USED_EXPORTS_BAILOUT: &str = "Used exports bailout";
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 enum ModuleOrAsyncDependenciesBlock {
   Module(ModuleIdentifier),
@@ -24,6 +26,7 @@ pub struct FlagDependencyUsagePluginProxy<'a> {
   global: bool,
   compilation: &'a mut Compilation,
   exports_info_module_map: UkeyMap<ExportsInfo, ModuleIdentifier>,
+  optimization_bailouts: Vec<(ModuleIdentifier, String)>,
 }
 
 #[allow(unused)]
@@ -33,6 +36,7 @@ impl<'a> FlagDependencyUsagePluginProxy<'a> {
       global,
       compilation,
       exports_info_module_map: UkeyMap::default(),
+      optimization_bailouts: Vec::new(),
     }
   }
 
@@ -46,8 +50,11 @@ impl<'a> FlagDependencyUsagePluginProxy<'a> {
     let mut q = Queue::new();
     let mg = &mut module_graph;
     // debug_exports_info!(mg);
-    for exports_info in self.exports_info_module_map.keys() {
+    for (exports_info, module_id) in self.exports_info_module_map.iter() {
       exports_info.set_has_use_info(mg);
+      // the used exports bailouts are collected again below
+      mg.get_optimization_bailout_mut(module_id)
+        .retain(|bailout| !bailout.starts_with(USED_EXPORTS_BAILOUT));
     }
     // SAFETY: we can make sure that entries will not be used other place at the same time,
     // this take is aiming to avoid use self ref and mut ref at the same time;
@@ -86,6 +93,14 @@ impl<'a> FlagDependencyUsagePluginProxy<'a> {
         &mut q,
       );
     }
+
+    let mut module_graph = self.compilation.get_module_graph_mut();
+    for (module_id, bailout) in std::mem::take(&mut self.optimization_bailouts) {
+      let optimization_bailout = module_graph.get_optimization_bailout_mut(&module_id);
+      if !optimization_bailout.contains(&bailout) {
+        optimization_bailout.push(bailout);
+      }
+    }
   }
 
   fn process_module(
@@ -181,6 +196,26 @@ impl<'a> FlagDependencyUsagePluginProxy<'a> {
           continue;
         };
 
+        if is_exports_object_referenced(&referenced_exports)
+          && let Some(referenced_module) =
+            module_graph.module_by_identifier(connection.module_identifier())
+          && referenced_module
+            .build_meta()
+            .is_some_and(|meta| matches!(meta.exports_type, BuildMetaExportsType::Namespace))
+          && let Some(origin_module) = module_graph
+            .get_parent_module(&dep_id)
+            .and_then(|origin| module_graph.module_by_identifier(origin))
+        {
+          self.optimization_bailouts.push((
+            *connection.module_identifier(),
+            format!(
+              "{USED_EXPORTS_BAILOUT}: the exports object is referenced by {} ({})",
+              origin_module.readable_identifier(&self.compilation.options.context),
+              dep.dependency_type()
+            ),
+          ));
+        }
+
         if old_referenced_exports.is_none()
           || matches!(old_referenced_exports, Some(ProcessModuleReferencedExports::ExtendRef(ref v)) if is_no_exports_referenced(v))
           || is_exports_object_referenced(&referenced_exports)
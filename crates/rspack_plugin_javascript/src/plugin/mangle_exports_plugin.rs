@@ -15,6 +15,9 @@ use crate::utils::mangle_exports::{
   number_to_identifier, NUMBER_OF_IDENTIFIER_CONTINUATION_CHARS, NUMBER_OF_IDENTIFIER_START_CHARS,
 };
 
+const MANGLE_EXPORTS_BAILOUT: &str =
+  "Mangle exports bailout: exports are used in an unknown way, export names are kept";
+
 fn can_mangle(exports_info: ExportsInfo, mg: &ModuleGraph) -> bool {
   if exports_info.other_exports_info(mg).get_used(mg, None) != UsageState::Unused {
     return false;
@@ -60,6 +63,15 @@ fn optimize_code_generation(&self, compilation: &mut Compilation) -> Result<()>
       .map(|meta| matches!(meta.exports_type, BuildMetaExportsType::Namespace))
       .unwrap_or_default();
     let exports_info = mgm.exports;
+    let bailout = is_namespace
+      && exports_info.exports(&mg).next().is_some()
+      && exports_info.other_exports_info(&mg).get_used(&mg, None) != UsageState::Unused;
+    // the bailout of the previous build may be stale
+    let optimization_bailout = mg.get_optimization_bailout_mut(&identifier);
+    optimization_bailout.retain(|reason| reason != MANGLE_EXPORTS_BAILOUT);
+    if bailout {
+      optimization_bailout.push(MANGLE_EXPORTS_BAILOUT.to_string());
+    }
     mangle_exports_info(&mut mg, self.deterministic, exports_info, is_namespace);
   }
   Ok(())
@@ -139,7 +139,12 @@ impl JsPlugin {
     Box<JavascriptModulesPluginHooks>,
     BuildFxHasher,
   > {
-    COMPILATION_HOOKS_MAP.entry(compilation.id()).or_default()
+    let mut hooks = COMPILATION_HOOKS_MAP.entry(compilation.id()).or_default();
+    // the taps are only added by this, so the hooks tapped by plugins always have the profiler
+    if compilation.options.profile {
+      hooks.set_profiler(&compilation.plugin_driver.hook_profiler);
+    }
+    hooks
   }
 
   pub fn render_require(&self, chunk_ukey: &ChunkUkey, compilation: &Compilation) -> Vec<Cow<str>> {
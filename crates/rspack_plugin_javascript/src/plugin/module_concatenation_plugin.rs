@@ -11,8 +11,8 @@ use rspack_core::concatenated_module::{
 };
 use rspack_core::{
   filter_runtime, merge_runtime, ApplyContext, Compilation, CompilationOptimizeChunkModules,
-  CompilerOptions, ExportInfoProvided, ExtendedReferencedExport, LibIdentOptions, Logger, Module,
-  ModuleExt, ModuleGraph, ModuleGraphModule, ModuleIdentifier, Plugin, PluginContext,
+  CompilerOptions, Context, ExportInfoProvided, ExtendedReferencedExport, LibIdentOptions, Logger,
+  Module, ModuleExt, ModuleGraph, ModuleGraphModule, ModuleIdentifier, Plugin, PluginContext,
   ProvidedExports, RuntimeCondition, RuntimeSpec, SourceType,
 };
 use rspack_error::Result;
@@ -97,11 +97,25 @@ pub struct ModuleConcatenationPlugin {
 }
 
 impl ModuleConcatenationPlugin {
-  fn format_bailout_warning(&self, module: ModuleIdentifier, warning: &Warning) -> String {
+  fn format_bailout_warning(
+    &self,
+    module: ModuleIdentifier,
+    warning: &Warning,
+    module_graph: &ModuleGraph,
+    context: &Context,
+  ) -> String {
+    let readable_identifier = |identifier: &ModuleIdentifier| {
+      module_graph
+        .module_by_identifier(identifier)
+        .map(|module| module.readable_identifier(context).to_string())
+        .unwrap_or_else(|| identifier.to_string())
+    };
     match warning {
-      Warning::Problem(id) => {
-        format_bailout_reason(&format!("Cannot concat with {}: {}", module, id))
-      }
+      Warning::Problem(problem) => format_bailout_reason(&format!(
+        "Cannot concat with {}: {}",
+        readable_identifier(&module),
+        problem
+      )),
       Warning::Id(id) => {
         let reason = self.get_inner_bailout_reason(id);
         let reason_with_prefix = match reason {
@@ -111,12 +125,15 @@ impl ModuleConcatenationPlugin {
         if id == &module {
           format_bailout_reason(&format!(
             "Cannot concat with {}{}",
-            module, reason_with_prefix
+            readable_identifier(&module),
+            reason_with_prefix
           ))
         } else {
           format_bailout_reason(&format!(
             "Cannot concat with {} because of {}{}",
-            module, id, reason_with_prefix
+            readable_identifier(&module),
+            readable_identifier(id),
+            reason_with_prefix
           ))
         }
       }
@@ -278,24 +295,25 @@ impl ModuleConcatenationPlugin {
         .filter(|&connection| connection.is_active(&module_graph, runtime))
         .collect::<Vec<_>>();
 
-      // TODO: ADD module connection explanations
       if !active_non_modules_connections.is_empty() {
         let problem = {
-          // let importing_explanations: HashSet<_> = active_non_modules_connections
-          //   .iter()
-          //   .flat_map(|&c| c.explanation.as_ref())
-          //   .cloned()
-          //   .collect();
-          // let mut explanations: Vec<_> = importing_explanations.into_iter().collect();
-          // explanations.sort();
+          // explain the connections by the types of their dependencies, e.g. entry
+          let mut explanations: Vec<_> = active_non_modules_connections
+            .iter()
+            .filter_map(|connection| module_graph.dependency_by_id(&connection.dependency_id))
+            .map(|dependency| dependency.dependency_type().to_string())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+          explanations.sort();
           format!(
-            "Module {} is referenced",
+            "Module {} is referenced {}",
             module_readable_identifier,
-            // if !explanations.is_empty() {
-            //   format!("by: {}", explanations.join(", "))
-            // } else {
-            //   "in an unsupported way".to_string()
-            // }
+            if !explanations.is_empty() {
+              format!("by: {}", explanations.join(", "))
+            } else {
+              "in an unsupported way".to_string()
+            }
           )
         };
         let problem = Warning::Problem(problem);
@@ -397,13 +415,13 @@ impl ModuleConcatenationPlugin {
         .collect();
 
       if !selected.is_empty() {
-        non_esm_connections.insert(origin_module, connections);
+        non_esm_connections.insert(origin_module, selected);
       }
     }
 
     if !non_esm_connections.is_empty() {
       let problem = {
-        let names: Vec<_> = non_esm_connections
+        let mut names: Vec<_> = non_esm_connections
           .iter()
           .map(|(origin_module, connections)| {
             let module = module_graph
@@ -414,17 +432,22 @@ impl ModuleConcatenationPlugin {
               .iter()
               .filter_map(|item| {
                 let dep = module_graph.dependency_by_id(&item.dependency_id)?;
-                Some(dep.dependency_type().to_string())
+                Some(match dep.loc() {
+                  Some(loc) => format!("{} at {loc}", dep.dependency_type()),
+                  None => dep.dependency_type().to_string(),
+                })
               })
               .collect::<Vec<_>>();
             names.sort();
+            names.dedup();
             format!(
               "{} (referenced with {})",
               readable_identifier,
-              names.join(",")
+              names.join(", ")
             )
           })
           .collect();
+        names.sort();
 
         format!(
           "Module {} is referenced from these modules with unsupported syntax: {}",
@@ -636,6 +659,7 @@ impl ModuleConcatenationPlugin {
           plugin_driver: compilation.plugin_driver.clone(),
           compiler_options: compilation.options.clone(),
           fs: compilation.input_filesystem.clone(),
+          old_cache: compilation.old_cache.clone(),
         },
         Some(compilation),
       )
@@ -972,11 +996,23 @@ impl ModuleConcatenationPlugin {
         concat_configurations.push(current_configuration);
       } else {
         stats_empty_configurations += 1;
+        let module_graph = compilation.get_module_graph();
+        let bailouts = current_configuration
+          .get_warnings_sorted()
+          .into_iter()
+          .map(|(module, warning)| {
+            self.format_bailout_warning(
+              module,
+              &warning,
+              &module_graph,
+              &compilation.options.context,
+            )
+          })
+          .collect::<Vec<_>>();
         let mut module_graph = compilation.get_module_graph_mut();
-        let optimization_bailouts = module_graph.get_optimization_bailout_mut(current_root);
-        for warning in current_configuration.get_warnings_sorted() {
-          optimization_bailouts.push(self.format_bailout_warning(warning.0, &warning.1));
-        }
+        module_graph
+          .get_optimization_bailout_mut(current_root)
+          .extend(bailouts);
       }
     }
 
@@ -8,6 +8,7 @@ pub(crate) struct LazyCompilationDependency {
   id: DependencyId,
   pub original_module_create_data: ModuleFactoryCreateData,
   request: String,
+  resource_identifier: String,
 }
 
 impl LazyCompilationDependency {
@@ -16,11 +17,13 @@ impl LazyCompilationDependency {
       .as_module_dependency()
       .expect("LazyCompilation: should convert to module dependency");
     let request = dep.request().to_string();
+    let resource_identifier = format!("{}|{}", DependencyType::LazyImport, &request);
 
     Self {
       id: DependencyId::new(),
       original_module_create_data,
       request,
+      resource_identifier,
     }
   }
 }
@@ -39,6 +42,10 @@ impl Dependency for LazyCompilationDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn category(&self) -> &DependencyCategory {
     &DependencyCategory::Esm
   }
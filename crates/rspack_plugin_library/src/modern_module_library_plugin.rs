@@ -220,12 +220,10 @@ async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
   // Remove `import()` runtime.
   for module_id in &module_ids {
     let mut deps_to_replace = Vec::new();
-    let module = mg.module_by_identifier(module_id).expect("should have mgm");
-
     let connections = mg.get_outgoing_connections(module_id);
-    let block_ids = module.get_blocks();
+    let block_ids = mg.get_all_blocks(module_id);
 
-    for block_id in block_ids {
+    for block_id in &block_ids {
       let block = mg.block_by_id(block_id).expect("should have block");
       for block_dep_id in block.get_dependencies() {
         let block_dep = mg.dependency_by_id(block_dep_id);
@@ -7,13 +7,16 @@ use rspack_core::{
 pub struct FallbackItemDependency {
   id: DependencyId,
   request: String,
+  resource_identifier: String,
 }
 
 impl FallbackItemDependency {
   pub fn new(request: String) -> Self {
+    let resource_identifier = format!("{}|{}", DependencyType::RemoteToFallbackItem, &request);
     Self {
       id: DependencyId::new(),
       request,
+      resource_identifier,
     }
   }
 }
@@ -23,6 +26,10 @@ impl Dependency for FallbackItemDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn category(&self) -> &DependencyCategory {
     &DependencyCategory::Esm
   }
@@ -70,6 +70,10 @@ impl RemoteModule {
       source_map_kind: SourceMapKind::empty(),
     }
   }
+
+  pub fn external_requests(&self) -> &[String] {
+    &self.external_requests
+  }
 }
 
 impl Identifiable for RemoteModule {
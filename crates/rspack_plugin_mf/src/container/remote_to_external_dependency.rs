@@ -7,13 +7,16 @@ use rspack_core::{
 pub struct RemoteToExternalDependency {
   id: DependencyId,
   request: String,
+  resource_identifier: String,
 }
 
 impl RemoteToExternalDependency {
   pub fn new(request: String) -> Self {
+    let resource_identifier = format!("{}|{}", DependencyType::RemoteToExternal, &request);
     Self {
       id: DependencyId::new(),
       request,
+      resource_identifier,
     }
   }
 }
@@ -23,6 +26,10 @@ impl Dependency for RemoteToExternalDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn dependency_type(&self) -> &DependencyType {
     &DependencyType::RemoteToExternal
   }
@@ -2,6 +2,7 @@
 #![feature(hash_raw_entry)]
 
 mod container;
+mod manifest;
 mod sharing;
 
 pub use container::container_plugin::{ContainerPlugin, ContainerPluginOptions, ExposeOptions};
@@ -9,6 +10,11 @@ pub use container::container_reference_plugin::{
   ContainerReferencePlugin, ContainerReferencePluginOptions, RemoteOptions,
 };
 pub use container::module_federation_runtime_plugin::ModuleFederationRuntimePlugin;
+pub use manifest::{
+  ManifestAssetFiles, ManifestAssets, ManifestExpose, ManifestMetaData, ManifestRemote,
+  ManifestRemoteEntry, ManifestShared, ManifestStats, ModuleFederationManifestPlugin,
+  ModuleFederationManifestPluginOptions,
+};
 pub use sharing::consume_shared_plugin::{
   ConsumeOptions, ConsumeSharedPlugin, ConsumeSharedPluginOptions, ConsumeVersion,
 };
@@ -0,0 +1,108 @@
+use serde::Serialize;
+
+/// The content of `mf-stats.json`, `mf-manifest.json` is the same without the fields
+/// only used for analysis.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestStats {
+  pub id: String,
+  pub name: String,
+  pub meta_data: ManifestMetaData,
+  pub shared: Vec<ManifestShared>,
+  pub remotes: Vec<ManifestRemote>,
+  pub exposes: Vec<ManifestExpose>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestMetaData {
+  pub name: String,
+  pub global_name: String,
+  pub public_path: String,
+  #[serde(rename = "type")]
+  pub ty: String,
+  pub remote_entry: ManifestRemoteEntry,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestRemoteEntry {
+  pub name: String,
+  pub path: String,
+  #[serde(rename = "type")]
+  pub ty: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ManifestAssets {
+  pub js: ManifestAssetFiles,
+  pub css: ManifestAssetFiles,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ManifestAssetFiles {
+  pub sync: Vec<String>,
+  #[serde(rename = "async")]
+  pub r#async: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestExpose {
+  pub id: String,
+  pub name: String,
+  pub path: String,
+  /// The requests of the exposed modules.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub file: Option<Vec<String>>,
+  pub assets: ManifestAssets,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestShared {
+  pub id: String,
+  pub name: String,
+  pub share_scope: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub version: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub required_version: Option<String>,
+  pub singleton: bool,
+  pub eager: bool,
+  pub assets: ManifestAssets,
+  /// The modules consuming the shared module.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub used_in: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestRemote {
+  pub alias: String,
+  pub consuming_federation_container_name: String,
+  pub federation_container_name: String,
+  pub module_name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub entry: Option<String>,
+  /// The modules importing the remote module.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub used_in: Option<Vec<String>>,
+}
+
+impl ManifestStats {
+  /// The manifest for the runtime, without the fields only used for analysis.
+  pub fn to_manifest(&self) -> Self {
+    let mut manifest = self.clone();
+    for expose in &mut manifest.exposes {
+      expose.file = None;
+    }
+    for shared in &mut manifest.shared {
+      shared.used_in = None;
+    }
+    for remote in &mut manifest.remotes {
+      remote.used_in = None;
+    }
+    manifest
+  }
+}
@@ -0,0 +1,423 @@
+mod data;
+
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use itertools::Itertools;
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  ApplyContext, AssetInfo, ChunkUkey, Compilation, CompilationAsset, CompilationProcessAssets,
+  CompilerOptions, DependenciesBlock, DependencyId, ExternalModule, ExternalRequest, LibraryName,
+  LibraryNonUmdObject, ModuleDependency, ModuleIdentifier, Plugin, PluginContext, PublicPath,
+};
+use rspack_error::{error, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rustc_hash::FxHashSet as HashSet;
+
+pub use self::data::*;
+use crate::{
+  container::{
+    container_entry_module::ContainerEntryModule,
+    container_exposed_dependency::ContainerExposedDependency, remote_module::RemoteModule,
+  },
+  sharing::{
+    consume_shared_module::ConsumeSharedModule, provide_shared_module::ProvideSharedModule,
+  },
+  ConsumeVersion, ProvideVersion,
+};
+
+#[derive(Debug)]
+pub struct ModuleFederationManifestPluginOptions {
+  /// The name of the container.
+  pub name: String,
+  /// The directory of the manifest files relative to the output path.
+  pub file_path: Option<String>,
+  /// The filename of the manifest, defaults to `mf-manifest.json`, the stats are emitted
+  /// to `mf-stats.json` or `[name]-stats.json` next to it.
+  pub file_name: Option<String>,
+}
+
+/// Emits `mf-manifest.json` and `mf-stats.json` to describe the exposes, shared modules
+/// and remotes of a build, so the federated assets can be discovered without loading
+/// the remote entry.
+#[plugin]
+#[derive(Debug)]
+pub struct ModuleFederationManifestPlugin {
+  options: ModuleFederationManifestPluginOptions,
+}
+
+impl ModuleFederationManifestPlugin {
+  pub fn new(options: ModuleFederationManifestPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+
+  fn get_file_names(&self) -> (String, String) {
+    let (manifest, stats) = match &self.options.file_name {
+      Some(file_name) => {
+        let stem = file_name.strip_suffix(".json").unwrap_or(file_name);
+        (format!("{stem}.json"), format!("{stem}-stats.json"))
+      }
+      None => ("mf-manifest.json".to_string(), "mf-stats.json".to_string()),
+    };
+    match self
+      .options
+      .file_path
+      .as_deref()
+      .map(|path| path.trim_end_matches('/'))
+      .filter(|path| !path.is_empty())
+    {
+      Some(path) => (format!("{path}/{manifest}"), format!("{path}/{stats}")),
+      None => (manifest, stats),
+    }
+  }
+
+  fn create_stats(&self, compilation: &Compilation) -> ManifestStats {
+    let name = &self.options.name;
+    let module_graph = compilation.get_module_graph();
+    let entry = compilation.entries.get(name);
+
+    let library = entry.and_then(|entry| entry.options.library.as_ref());
+    let global_name = match library.and_then(|library| library.name.as_ref()) {
+      Some(LibraryName::NonUmdObject(LibraryNonUmdObject::String(name))) => name.clone(),
+      _ => name.clone(),
+    };
+    let remote_entry_file = compilation
+      .entrypoints
+      .get(name)
+      .map(|ukey| {
+        let entrypoint = compilation.chunk_group_by_ukey.expect_get(ukey);
+        let chunk = compilation
+          .chunk_by_ukey
+          .expect_get(&entrypoint.get_entry_point_chunk());
+        chunk
+          .files
+          .iter()
+          .find(|file| is_js_file(file))
+          .cloned()
+          .unwrap_or_default()
+      })
+      .unwrap_or_default();
+    let (remote_entry_path, remote_entry_name) = match remote_entry_file.rsplit_once('/') {
+      Some((path, name)) => (path.to_string(), name.to_string()),
+      None => (String::new(), remote_entry_file),
+    };
+    let public_path = match &compilation.options.output.public_path {
+      PublicPath::Auto => "auto".to_string(),
+      PublicPath::Filename(filename) => PublicPath::render_filename(compilation, filename),
+    };
+    let meta_data = ManifestMetaData {
+      name: name.clone(),
+      global_name,
+      public_path,
+      ty: "app".to_string(),
+      remote_entry: ManifestRemoteEntry {
+        name: remote_entry_name,
+        path: remote_entry_path,
+        ty: library
+          .map(|library| library.library_type.clone())
+          .unwrap_or_else(|| "global".to_string()),
+      },
+    };
+
+    let mut exposes = vec![];
+    let container_entry_module = entry
+      .and_then(|entry| entry.dependencies.first())
+      .and_then(|dependency_id| module_graph.get_module_by_dependency_id(dependency_id))
+      .and_then(|module| module.downcast_ref::<ContainerEntryModule>());
+    if let Some(container_entry_module) = container_entry_module {
+      for block_id in container_entry_module.get_blocks() {
+        let block = module_graph
+          .block_by_id(block_id)
+          .expect("should have block");
+        let requests = block
+          .get_dependencies()
+          .iter()
+          .filter_map(|dependency_id| {
+            module_graph
+              .dependency_by_id(dependency_id)
+              .and_then(|dependency| dependency.downcast_ref::<ContainerExposedDependency>())
+          })
+          .collect::<Vec<_>>();
+        let Some(path) = requests.first().map(|dep| dep.exposed_name.clone()) else {
+          continue;
+        };
+        let expose_name = path.strip_prefix("./").unwrap_or(&path).to_string();
+        let sync_chunks = compilation
+          .chunk_graph
+          .get_block_chunk_group(block_id, &compilation.chunk_group_by_ukey)
+          .map(|chunk_group| chunk_group.chunks.iter().copied().collect::<HashSet<_>>())
+          .unwrap_or_default();
+        exposes.push(ManifestExpose {
+          id: format!("{name}:{expose_name}"),
+          name: expose_name,
+          path,
+          file: Some(
+            requests
+              .iter()
+              .map(|dep| dep.user_request().to_string())
+              .collect(),
+          ),
+          assets: get_chunks_assets(compilation, sync_chunks),
+        });
+      }
+    }
+
+    let mut shared = BTreeMap::<(String, String), ManifestShared>::new();
+    // a shared module may be provided or consumed by several modules, the version, required
+    // version and assets are taken from the first of them by module identifier, as the order
+    // of the module graph is not stable
+    let mut provided = HashSet::default();
+    let mut consumed = HashSet::default();
+    let mut remotes = vec![];
+    for module in module_graph
+      .modules()
+      .values()
+      .sorted_unstable_by_key(|module| module.identifier())
+    {
+      if let Some(provide_shared_module) = module.downcast_ref::<ProvideSharedModule>() {
+        let share_scope = provide_shared_module.share_scope().to_string();
+        let share_name = provide_shared_module.name().to_string();
+        let key = (share_scope.clone(), share_name.clone());
+        let is_first_provider = provided.insert(key.clone());
+        let item = shared
+          .entry(key)
+          .or_insert_with(|| create_shared(name, share_scope, share_name));
+        if let ProvideVersion::Version(version) = provide_shared_module.version()
+          && item.version.is_none()
+        {
+          item.version = Some(version.clone());
+        }
+        if let Some(ConsumeVersion::Version(version)) = provide_shared_module.required_version()
+          && item.required_version.is_none()
+        {
+          item.required_version = Some(version.clone());
+        }
+        item.singleton |= provide_shared_module.singleton().unwrap_or_default();
+        item.eager |= provide_shared_module.eager();
+        if !is_first_provider {
+          continue;
+        }
+        // the provided module is in a lazy block unless it is eager
+        let chunks = get_dependencies_chunks(
+          compilation,
+          provide_shared_module.get_dependencies().iter().chain(
+            provide_shared_module
+              .get_blocks()
+              .iter()
+              .flat_map(|block_id| {
+                module_graph
+                  .block_by_id(block_id)
+                  .map(|block| block.get_dependencies())
+                  .unwrap_or_default()
+              }),
+          ),
+        );
+        item.assets = get_chunks_assets(compilation, chunks);
+      } else if let Some(consume_shared_module) = module.downcast_ref::<ConsumeSharedModule>() {
+        let options = consume_shared_module.options();
+        let key = (options.share_scope.clone(), options.share_key.clone());
+        // the required version of the consumers wins over the one of the providers
+        let is_first_required_version =
+          matches!(options.required_version, Some(ConsumeVersion::Version(_)))
+            && consumed.insert(key.clone());
+        let item = shared.entry(key).or_insert_with(|| {
+          create_shared(name, options.share_scope.clone(), options.share_key.clone())
+        });
+        if is_first_required_version
+          && let Some(ConsumeVersion::Version(version)) = &options.required_version
+        {
+          item.required_version = Some(version.clone());
+        }
+        item.singleton |= options.singleton;
+        item.eager |= options.eager;
+        item
+          .used_in
+          .get_or_insert_with(Default::default)
+          .extend(get_issuers(compilation, &module.identifier()));
+      } else if let Some(remote_module) = module.downcast_ref::<RemoteModule>() {
+        let external_module = remote_module
+          .get_dependencies()
+          .first()
+          .and_then(|dependency_id| module_graph.get_module_by_dependency_id(dependency_id))
+          .and_then(|module| module.downcast_ref::<ExternalModule>());
+        let external_request = external_module
+          .and_then(|module| match &module.request {
+            ExternalRequest::Single(request) => Some(request),
+            ExternalRequest::Map(map) => map.get(&module.external_type),
+          })
+          .map(|request| request.primary().to_string())
+          .or_else(|| remote_module.external_requests().first().cloned())
+          .unwrap_or_default();
+        let (federation_container_name, entry) = match external_request.split_once('@') {
+          Some((container_name, entry)) => (container_name.to_string(), Some(entry.to_string())),
+          None => (external_request, None),
+        };
+        let module_name = remote_module
+          .internal_request
+          .strip_prefix("./")
+          .unwrap_or(&remote_module.internal_request);
+        remotes.push(ManifestRemote {
+          alias: remote_module.remote_key.clone(),
+          consuming_federation_container_name: name.clone(),
+          federation_container_name,
+          module_name: if module_name.is_empty() {
+            ".".to_string()
+          } else {
+            module_name.to_string()
+          },
+          entry,
+          used_in: Some(get_issuers(compilation, &module.identifier())),
+        });
+      }
+    }
+
+    let shared = shared
+      .into_values()
+      .map(|mut item| {
+        if let Some(used_in) = &mut item.used_in {
+          used_in.sort_unstable();
+          used_in.dedup();
+        }
+        item
+      })
+      .collect();
+    remotes.sort_unstable_by(|a, b| (&a.alias, &a.module_name).cmp(&(&b.alias, &b.module_name)));
+    exposes.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+
+    ManifestStats {
+      id: name.clone(),
+      name: name.clone(),
+      meta_data,
+      shared,
+      remotes,
+      exposes,
+    }
+  }
+}
+
+fn create_shared(container_name: &str, share_scope: String, name: String) -> ManifestShared {
+  ManifestShared {
+    id: format!("{container_name}:{name}"),
+    name,
+    share_scope,
+    version: None,
+    required_version: None,
+    singleton: false,
+    eager: false,
+    assets: ManifestAssets::default(),
+    used_in: None,
+  }
+}
+
+fn is_js_file(file: &str) -> bool {
+  let file = file.split('?').next().unwrap_or(file);
+  file.ends_with(".js") || file.ends_with(".mjs") || file.ends_with(".cjs")
+}
+
+fn is_css_file(file: &str) -> bool {
+  file.split('?').next().unwrap_or(file).ends_with(".css")
+}
+
+fn get_dependencies_chunks<'a>(
+  compilation: &Compilation,
+  dependencies: impl Iterator<Item = &'a DependencyId>,
+) -> HashSet<ChunkUkey> {
+  let module_graph = compilation.get_module_graph();
+  dependencies
+    .filter_map(|dependency_id| module_graph.module_identifier_by_dependency_id(dependency_id))
+    .flat_map(|module_identifier| {
+      compilation
+        .chunk_graph
+        .get_module_chunks(*module_identifier)
+        .iter()
+        .copied()
+    })
+    .collect()
+}
+
+/// The files of the chunks are loaded synchronously, the files of the chunks they may
+/// load later are loaded asynchronously.
+fn get_chunks_assets(compilation: &Compilation, chunks: HashSet<ChunkUkey>) -> ManifestAssets {
+  let async_chunks = chunks
+    .iter()
+    .flat_map(|chunk_ukey| {
+      compilation
+        .chunk_by_ukey
+        .expect_get(chunk_ukey)
+        .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+    })
+    .filter(|chunk_ukey| !chunks.contains(chunk_ukey))
+    .collect::<HashSet<_>>();
+  let get_files = |chunks: &HashSet<ChunkUkey>, filter: fn(&str) -> bool| {
+    chunks
+      .iter()
+      .flat_map(|chunk_ukey| {
+        compilation
+          .chunk_by_ukey
+          .expect_get(chunk_ukey)
+          .files
+          .iter()
+      })
+      .filter(|file| filter(file))
+      .cloned()
+      .sorted_unstable()
+      .dedup()
+      .collect::<Vec<_>>()
+  };
+  ManifestAssets {
+    js: ManifestAssetFiles {
+      sync: get_files(&chunks, is_js_file),
+      r#async: get_files(&async_chunks, is_js_file),
+    },
+    css: ManifestAssetFiles {
+      sync: get_files(&chunks, is_css_file),
+      r#async: get_files(&async_chunks, is_css_file),
+    },
+  }
+}
+
+fn get_issuers(compilation: &Compilation, module_identifier: &ModuleIdentifier) -> Vec<String> {
+  let module_graph = compilation.get_module_graph();
+  let context = &compilation.options.context;
+  module_graph
+    .get_incoming_connections(module_identifier)
+    .iter()
+    .filter_map(|connection| connection.original_module_identifier)
+    .filter_map(|identifier| module_graph.module_by_identifier(&identifier))
+    .map(|module| module.readable_identifier(context).to_string())
+    .sorted_unstable()
+    .dedup()
+    .collect()
+}
+
+#[plugin_hook(CompilationProcessAssets for ModuleFederationManifestPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_TRANSFER)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let stats = self.create_stats(compilation);
+  let manifest = stats.to_manifest();
+  let (manifest_file_name, stats_file_name) = self.get_file_names();
+  for (file_name, content) in [(stats_file_name, stats), (manifest_file_name, manifest)] {
+    let json = serde_json::to_string_pretty(&content)
+      .map_err(|err| error!("Failed to serialize {file_name}: {err}"))?;
+    compilation.emit_asset(
+      file_name,
+      CompilationAsset::new(Some(RawSource::from(json).boxed()), AssetInfo::default()),
+    );
+  }
+  Ok(())
+}
+
+#[async_trait]
+impl Plugin for ModuleFederationManifestPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.ModuleFederationManifestPlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
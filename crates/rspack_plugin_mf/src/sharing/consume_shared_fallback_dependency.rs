@@ -7,13 +7,16 @@ use rspack_core::{
 pub struct ConsumeSharedFallbackDependency {
   id: DependencyId,
   request: String,
+  resource_identifier: String,
 }
 
 impl ConsumeSharedFallbackDependency {
   pub fn new(request: String) -> Self {
+    let resource_identifier = format!("{}|{}", DependencyType::ConsumeSharedFallback, &request);
     Self {
       id: DependencyId::new(),
       request,
+      resource_identifier,
     }
   }
 }
@@ -23,6 +26,10 @@ impl Dependency for ConsumeSharedFallbackDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn dependency_type(&self) -> &DependencyType {
     &DependencyType::ConsumeSharedFallback
   }
@@ -7,13 +7,16 @@ use rspack_core::{
 pub struct ProvideForSharedDependency {
   id: DependencyId,
   request: String,
+  resource_identifier: String,
 }
 
 impl ProvideForSharedDependency {
   pub fn new(request: String) -> Self {
+    let resource_identifier = format!("{}|{}", DependencyType::ProvideModuleForShared, &request);
     Self {
       id: DependencyId::new(),
       request,
+      resource_identifier,
     }
   }
 }
@@ -23,6 +26,10 @@ impl Dependency for ProvideForSharedDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn dependency_type(&self) -> &DependencyType {
     &DependencyType::ProvideModuleForShared
   }
@@ -79,6 +79,30 @@ impl ProvideSharedModule {
       source_map_kind: SourceMapKind::empty(),
     }
   }
+
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  pub fn share_scope(&self) -> &str {
+    &self.share_scope
+  }
+
+  pub fn version(&self) -> &ProvideVersion {
+    &self.version
+  }
+
+  pub fn eager(&self) -> bool {
+    self.eager
+  }
+
+  pub fn singleton(&self) -> Option<bool> {
+    self.singleton
+  }
+
+  pub fn required_version(&self) -> Option<&ConsumeVersion> {
+    self.required_version.as_ref()
+  }
 }
 
 impl Identifiable for ProvideSharedModule {
@@ -567,3 +567,35 @@ impl Plugin for ProgressPlugin {
     Ok(())
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use rspack_core::{RawModule, RuntimeGlobals};
+
+  use super::*;
+
+  #[tokio::test]
+  async fn count_modules_built_with_errors_as_done() -> Result<()> {
+    let plugin = ProgressPlugin::new(ProgressPluginOptions::Handler(Arc::new(|_, _, _| Ok(()))));
+    let mut module: BoxModule = Box::new(RawModule::new(
+      String::new(),
+      "./a.js".into(),
+      "./a.js".to_string(),
+      RuntimeGlobals::default(),
+    ));
+
+    build_module::new(&plugin).run(&mut module).await?;
+    assert_eq!(plugin.modules_count.load(Relaxed), 1);
+    assert_eq!(plugin.modules_done.load(Relaxed), 0);
+
+    // modules built with error diagnostics (e.g. loader errors) also call `succeed_module`
+    succeed_module::new(&plugin).run(&mut module).await?;
+    assert_eq!(plugin.modules_done.load(Relaxed), 1);
+    assert!(plugin
+      .active_modules
+      .read()
+      .expect("should read active modules")
+      .is_empty());
+    Ok(())
+  }
+}
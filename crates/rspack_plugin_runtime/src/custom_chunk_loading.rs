@@ -0,0 +1,149 @@
+use std::{fmt, sync::Arc};
+
+use rspack_core::{
+  BoxRuntimeModule, ChunkLoading, ChunkLoadingType, ChunkUkey, Compilation, CompilationParams,
+  CompilationRuntimeRequirementInTree, CompilerThisCompilation, Plugin, PluginContext,
+  RuntimeGlobals,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+
+use crate::runtime_module::is_enabled_for_chunk;
+
+/// Creates the chunk loading runtime module for a runtime chunk,
+/// called with the runtime requirements of the chunk tree.
+pub type CreateChunkLoadingRuntimeModule =
+  Arc<dyn Fn(&Compilation, &ChunkUkey, &RuntimeGlobals) -> Result<BoxRuntimeModule> + Send + Sync>;
+
+/// Wires a plugin provided chunk loading type, e.g. `output.chunkLoading: "my-host"`.
+///
+/// The runtime module is added for chunks using `ChunkLoadingType::Custom(name)` when they load chunks,
+/// just like the builtin jsonp, import-scripts and require chunk loading.
+#[plugin]
+pub struct CustomChunkLoadingPlugin {
+  chunk_loading: ChunkLoading,
+  create_runtime_module: CreateChunkLoadingRuntimeModule,
+}
+
+impl CustomChunkLoadingPlugin {
+  pub fn new(name: String, create_runtime_module: CreateChunkLoadingRuntimeModule) -> Self {
+    Self::new_inner(
+      ChunkLoading::Enable(ChunkLoadingType::Custom(name)),
+      create_runtime_module,
+    )
+  }
+}
+
+impl fmt::Debug for CustomChunkLoadingPlugin {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("CustomChunkLoadingPlugin")
+      .field("chunk_loading", &self.chunk_loading)
+      .finish()
+  }
+}
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for CustomChunkLoadingPlugin)]
+fn runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  _all_runtime_requirements: &RuntimeGlobals,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if !is_enabled_for_chunk(chunk_ukey, &self.chunk_loading, compilation) {
+    return Ok(None);
+  }
+
+  let has_chunk_loading = runtime_requirements.intersects(
+    RuntimeGlobals::ENSURE_CHUNK_HANDLERS
+      | RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS
+      | RuntimeGlobals::HMR_DOWNLOAD_MANIFEST
+      | RuntimeGlobals::BASE_URI
+      | RuntimeGlobals::ON_CHUNKS_LOADED,
+  );
+  if !has_chunk_loading {
+    return Ok(None);
+  }
+
+  if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS) {
+    runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+    runtime_requirements_mut.insert(RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME);
+  }
+  runtime_requirements_mut.insert(RuntimeGlobals::MODULE_FACTORIES_ADD_ONLY);
+  runtime_requirements_mut.insert(RuntimeGlobals::HAS_OWN_PROPERTY);
+
+  let runtime_module = (self.create_runtime_module)(compilation, chunk_ukey, runtime_requirements)?;
+  compilation.add_runtime_module(chunk_ukey, runtime_module)?;
+  Ok(None)
+}
+
+impl Plugin for CustomChunkLoadingPlugin {
+  fn name(&self) -> &'static str {
+    "CustomChunkLoadingPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(runtime_requirements_in_tree::new(self));
+    Ok(())
+  }
+}
+
+/// Reports a chunk loading type which no plugin provides, once for every compilation.
+///
+/// Plugins providing a custom chunk loading type enable it themselves, so only the
+/// unknown types of `output.chunkLoading`, `output.workerChunkLoading` and the entries get here.
+#[plugin]
+#[derive(Debug)]
+pub(crate) struct UnknownChunkLoadingPlugin {
+  name: String,
+}
+
+impl UnknownChunkLoadingPlugin {
+  pub(crate) fn new(name: String) -> Self {
+    Self::new_inner(name)
+  }
+}
+
+#[plugin_hook(CompilerThisCompilation for UnknownChunkLoadingPlugin)]
+async fn unknown_chunk_loading_plugin_this_compilation(
+  &self,
+  compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  compilation.push_diagnostic(Diagnostic::error(
+    "Unsupported chunk loading type".into(),
+    format!(
+      "Unsupported chunk loading type \"{}\".\nPlugins which provide custom chunk loading types must enable them with CustomChunkLoadingPlugin, otherwise use one of \"jsonp\", \"import-scripts\", \"require\", \"async-node\" or \"import\".",
+      self.name
+    ),
+  ));
+  Ok(())
+}
+
+impl Plugin for UnknownChunkLoadingPlugin {
+  fn name(&self) -> &'static str {
+    "UnknownChunkLoadingPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .this_compilation
+      .tap(unknown_chunk_loading_plugin_this_compilation::new(self));
+    Ok(())
+  }
+}
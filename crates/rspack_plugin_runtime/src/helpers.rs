@@ -4,8 +4,8 @@ use rspack_collections::{IdentifierLinkedMap, UkeyIndexSet};
 use rspack_core::{
   get_chunk_from_ukey, get_chunk_group_from_ukey, get_js_chunk_filename_template,
   rspack_sources::{BoxSource, RawSource, SourceExt},
-  Chunk, ChunkGroupByUkey, ChunkGroupUkey, ChunkUkey, Compilation, PathData, RuntimeGlobals,
-  SourceType,
+  Chunk, ChunkGroupByUkey, ChunkGroupUkey, ChunkUkey, Compilation, CrossOriginLoading, PathData,
+  RuntimeGlobals, SourceType,
 };
 use rspack_error::{error, Result};
 use rspack_hash::RspackHash;
@@ -271,3 +271,32 @@ pub fn get_chunk_runtime_requirements<'a>(
     .expect_chunk_graph_chunk(chunk_ukey)
     .runtime_requirements
 }
+
+/// Returns the code setting `integrity` on a dynamically created `element` from
+/// [RuntimeGlobals::SRI_HASHES], or an empty string when subresource integrity is disabled.
+///
+/// `crossOrigin` is required by browsers to check the integrity of cross-origin resources,
+/// so it falls back to `anonymous` when `output.crossOriginLoading` is disabled.
+pub fn get_sri_integrity_code(
+  compilation: &Compilation,
+  runtime_requirements: &RuntimeGlobals,
+  element: &str,
+  source_type: &str,
+) -> String {
+  if !runtime_requirements.contains(RuntimeGlobals::SRI_HASHES) {
+    return String::new();
+  }
+  let cross_origin = match &compilation.options.output.cross_origin_loading {
+    CrossOriginLoading::Enable(cross_origin) => cross_origin.as_str(),
+    CrossOriginLoading::Disable => "anonymous",
+  };
+  format!(
+    r#"
+    if ({sri_hashes}.{source_type}[chunkId]) {{
+      {element}.integrity = {sri_hashes}.{source_type}[chunkId];
+      {element}.crossOrigin = "{cross_origin}";
+    }}
+    "#,
+    sri_hashes = RuntimeGlobals::SRI_HASHES,
+  )
+}
@@ -18,10 +18,13 @@ mod module_chunk_loading;
 pub use module_chunk_loading::ModuleChunkLoadingPlugin;
 mod import_scripts_chunk_loading;
 pub use import_scripts_chunk_loading::ImportScriptsChunkLoadingPlugin;
+mod custom_chunk_loading;
+use custom_chunk_loading::UnknownChunkLoadingPlugin;
+pub use custom_chunk_loading::{CreateChunkLoadingRuntimeModule, CustomChunkLoadingPlugin};
 mod runtime_module;
 pub use runtime_module::{
   chunk_has_css, chunk_has_js, is_enabled_for_chunk, stringify_chunks,
-  GetChunkFilenameRuntimeModule,
+  CustomChunkLoadingRuntimeModule, GetChunkFilenameRuntimeModule,
 };
 mod startup_chunk_dependencies;
 pub use startup_chunk_dependencies::StartupChunkDependenciesPlugin;
@@ -65,5 +68,10 @@ pub fn enable_chunk_loading_plugin(loading_type: ChunkLoadingType, plugins: &mut
       plugins.push(ImportScriptsChunkLoadingPlugin::default().boxed());
     }
     ChunkLoadingType::Import => plugins.push(ModuleChunkLoadingPlugin::default().boxed()),
+    ChunkLoadingType::Custom(name) => {
+      // custom types are enabled by the `CustomChunkLoadingPlugin` providing them,
+      // so no plugin provides this one
+      plugins.push(UnknownChunkLoadingPlugin::new(name).boxed());
+    }
   }
 }
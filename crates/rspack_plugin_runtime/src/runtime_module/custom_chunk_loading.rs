@@ -0,0 +1,113 @@
+use cow_utils::CowUtils;
+use rspack_collections::Identifier;
+use rspack_core::{
+  compile_boolean_matcher, impl_runtime_module,
+  rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt},
+  BooleanMatcher, ChunkUkey, Compilation, RuntimeGlobals, RuntimeModule, RuntimeModuleStage,
+};
+
+use super::utils::chunk_has_js;
+use crate::{
+  get_chunk_runtime_requirements,
+  runtime_module::utils::{get_initial_chunk_ids, stringify_chunks},
+};
+
+/// Loads chunks in the `commonjs` chunk format with a function provided by the host,
+/// used by the chunk loading types provided with `CustomChunkLoadingPlugin`.
+#[impl_runtime_module]
+#[derive(Debug)]
+pub struct CustomChunkLoadingRuntimeModule {
+  id: Identifier,
+  chunk: Option<ChunkUkey>,
+  chunk_loading: String,
+  load_chunk: String,
+}
+
+impl CustomChunkLoadingRuntimeModule {
+  /// `load_chunk` is the code of a function called with the chunk id and the chunk filename,
+  /// returning the exports of the chunk or a promise of them.
+  pub fn new(chunk_loading: String, load_chunk: String) -> Self {
+    Self::with_default(
+      Identifier::from(format!("webpack/runtime/{chunk_loading}_chunk_loading")),
+      None,
+      chunk_loading,
+      load_chunk,
+    )
+  }
+}
+
+impl RuntimeModule for CustomChunkLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+    let chunk = compilation
+      .chunk_by_ukey
+      .expect_get(&self.chunk.expect("The chunk should be attached."));
+    let runtime_requirements = get_chunk_runtime_requirements(compilation, &chunk.ukey);
+
+    let with_loading = runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS);
+
+    let condition_map =
+      compilation
+        .chunk_graph
+        .get_chunk_condition_map(&chunk.ukey, compilation, chunk_has_js);
+    let has_js_matcher = compile_boolean_matcher(&condition_map);
+
+    let initial_chunks = get_initial_chunk_ids(self.chunk, compilation, chunk_has_js);
+    let mut source = ConcatSource::default();
+
+    source.add(RawSource::from(format!(
+      "var installedChunks = {};\n",
+      &stringify_chunks(&initial_chunks, 0)
+    )));
+
+    if with_loading {
+      source.add(RawSource::from(
+        include_str!("runtime/readfile_chunk_loading.js")
+          .cow_replace("$WITH_ON_CHUNK_LOADED$", "")
+          .into_owned(),
+      ));
+
+      let body = if matches!(has_js_matcher, BooleanMatcher::Condition(false)) {
+        "installedChunks[chunkId] = 0;".to_string()
+      } else {
+        include_str!("runtime/custom_chunk_loading_with_loading.js")
+          .cow_replace("$JS_MATCHER$", &has_js_matcher.render("chunkId"))
+          .cow_replace(
+            "$MATCH_FALLBACK$",
+            if matches!(has_js_matcher, BooleanMatcher::Condition(true)) {
+              ""
+            } else {
+              "else installedChunks[chunkId] = 0;\n"
+            },
+          )
+          // replaced last to keep the host code as it is
+          .cow_replace("$LOAD_CHUNK$", &self.load_chunk)
+          .into_owned()
+      };
+
+      source.add(RawSource::from(format!(
+        r#"
+        // {chunk_loading} chunk loading for javascript
+        __webpack_require__.f[{key}] = function (chunkId, promises) {{
+          {body}
+        }};
+        "#,
+        chunk_loading = self.chunk_loading,
+        key = serde_json::to_string(&self.chunk_loading).expect("should stringify the name"),
+      )));
+    }
+
+    Ok(source.boxed())
+  }
+
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk)
+  }
+
+  fn stage(&self) -> RuntimeModuleStage {
+    RuntimeModuleStage::Attach
+  }
+}
@@ -9,7 +9,7 @@ use rspack_core::{
 
 use super::generate_javascript_hmr_runtime;
 use crate::{
-  get_chunk_runtime_requirements,
+  get_chunk_runtime_requirements, get_sri_integrity_code,
   runtime_module::utils::{chunk_has_js, get_initial_chunk_ids, stringify_chunks},
 };
 
@@ -136,12 +136,18 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
     }
 
     if with_prefetch && !matches!(has_js_matcher, BooleanMatcher::Condition(false)) {
-      let cross_origin = match cross_origin_loading {
+      let mut cross_origin = match cross_origin_loading {
         CrossOriginLoading::Disable => "".to_string(),
         CrossOriginLoading::Enable(_) => {
           format!("link.crossOrigin = {}", cross_origin_loading)
         }
       };
+      cross_origin.push_str(&get_sri_integrity_code(
+        compilation,
+        runtime_requirements,
+        "link",
+        "js",
+      ));
       source.add(RawSource::from(
         include_str!("runtime/jsonp_chunk_loading_with_prefetch.js")
           .cow_replace("$JS_MATCHER$", &js_matcher)
@@ -151,7 +157,7 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
     }
 
     if with_preload && !matches!(has_js_matcher, BooleanMatcher::Condition(false)) {
-      let cross_origin = match cross_origin_loading {
+      let mut cross_origin = match cross_origin_loading {
         CrossOriginLoading::Disable => "".to_string(),
         CrossOriginLoading::Enable(cross_origin_value) => {
           if cross_origin_value.eq("use-credentials") {
@@ -168,6 +174,12 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
           }
         }
       };
+      cross_origin.push_str(&get_sri_integrity_code(
+        compilation,
+        runtime_requirements,
+        "link",
+        "js",
+      ));
       let script_type_link_pre = if script_type.eq("module") || script_type.eq("false") {
         "".to_string()
       } else {
@@ -6,7 +6,7 @@ use rspack_core::{
   ChunkUkey, Compilation, CrossOriginLoading, RuntimeGlobals, RuntimeModule,
 };
 
-use crate::get_chunk_runtime_requirements;
+use crate::{get_chunk_runtime_requirements, get_sri_integrity_code};
 
 #[impl_runtime_module]
 #[derive(Debug)]
@@ -42,7 +42,7 @@ impl RuntimeModule for LoadScriptRuntimeModule {
     } else {
       "url".to_string()
     };
-    let cross_origin_loading = match &compilation.options.output.cross_origin_loading {
+    let mut cross_origin_loading = match &compilation.options.output.cross_origin_loading {
       CrossOriginLoading::Disable => "".to_string(),
       CrossOriginLoading::Enable(cross_origin) => {
         if cross_origin == "use-credentials" {
@@ -58,6 +58,12 @@ impl RuntimeModule for LoadScriptRuntimeModule {
         }
       }
     };
+    cross_origin_loading.push_str(&get_sri_integrity_code(
+      compilation,
+      runtime_requirements,
+      "script",
+      "js",
+    ));
 
     let script_type = if compilation.options.output.script_type.eq("false") {
       String::new()
@@ -10,6 +10,7 @@ mod compat_get_default_export;
 mod create_fake_namespace_object;
 mod create_script;
 mod create_script_url;
+mod custom_chunk_loading;
 mod define_property_getters;
 mod ensure_chunk;
 mod esm_module_decorator;
@@ -52,6 +53,7 @@ pub use compat_get_default_export::CompatGetDefaultExportRuntimeModule;
 pub use create_fake_namespace_object::CreateFakeNamespaceObjectRuntimeModule;
 pub use create_script::CreateScriptRuntimeModule;
 pub use create_script_url::CreateScriptUrlRuntimeModule;
+pub use custom_chunk_loading::CustomChunkLoadingRuntimeModule;
 pub use define_property_getters::DefinePropertyGettersRuntimeModule;
 pub use ensure_chunk::EnsureChunkRuntimeModule;
 pub use esm_module_decorator::ESMModuleDecoratorRuntimeModule;
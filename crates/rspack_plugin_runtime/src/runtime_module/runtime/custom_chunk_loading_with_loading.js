@@ -0,0 +1,23 @@
+var installedChunkData = installedChunks[chunkId];
+if (installedChunkData !== 0) {  // 0 means "already installed".
+  // array of [resolve, reject, promise] means "currently loading"
+  if (installedChunkData) {
+    promises.push(installedChunkData[2]);
+  } else {
+    if ($JS_MATCHER$) {  // all chunks have JS
+      // load the chunk with the host and return promise to it
+      var promise = new Promise(function (resolve, reject) {
+        installedChunkData = installedChunks[chunkId] = [resolve, reject];
+        Promise.resolve()
+          .then(function () {
+            return ($LOAD_CHUNK$)(chunkId, __webpack_require__.u(chunkId));
+          })
+          .then(installChunk, function (err) {
+            installedChunks[chunkId] = undefined;
+            reject(err);
+          });
+      });
+      promises.push(installedChunkData[2] = promise);
+    } $MATCH_FALLBACK$
+  }
+}
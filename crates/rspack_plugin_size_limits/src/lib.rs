@@ -1,17 +1,79 @@
-use std::{collections::HashMap, fmt::Debug};
+use std::{collections::HashMap, fmt::Debug, hash::Hasher, sync::Mutex};
 
 use derivative::Derivative;
+use fast_glob::glob_match;
 use futures::future::BoxFuture;
+use rayon::prelude::*;
 use rspack_core::{
-  ApplyContext, ChunkGroup, ChunkGroupUkey, Compilation, CompilationAsset, CompilerAfterEmit,
-  CompilerOptions, Plugin, PluginContext,
+  ApplyContext, AssetInfo, ChunkGroupUkey, Compilation, CompilationAsset, CompilerAfterEmit,
+  CompilerOptions, Plugin, PluginContext, StatsDiff, StatsSizeDelta, StatsSnapshot,
 };
-use rspack_error::{Diagnostic, Result};
+use rspack_error::{error, Diagnostic, Result};
+use rspack_hash::{HashFunction, RspackHash};
 use rspack_hook::{plugin, plugin_hook};
+use rspack_plugin_compression::CompressionAlgorithm;
 use rspack_util::size::format_size;
 
 pub type AssetFilterFn = Box<dyn for<'a> Fn(&'a str) -> BoxFuture<'a, Result<bool>> + Sync + Send>;
 
+/// The compression the assets are served with, the limits are compared with the compressed
+/// sizes when it's set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizeLimitsCompression {
+  Gzip,
+  Brotli,
+}
+
+impl SizeLimitsCompression {
+  fn label(&self) -> &'static str {
+    match self {
+      Self::Gzip => "gzip",
+      Self::Brotli => "brotli",
+    }
+  }
+
+  /// Estimates the compressed size with the level the compression plugin uses, it's only used
+  /// for the assets which are not compressed by the compression plugin.
+  fn compressed_size(&self, content: &[u8]) -> std::io::Result<f64> {
+    let algorithm = match self {
+      Self::Gzip => CompressionAlgorithm::Gzip,
+      Self::Brotli => CompressionAlgorithm::Brotli,
+    };
+    Ok(algorithm.compress(content)?.len() as f64)
+  }
+
+  /// The filename of the compressed asset the compression plugin emitted for an asset.
+  fn related<'a>(&self, info: &'a AssetInfo) -> Option<&'a String> {
+    match self {
+      Self::Gzip => info.related.gzipped.as_ref(),
+      Self::Brotli => info.related.brotli_compressed.as_ref(),
+    }
+  }
+
+  fn set_compressed_size(&self, info: &mut AssetInfo, size: f64) {
+    match self {
+      Self::Gzip => info.gzip_size = Some(size),
+      Self::Brotli => info.brotli_size = Some(size),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub enum SizeLimitsBudgetTarget {
+  /// Glob of the asset filenames.
+  Asset(String),
+  /// Glob of the entrypoint names.
+  Entrypoint(String),
+}
+
+/// A limit of the assets or entrypoints matching the glob, which overrides `max_asset_size`
+/// or `max_entrypoint_size`. The first matching budget is used.
+#[derive(Debug)]
+pub struct SizeLimitsBudget {
+  pub target: SizeLimitsBudgetTarget,
+  pub max_size: f64,
+}
+
 #[derive(Derivative)]
 #[derivative(Debug)]
 pub struct SizeLimitsPluginOptions {
@@ -20,17 +82,73 @@ pub struct SizeLimitsPluginOptions {
   pub hints: Option<String>,
   pub max_asset_size: Option<f64>,
   pub max_entrypoint_size: Option<f64>,
+  /// Path of a stats snapshot of a previous build, assets and entrypoints exceeding the limit
+  /// which are new or grew compared to it are reported as size regressions.
+  pub baseline: Option<String>,
+  pub compression: Option<SizeLimitsCompression>,
+  pub budgets: Vec<SizeLimitsBudget>,
+}
+
+/// The size of a checked asset, `compressed` is only computed when `compression` is set.
+#[derive(Debug, Clone, Copy)]
+struct AssetSize {
+  raw: f64,
+  compressed: Option<f64>,
+}
+
+impl AssetSize {
+  fn size(&self) -> f64 {
+    self.compressed.unwrap_or(self.raw)
+  }
+}
+
+#[derive(Debug)]
+struct EntrypointOverSizeLimit<'a> {
+  name: &'a String,
+  size: f64,
+  limit: f64,
+  files: Vec<(String, AssetSize)>,
 }
 
 #[plugin]
 #[derive(Debug)]
 pub struct SizeLimitsPlugin {
   options: SizeLimitsPluginOptions,
+  /// Compressed sizes of the assets of the last build by the hash of their content, so that
+  /// unchanged assets are not compressed again on rebuilds.
+  compressed_sizes: Mutex<HashMap<u64, f64>>,
 }
 
 impl SizeLimitsPlugin {
   pub fn new(options: SizeLimitsPluginOptions) -> Self {
-    Self::new_inner(options)
+    Self::new_inner(options, Default::default())
+  }
+
+  /// Uses the size of the compressed asset of the compression plugin when there is one,
+  /// otherwise the cached or estimated size of the content. Returns the content hash of the
+  /// estimated sizes, to cache them for the next build.
+  fn get_compressed_size(
+    compression: SizeLimitsCompression,
+    compilation: &Compilation,
+    asset: &CompilationAsset,
+    cache: &HashMap<u64, f64>,
+  ) -> std::io::Result<(f64, Option<u64>)> {
+    if let Some(source) = compression
+      .related(&asset.info)
+      .and_then(|filename| compilation.assets().get(filename))
+      .and_then(|compressed| compressed.get_source())
+    {
+      return Ok((source.size() as f64, None));
+    }
+    let content = asset.get_source().expect("should have source").buffer();
+    let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+    hasher.write(&content);
+    let hash = hasher.finish();
+    let size = match cache.get(&hash) {
+      Some(size) => *size,
+      None => compression.compressed_size(&content)?,
+    };
+    Ok((size, Some(hash)))
   }
 
   async fn asset_filter(&self, name: &str, asset: &CompilationAsset) -> bool {
@@ -45,26 +163,83 @@ impl SizeLimitsPlugin {
     }
   }
 
-  async fn get_entrypoint_size(&self, entrypoint: &ChunkGroup, compilation: &Compilation) -> f64 {
-    let mut size = 0.0;
+  fn get_asset_limit(&self, name: &str) -> f64 {
+    self
+      .options
+      .budgets
+      .iter()
+      .find_map(|budget| match &budget.target {
+        SizeLimitsBudgetTarget::Asset(glob) if glob_match(glob, name) => Some(budget.max_size),
+        _ => None,
+      })
+      .unwrap_or_else(|| self.max_asset_size())
+  }
 
-    for filename in entrypoint.get_files(&compilation.chunk_by_ukey) {
-      let asset = compilation.assets().get(&filename);
+  fn get_entrypoint_limit(&self, name: &str) -> f64 {
+    self
+      .options
+      .budgets
+      .iter()
+      .find_map(|budget| match &budget.target {
+        SizeLimitsBudgetTarget::Entrypoint(glob) if glob_match(glob, name) => Some(budget.max_size),
+        _ => None,
+      })
+      .unwrap_or_else(|| self.max_entrypoint_size())
+  }
 
-      if let Some(asset) = asset {
-        if !self.asset_filter(&filename, asset).await {
-          continue;
-        }
+  fn max_asset_size(&self) -> f64 {
+    self.options.max_asset_size.unwrap_or(250000.0)
+  }
 
-        let source = asset.get_source();
+  fn max_entrypoint_size(&self) -> f64 {
+    self.options.max_entrypoint_size.unwrap_or(250000.0)
+  }
 
-        if let Some(source) = source {
-          size += source.size() as f64;
-        }
-      }
+  fn format_limit(&self, limit: f64) -> String {
+    match self.options.compression {
+      Some(compression) => format!("{} {}", format_size(limit), compression.label()),
+      None => format_size(limit),
     }
+  }
 
-    size
+  fn format_asset_size(&self, size: &AssetSize) -> String {
+    match (self.options.compression, size.compressed) {
+      (Some(compression), Some(compressed)) => format!(
+        "{} {}, {}",
+        format_size(compressed),
+        compression.label(),
+        format_size(size.raw)
+      ),
+      _ => format_size(size.raw),
+    }
+  }
+
+  /// Compares the build with the baseline, by the compressed sizes when `compression` is set
+  /// as the limits are, so the compressed sizes of this build have to be set on the assets.
+  /// Returns the snapshot of the build too, to find the filenames of the compared assets.
+  fn diff_baseline(
+    &self,
+    baseline: &str,
+    compilation: &Compilation,
+  ) -> Result<(StatsDiff, StatsSnapshot)> {
+    let path = compilation.options.context.as_path().join(baseline);
+    let content = compilation
+      .input_filesystem
+      .read(path.as_std_path())
+      .map_err(|e| error!("Failed to read the size limits baseline {path}: {e}"))?;
+    let baseline = StatsSnapshot::from_json(&String::from_utf8_lossy(&content))?;
+    let current = StatsSnapshot::new(compilation);
+    match self.options.compression {
+      Some(compression) => {
+        let label = compression.label();
+        let baseline = baseline.get_compressed(label).ok_or_else(|| {
+          error!("The size limits baseline {path} has no {label} sizes, generate it from a build with the same compression")
+        })?;
+        let current = current.get_compressed(label).unwrap_or_default();
+        Ok((StatsDiff::new(&baseline, &current), current))
+      }
+      None => Ok((StatsDiff::new(&baseline, &current), current)),
+    }
   }
 
   fn add_diagnostic(
@@ -82,80 +257,217 @@ impl SizeLimitsPlugin {
   }
 
   fn add_assets_over_size_limit_warning(
-    detail: &[(&String, f64)],
-    limit: f64,
+    &self,
+    detail: &[(&String, AssetSize, f64)],
     hints: &str,
     diagnostics: &mut Vec<Diagnostic>,
   ) {
+    let default_limit = self.max_asset_size();
     let asset_list: String = detail
       .iter()
-      .map(|&(name, size)| format!("\n  {} ({})", name, format_size(size)))
+      .map(|(name, size, limit)| {
+        let budget = if *limit != default_limit {
+          format!(", budget {}", self.format_limit(*limit))
+        } else {
+          String::new()
+        };
+        format!("\n  {} ({}{})", name, self.format_asset_size(size), budget)
+      })
       .collect::<Vec<String>>()
       .join("");
     let title = String::from("assets over size limit warning");
-    let message = format!("asset size limit: The following asset(s) exceed the recommended size limit ({}). This can impact web performance.\nAssets:{}", format_size(limit), asset_list);
+    let message = format!("asset size limit: The following asset(s) exceed the recommended size limit ({}). This can impact web performance.\nAssets:{}", self.format_limit(default_limit), asset_list);
 
     Self::add_diagnostic(hints, title, message, diagnostics);
   }
 
   fn add_entrypoints_over_size_limit_warning(
-    detail: &[(&String, f64, Vec<String>)],
-    limit: f64,
+    &self,
+    detail: &[EntrypointOverSizeLimit],
     hints: &str,
     diagnostics: &mut Vec<Diagnostic>,
   ) {
+    let default_limit = self.max_entrypoint_size();
+    let detailed = self.options.compression.is_some() || !self.options.budgets.is_empty();
     let entrypoint_list: String = detail
       .iter()
-      .map(|(name, size, files)| {
-        format!(
-          "\n  {} ({})\n{}",
-          name,
-          format_size(*size),
-          files
-            .iter()
-            .map(|file| format!("      {}", file))
-            .collect::<Vec<_>>()
-            .join("\n")
-        )
+      .map(|entrypoint| {
+        if detailed {
+          format!(
+            "\n  {} ({}, limit {})\n{}",
+            entrypoint.name,
+            self.format_limit(entrypoint.size),
+            self.format_limit(entrypoint.limit),
+            self.format_breakdown_table(&entrypoint.files)
+          )
+        } else {
+          format!(
+            "\n  {} ({})\n{}",
+            entrypoint.name,
+            format_size(entrypoint.size),
+            entrypoint
+              .files
+              .iter()
+              .map(|(file, _)| format!("      {}", file))
+              .collect::<Vec<_>>()
+              .join("\n")
+          )
+        }
       })
       .collect::<Vec<_>>()
       .join("");
     let title = String::from("entrypoints over size limit warning");
     let message = format!(
       "entrypoint size limit: The following entrypoint(s) combined asset size exceeds the recommended limit ({}). This can impact web performance.\nEntrypoints:{}",
-      format_size(limit),
+      self.format_limit(default_limit),
       entrypoint_list
     );
 
     Self::add_diagnostic(hints, title, message, diagnostics);
   }
+
+  /// Formats the sizes of the files of an entrypoint as a table, with a column of the compressed
+  /// sizes when `compression` is set and a row of the total sizes.
+  fn format_breakdown_table(&self, files: &[(String, AssetSize)]) -> String {
+    let compression = self.options.compression;
+    let mut header = vec![String::from("Asset"), String::from("Size")];
+    if let Some(compression) = compression {
+      header.push(compression.label().to_string());
+    }
+    let mut rows = vec![header];
+    let mut total = AssetSize {
+      raw: 0.0,
+      compressed: compression.map(|_| 0.0),
+    };
+    for (file, size) in files {
+      let mut row = vec![file.clone(), format_size(size.raw)];
+      if compression.is_some() {
+        row.push(format_size(size.compressed.unwrap_or_default()));
+      }
+      rows.push(row);
+      total.raw += size.raw;
+      total.compressed = total
+        .compressed
+        .map(|c| c + size.compressed.unwrap_or_default());
+    }
+    let mut row = vec![String::from("Total"), format_size(total.raw)];
+    if let Some(compressed) = total.compressed {
+      row.push(format_size(compressed));
+    }
+    rows.push(row);
+
+    let widths = (0..rows[0].len())
+      .map(|column| rows.iter().map(|row| row[column].len()).max().unwrap_or(0))
+      .collect::<Vec<_>>();
+    rows
+      .iter()
+      .map(|row| {
+        let line = row
+          .iter()
+          .zip(&widths)
+          .map(|(cell, width)| format!("{cell:<width$}"))
+          .collect::<Vec<_>>()
+          .join("  ");
+        format!("      {}", line.trim_end())
+      })
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+
+  fn add_size_regression_warning(
+    assets: &[&StatsSizeDelta],
+    entrypoints: &[&StatsSizeDelta],
+    hints: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+  ) {
+    let format_list = |deltas: &[&StatsSizeDelta]| {
+      deltas
+        .iter()
+        .map(|delta| {
+          format!(
+            "\n  {} ({}, +{})",
+            delta.name,
+            format_size(delta.after.unwrap_or_default()),
+            format_size(delta.delta)
+          )
+        })
+        .collect::<Vec<_>>()
+        .join("")
+    };
+    let mut message = String::from("size regression: The following asset(s) and entrypoint(s) exceed the recommended size limit and grew compared to the baseline.");
+    if !assets.is_empty() {
+      message += &format!("\nAssets:{}", format_list(assets));
+    }
+    if !entrypoints.is_empty() {
+      message += &format!("\nEntrypoints:{}", format_list(entrypoints));
+    }
+    let title = String::from("size regression warning");
+
+    Self::add_diagnostic(hints, title, message, diagnostics);
+  }
 }
 
 #[plugin_hook(CompilerAfterEmit for SizeLimitsPlugin)]
 async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
   let hints = &self.options.hints;
-  let max_asset_size = self.options.max_asset_size.unwrap_or(250000.0);
-  let max_entrypoint_size = self.options.max_entrypoint_size.unwrap_or(250000.0);
   let mut checked_assets: HashMap<String, bool> = HashMap::default();
   let mut checked_chunk_groups: HashMap<ChunkGroupUkey, bool> = HashMap::default();
 
-  let mut assets_over_size_limit = vec![];
-
+  let mut filtered_assets = vec![];
   for (name, asset) in compilation.assets() {
-    let source = asset.get_source();
+    if asset.get_source().is_some() && self.asset_filter(name, asset).await {
+      filtered_assets.push(name);
+    }
+  }
 
-    if !self.asset_filter(name, asset).await {
-      continue;
+  let cache = std::mem::take(&mut *self.compressed_sizes.lock().expect("should lock"));
+  let sizes = filtered_assets
+    .into_par_iter()
+    .map(|name| {
+      let asset = &compilation.assets()[name];
+      let raw = asset.get_source().expect("should have source").size() as f64;
+      let (compressed, hash) = match self.options.compression {
+        Some(compression) => {
+          let (size, hash) = Self::get_compressed_size(compression, compilation, asset, &cache)
+            .map_err(|e| error!("Failed to compress {name}: {e}"))?;
+          (Some(size), hash)
+        }
+        None => (None, None),
+      };
+      Ok((name.clone(), AssetSize { raw, compressed }, hash))
+    })
+    .collect::<Result<Vec<_>>>()?;
+  // only keep the sizes of this build, so the cache doesn't grow with every rebuild
+  *self.compressed_sizes.lock().expect("should lock") = sizes
+    .iter()
+    .filter_map(|(_, size, hash)| Some(((*hash)?, size.compressed?)))
+    .collect();
+  let asset_sizes = sizes
+    .into_iter()
+    .map(|(name, size, _)| (name, size))
+    .collect::<HashMap<_, _>>();
+
+  // the compressed sizes are set before comparing with the baseline, which reads them
+  if let Some(compression) = self.options.compression {
+    for (name, asset) in compilation.assets_mut() {
+      if let Some(compressed) = asset_sizes.get(name).and_then(|size| size.compressed) {
+        compression.set_compressed_size(&mut asset.info, compressed);
+      }
     }
+  }
 
-    if let Some(source) = source {
-      let size = source.size() as f64;
-      let is_over_size_limit = size > max_asset_size;
+  let mut assets_over_size_limit = vec![];
 
-      checked_assets.insert(name.to_owned(), is_over_size_limit);
-      if is_over_size_limit {
-        assets_over_size_limit.push((name, size));
-      }
+  for name in compilation.assets().keys() {
+    let Some(size) = asset_sizes.get(name) else {
+      continue;
+    };
+    let limit = self.get_asset_limit(name);
+    let is_over_size_limit = size.size() > limit;
+
+    checked_assets.insert(name.to_owned(), is_over_size_limit);
+    if is_over_size_limit {
+      assets_over_size_limit.push((name, *size, limit));
     }
   }
 
@@ -163,24 +475,26 @@ async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
 
   for (name, ukey) in compilation.entrypoints.iter() {
     let entry = compilation.chunk_group_by_ukey.expect_get(ukey);
-    let size = self.get_entrypoint_size(entry, compilation).await;
-    let is_over_size_limit = size > max_entrypoint_size;
+    let files = entry
+      .get_files(&compilation.chunk_by_ukey)
+      .into_iter()
+      .filter_map(|file| {
+        let size = *asset_sizes.get(&file)?;
+        Some((file, size))
+      })
+      .collect::<Vec<_>>();
+    let size = files.iter().map(|(_, size)| size.size()).sum::<f64>();
+    let limit = self.get_entrypoint_limit(name);
+    let is_over_size_limit = size > limit;
 
     checked_chunk_groups.insert(ukey.to_owned(), is_over_size_limit);
     if is_over_size_limit {
-      let mut files = vec![];
-
-      for filename in entry.get_files(&compilation.chunk_by_ukey) {
-        let asset = compilation.assets().get(&filename);
-
-        if let Some(asset) = asset {
-          if self.asset_filter(&filename, asset).await {
-            files.push(filename);
-          }
-        }
-      }
-
-      entrypoints_over_limit.push((name, size, files));
+      entrypoints_over_limit.push(EntrypointOverSizeLimit {
+        name,
+        size,
+        limit,
+        files,
+      });
     }
   }
 
@@ -188,23 +502,54 @@ async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
     let mut diagnostics = vec![];
 
     if !assets_over_size_limit.is_empty() {
-      Self::add_assets_over_size_limit_warning(
-        &assets_over_size_limit,
-        max_asset_size,
-        hints,
-        &mut diagnostics,
-      );
+      self.add_assets_over_size_limit_warning(&assets_over_size_limit, hints, &mut diagnostics);
     }
 
     if !entrypoints_over_limit.is_empty() {
-      Self::add_entrypoints_over_size_limit_warning(
+      self.add_entrypoints_over_size_limit_warning(
         &entrypoints_over_limit,
-        max_entrypoint_size,
         hints,
         &mut diagnostics,
       );
     }
 
+    if let Some(baseline) = &self.options.baseline {
+      match self.diff_baseline(baseline, compilation) {
+        Ok((diff, current)) => {
+          let regressed_assets = diff
+            .assets
+            .iter()
+            .filter(|delta| {
+              delta.delta > 0.0
+                && checked_assets.get(current.get_asset_filename(&delta.name)) == Some(&true)
+            })
+            .collect::<Vec<_>>();
+          let regressed_entrypoints = diff
+            .entrypoints
+            .iter()
+            .filter(|delta| {
+              delta.delta > 0.0
+                && entrypoints_over_limit
+                  .iter()
+                  .any(|entrypoint| *entrypoint.name == delta.name)
+            })
+            .collect::<Vec<_>>();
+          if !regressed_assets.is_empty() || !regressed_entrypoints.is_empty() {
+            Self::add_size_regression_warning(
+              &regressed_assets,
+              &regressed_entrypoints,
+              hints,
+              &mut diagnostics,
+            );
+          }
+        }
+        Err(e) => diagnostics.push(Diagnostic::warn(
+          String::from("size regression warning"),
+          e.to_string(),
+        )),
+      }
+    }
+
     if !diagnostics.is_empty() {
       let has_async_chunk = compilation
         .chunk_by_ukey
@@ -253,3 +598,78 @@ impl Plugin for SizeLimitsPlugin {
     Ok(())
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn size_limits_budgets() {
+    let plugin = SizeLimitsPlugin::new(SizeLimitsPluginOptions {
+      asset_filter: None,
+      hints: None,
+      max_asset_size: Some(1000.0),
+      max_entrypoint_size: None,
+      baseline: None,
+      compression: Some(SizeLimitsCompression::Gzip),
+      budgets: vec![
+        SizeLimitsBudget {
+          target: SizeLimitsBudgetTarget::Asset("vendor.*.js".into()),
+          max_size: 100.0,
+        },
+        SizeLimitsBudget {
+          target: SizeLimitsBudgetTarget::Entrypoint("admin".into()),
+          max_size: 50.0,
+        },
+      ],
+    });
+    assert_eq!(plugin.get_asset_limit("vendor.abc.js"), 100.0);
+    assert_eq!(plugin.get_asset_limit("main.js"), 1000.0);
+    assert_eq!(plugin.get_entrypoint_limit("admin"), 50.0);
+    assert_eq!(plugin.get_entrypoint_limit("main"), 250000.0);
+
+    let files = vec![
+      (
+        String::from("runtime.js"),
+        AssetSize {
+          raw: 2048.0,
+          compressed: Some(1024.0),
+        },
+      ),
+      (
+        String::from("main.js"),
+        AssetSize {
+          raw: 512.0,
+          compressed: Some(300.0),
+        },
+      ),
+    ];
+    assert_eq!(
+      plugin.format_breakdown_table(&files),
+      [
+        "      Asset       Size           gzip",
+        "      runtime.js  2.000 KiB      1.000 KiB",
+        "      main.js     512.000 bytes  300.000 bytes",
+        "      Total       2.500 KiB      1.293 KiB",
+      ]
+      .join("\n")
+    );
+  }
+
+  #[test]
+  fn size_limits_compressed_size() -> std::io::Result<()> {
+    let content = "export function add(a, b) { return a + b; }\n".repeat(200);
+    // the estimate of assets the compression plugin did not compress matches the size of the
+    // asset it would emit
+    for (compression, algorithm) in [
+      (SizeLimitsCompression::Gzip, CompressionAlgorithm::Gzip),
+      (SizeLimitsCompression::Brotli, CompressionAlgorithm::Brotli),
+    ] {
+      assert_eq!(
+        compression.compressed_size(content.as_bytes())?,
+        algorithm.compress(content.as_bytes())?.len() as f64
+      );
+    }
+    Ok(())
+  }
+}
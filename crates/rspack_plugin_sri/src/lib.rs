@@ -0,0 +1,374 @@
+mod runtime;
+
+use std::{str::FromStr, sync::LazyLock};
+
+use rayon::prelude::*;
+use regex::Regex;
+use rspack_core::{
+  rspack_sources::{BoxSource, ReplaceSource, SourceExt},
+  ChunkUkey, Compilation, CompilationAdditionalTreeRuntimeRequirements, CompilationProcessAssets,
+  CompilationRuntimeRequirementInTree, Logger, Plugin, PluginContext, RuntimeGlobals,
+};
+use rspack_error::{error, Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+pub use crate::runtime::SriHashesRuntimeModule;
+
+const PLACEHOLDER_PREFIX: &str = "*-*-*-CHUNK-SRI-HASH-";
+
+static PLACEHOLDER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"\*-\*-\*-CHUNK-SRI-HASH-[A-Za-z0-9+/=]+(?: sha(?:256|384|512)-[A-Za-z0-9+/=]+)*")
+    .expect("Invalid regex")
+});
+
+/// Returns the placeholder of the integrity of the chunk file of the source type, which is
+/// derived from the chunk id so that it is deterministic, and has the same length as the
+/// integrity so that replacing it doesn't shift the source maps.
+pub(crate) fn make_placeholder(
+  hash_funcs: &[SubresourceIntegrityHashFunction],
+  source_type: &str,
+  chunk_id: &str,
+) -> String {
+  let filler = create_integrity(
+    hash_funcs,
+    format!("{PLACEHOLDER_PREFIX}{source_type}-{chunk_id}").as_bytes(),
+  );
+  format!(
+    "{PLACEHOLDER_PREFIX}{}",
+    &filler[PLACEHOLDER_PREFIX.len()..]
+  )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubresourceIntegrityHashFunction {
+  Sha256,
+  Sha384,
+  Sha512,
+}
+
+impl FromStr for SubresourceIntegrityHashFunction {
+  type Err = rspack_error::Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    match s {
+      "sha256" => Ok(Self::Sha256),
+      "sha384" => Ok(Self::Sha384),
+      "sha512" => Ok(Self::Sha512),
+      _ => Err(error!(
+        "Expect hash function of SubresourceIntegrityRspackPlugin to be one of 'sha256', 'sha384' or 'sha512', but got '{s}'"
+      )),
+    }
+  }
+}
+
+impl SubresourceIntegrityHashFunction {
+  pub fn create_digest(&self, content: &[u8]) -> String {
+    match self {
+      Self::Sha256 => format!(
+        "sha256-{}",
+        rspack_base64::encode_to_string(&Sha256::digest(content)[..])
+      ),
+      Self::Sha384 => format!(
+        "sha384-{}",
+        rspack_base64::encode_to_string(&Sha384::digest(content)[..])
+      ),
+      Self::Sha512 => format!(
+        "sha512-{}",
+        rspack_base64::encode_to_string(&Sha512::digest(content)[..])
+      ),
+    }
+  }
+}
+
+/// Returns the integrity metadata of the content, with one digest for each hash function.
+pub fn create_integrity(hash_funcs: &[SubresourceIntegrityHashFunction], content: &[u8]) -> String {
+  hash_funcs
+    .iter()
+    .map(|hash_func| hash_func.create_digest(content))
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+/// The source type of a chunk file in the integrity map, which is the type of the elements
+/// created by the runtime to load it.
+fn get_source_type(file: &str) -> Option<&'static str> {
+  let file = file.split(['?', '#']).next().unwrap_or(file);
+  match file.rsplit_once('.').map(|(_, ext)| ext) {
+    Some("js" | "mjs" | "cjs") => Some("js"),
+    Some("css") => Some("css"),
+    _ => None,
+  }
+}
+
+#[derive(Debug)]
+pub struct SubresourceIntegrityRspackPluginOptions {
+  pub hash_func_names: Vec<SubresourceIntegrityHashFunction>,
+}
+
+/// Sets `integrity` and `crossOrigin` on the script and link elements created by the runtime
+/// to load chunks.
+///
+/// The integrity of the chunks is computed after the real content hash is applied, the runtime
+/// chunks reference the async chunks by their filenames which contain the content hash, so
+/// replacing the placeholders of the integrity map doesn't change the content hash of the
+/// runtime chunks.
+#[plugin]
+#[derive(Debug)]
+pub struct SubresourceIntegrityRspackPlugin {
+  options: SubresourceIntegrityRspackPluginOptions,
+}
+
+impl SubresourceIntegrityRspackPlugin {
+  pub fn new(options: SubresourceIntegrityRspackPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilationAdditionalTreeRuntimeRequirements for SubresourceIntegrityRspackPlugin)]
+async fn additional_tree_runtime_requirements(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  runtime_requirements: &mut RuntimeGlobals,
+) -> Result<()> {
+  if compilation
+    .chunk_by_ukey
+    .expect_get(chunk_ukey)
+    .has_async_chunks(&compilation.chunk_group_by_ukey)
+  {
+    runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+    runtime_requirements.insert(RuntimeGlobals::SRI_HASHES);
+  }
+  Ok(())
+}
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for SubresourceIntegrityRspackPlugin)]
+fn runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  _all_runtime_requirements: &RuntimeGlobals,
+  runtime_requirements: &RuntimeGlobals,
+  _runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if runtime_requirements.contains(RuntimeGlobals::SRI_HASHES) {
+    compilation.add_runtime_module(
+      chunk_ukey,
+      Box::new(SriHashesRuntimeModule::new(
+        self.options.hash_func_names.clone(),
+      )),
+    )?;
+  }
+  Ok(None)
+}
+
+#[plugin_hook(CompilationProcessAssets for SubresourceIntegrityRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_HASH + 1)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let logger = compilation.get_logger(self.name());
+  let start = logger.time("compute integrity");
+
+  // each chunk file referenced by the runtime has its own placeholder, the runtime loads one
+  // file per chunk and source type, so only the first of several such files is referenced
+  let mut placeholder_to_file: HashMap<String, String> = HashMap::default();
+  let mut files: HashSet<String> = HashSet::default();
+  for chunk in compilation.chunk_by_ukey.values() {
+    let mut chunk_files = chunk
+      .files
+      .iter()
+      .filter_map(|file| Some((file, get_source_type(file)?)))
+      .collect::<Vec<_>>();
+    chunk_files.sort_unstable();
+    for (file, source_type) in chunk_files {
+      files.insert(file.clone());
+      if let Some(chunk_id) = chunk.id.as_deref() {
+        placeholder_to_file
+          .entry(make_placeholder(
+            &self.options.hash_func_names,
+            source_type,
+            chunk_id,
+          ))
+          .or_insert_with(|| file.clone());
+      }
+    }
+  }
+  let file_to_placeholder = placeholder_to_file
+    .iter()
+    .map(|(placeholder, file)| (file.as_str(), placeholder.as_str()))
+    .collect::<HashMap<_, _>>();
+
+  // the placeholders each chunk file contains, a file can only be hashed after all of them
+  // are replaced
+  let mut pending = files
+    .par_iter()
+    .filter_map(|file| {
+      let source = compilation.assets().get(file)?.get_source()?;
+      let deps = PLACEHOLDER_REGEX
+        .find_iter(&source.source())
+        .map(|m| m.as_str().to_string())
+        .collect::<HashSet<_>>();
+      Some((file.clone(), deps))
+    })
+    .collect::<HashMap<_, _>>();
+
+  let mut integrities: HashMap<String, String> = HashMap::default();
+  while !pending.is_empty() {
+    let mut ready = pending
+      .iter()
+      .filter(|(_, deps)| {
+        deps
+          .iter()
+          .all(|dep| integrities.contains_key(dep) || !placeholder_to_file.contains_key(dep))
+      })
+      .map(|(file, _)| file.clone())
+      .collect::<Vec<_>>();
+    if ready.is_empty() {
+      // chunks that reference each other's integrity can't be hashed in order, the placeholders
+      // in them are replaced with spaces so that the browsers skip the check
+      let mut files = pending.keys().cloned().collect::<Vec<_>>();
+      files.sort();
+      compilation.push_diagnostic(Diagnostic::warn(
+        self.name().to_string(),
+        format!(
+          "The integrity of the following chunk files can't be computed because they reference each other: {}",
+          files.join(", ")
+        ),
+      ));
+      ready = files;
+    }
+
+    let results = ready
+      .par_iter()
+      .map(|file| {
+        let deps = &pending[file];
+        let source = compilation.assets()[file]
+          .get_source()
+          .expect("should have source")
+          .clone();
+        let source: BoxSource = if deps.is_empty() {
+          source
+        } else {
+          // the integrities have the same length as the placeholders, the replacements keep the
+          // source map of the file
+          let content = source.source().into_owned();
+          let mut replace_source = ReplaceSource::new(source);
+          for m in PLACEHOLDER_REGEX.find_iter(&content) {
+            let integrity = integrities
+              .get(m.as_str())
+              .cloned()
+              .unwrap_or_else(|| " ".repeat(m.len()));
+            replace_source.replace(m.start() as u32, m.end() as u32, &integrity, None);
+          }
+          replace_source.boxed()
+        };
+        let integrity = create_integrity(&self.options.hash_func_names, &source.buffer());
+        (
+          file.clone(),
+          (!deps.is_empty()).then_some(source),
+          integrity,
+        )
+      })
+      .collect::<Vec<_>>();
+
+    for (file, source, integrity) in results {
+      pending.remove(&file);
+      if let Some(source) = source {
+        compilation.update_asset(&file, |_, info| Ok((source, info)))?;
+      }
+      if let Some(placeholder) = file_to_placeholder.get(file.as_str()) {
+        integrities.insert(placeholder.to_string(), integrity);
+      }
+    }
+  }
+
+  logger.time_end(start);
+  Ok(())
+}
+
+impl Plugin for SubresourceIntegrityRspackPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.SubresourceIntegrityRspackPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .additional_tree_runtime_requirements
+      .tap(additional_tree_runtime_requirements::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(runtime_requirements_in_tree::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn sri_integrity() {
+    assert_eq!(
+      create_integrity(
+        &[SubresourceIntegrityHashFunction::Sha256],
+        b"alert('Hello, world.');"
+      ),
+      "sha256-qznLcsROx4GACP2dm0UCKCzCG+HiZ1guq6ZZDob/Tng="
+    );
+    assert_eq!(
+      create_integrity(
+        &[
+          SubresourceIntegrityHashFunction::Sha256,
+          SubresourceIntegrityHashFunction::Sha384
+        ],
+        b"alert('Hello, world.');"
+      ),
+      "sha256-qznLcsROx4GACP2dm0UCKCzCG+HiZ1guq6ZZDob/Tng= sha384-H8BRh8j48O9oYatfu5AZzq6A9RINhZO5H16dQZngK7T62em8MUt1FLm52t+eX6xO"
+    );
+    assert!("md5".parse::<SubresourceIntegrityHashFunction>().is_err());
+  }
+
+  #[test]
+  fn sri_placeholder() {
+    let hash_funcs = [
+      SubresourceIntegrityHashFunction::Sha256,
+      SubresourceIntegrityHashFunction::Sha512,
+    ];
+    let placeholder = make_placeholder(&hash_funcs, "js", "async_a");
+    assert!(placeholder.starts_with(PLACEHOLDER_PREFIX));
+    assert_eq!(placeholder, make_placeholder(&hash_funcs, "js", "async_a"));
+    assert_ne!(placeholder, make_placeholder(&hash_funcs, "css", "async_a"));
+    assert_eq!(
+      placeholder.len(),
+      create_integrity(&hash_funcs, b"alert('Hello, world.');").len()
+    );
+    let content = format!("{{\"async_a\": \"{placeholder}\"}}");
+    assert_eq!(
+      PLACEHOLDER_REGEX
+        .find(&content)
+        .map(|m| m.as_str().to_string()),
+      Some(placeholder)
+    );
+  }
+
+  #[test]
+  fn sri_source_type() {
+    assert_eq!(get_source_type("static/js/async/a.js?v=1"), Some("js"));
+    assert_eq!(get_source_type("a.mjs"), Some("js"));
+    assert_eq!(get_source_type("static/css/a.css"), Some("css"));
+    assert_eq!(get_source_type("a.js.map"), None);
+  }
+}
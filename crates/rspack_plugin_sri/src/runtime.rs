@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+
+use rspack_collections::Identifier;
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  ChunkUkey, Compilation, RuntimeGlobals, RuntimeModule,
+};
+use rspack_plugin_runtime::{chunk_has_css, chunk_has_js};
+
+use crate::{make_placeholder, SubresourceIntegrityHashFunction};
+
+#[impl_runtime_module]
+#[derive(Debug)]
+pub struct SriHashesRuntimeModule {
+  id: Identifier,
+  chunk: Option<ChunkUkey>,
+  hash_func_names: Vec<SubresourceIntegrityHashFunction>,
+}
+
+impl SriHashesRuntimeModule {
+  pub fn new(hash_func_names: Vec<SubresourceIntegrityHashFunction>) -> Self {
+    Self::with_default(
+      Identifier::from("webpack/runtime/sri_hashes"),
+      None,
+      hash_func_names,
+    )
+  }
+}
+
+impl RuntimeModule for SriHashesRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk);
+  }
+
+  fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+    let chunk = compilation
+      .chunk_by_ukey
+      .expect_get(&self.chunk.expect("The chunk should be attached"));
+
+    // the real hashes are unknown until the assets are optimized, so placeholders are
+    // rendered here and replaced in processAssets
+    let mut js = BTreeMap::new();
+    let mut css = BTreeMap::new();
+    for chunk_ukey in chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey) {
+      let Some(chunk_id) = compilation
+        .chunk_by_ukey
+        .expect_get(&chunk_ukey)
+        .id
+        .as_ref()
+      else {
+        continue;
+      };
+      if chunk_has_js(&chunk_ukey, compilation) {
+        js.insert(
+          chunk_id,
+          make_placeholder(&self.hash_func_names, "js", chunk_id),
+        );
+      }
+      if chunk_has_css(&chunk_ukey, compilation) {
+        css.insert(
+          chunk_id,
+          make_placeholder(&self.hash_func_names, "css", chunk_id),
+        );
+      }
+    }
+
+    Ok(
+      RawSource::from(format!(
+        "{} = {{\"js\": {}, \"css\": {}}};",
+        RuntimeGlobals::SRI_HASHES,
+        serde_json::to_string(&js).expect("invalid json tostring"),
+        serde_json::to_string(&css).expect("invalid json tostring"),
+      ))
+      .boxed(),
+    )
+  }
+}
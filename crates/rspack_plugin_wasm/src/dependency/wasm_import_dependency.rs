@@ -1,8 +1,9 @@
 use rspack_core::{
-  AsContextDependency, AsDependencyTemplate, Dependency, DependencyCategory, DependencyId,
-  DependencyRange, DependencyType, ExtendedReferencedExport, ModuleDependency, ModuleGraph,
-  RuntimeSpec,
+  create_resource_identifier_for_module_dependency, AsContextDependency, AsDependencyTemplate,
+  Dependency, DependencyCategory, DependencyId, DependencyRange, DependencyType,
+  ExtendedReferencedExport, ModuleDependency, ModuleGraph, RuntimeSpec,
 };
+use rspack_error::Diagnostic;
 use swc_core::ecma::atoms::Atom;
 
 use crate::WasmNode;
@@ -13,26 +14,43 @@ pub struct WasmImportDependency {
   id: DependencyId,
   name: Atom,
   request: String,
-  // only_direct_import: bool,
+  /// The reason why the import can only be satisfied by another wasm module,
+  /// e.g. memories, tables and globals imported by a sync wasm module.
+  only_direct_import: Option<String>,
   /// the WASM AST node
   pub desc: WasmNode,
   span: Option<DependencyRange>,
+  resource_identifier: String,
 }
 
 impl WasmImportDependency {
-  pub fn new(request: String, name: String, desc: WasmNode) -> Self {
+  pub fn new(
+    request: String,
+    name: String,
+    desc: WasmNode,
+    only_direct_import: Option<String>,
+  ) -> Self {
+    let resource_identifier = create_resource_identifier_for_module_dependency(
+      &DependencyCategory::Wasm,
+      &DependencyType::WasmImport,
+      &request,
+    );
     Self {
       id: DependencyId::new(),
       name: name.into(),
       request,
       desc,
-      // only_direct_import,
+      only_direct_import,
       span: None,
+      resource_identifier,
     }
   }
   pub fn name(&self) -> &str {
     &self.name
   }
+  pub fn only_direct_import(&self) -> bool {
+    self.only_direct_import.is_some()
+  }
 }
 
 impl Dependency for WasmImportDependency {
@@ -40,6 +58,10 @@ impl Dependency for WasmImportDependency {
     &self.id
   }
 
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
   fn category(&self) -> &DependencyCategory {
     &DependencyCategory::Wasm
   }
@@ -48,6 +70,21 @@ impl Dependency for WasmImportDependency {
     &DependencyType::WasmImport
   }
 
+  fn get_diagnostics(&self, module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    let reason = self.only_direct_import.as_ref()?;
+    let module = module_graph.get_module_by_dependency_id(&self.id)?;
+    if module.module_type().is_wasm_like() {
+      return None;
+    }
+    Some(vec![Diagnostic::error(
+      "UnsupportedWebAssemblyFeatureError".into(),
+      format!(
+        "Import \"{}\" from \"{}\" with {reason} can only be used for direct wasm to wasm dependencies",
+        self.name, self.request
+      ),
+    )])
+  }
+
   fn get_referenced_exports(
     &self,
     _module_graph: &ModuleGraph,
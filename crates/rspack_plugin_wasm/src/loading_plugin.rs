@@ -1,20 +1,66 @@
 use rspack_core::{
-  BoxPlugin, ChunkUkey, Compilation, CompilationRuntimeRequirementInTree, Plugin, PluginContext,
-  PluginExt, RuntimeGlobals, RuntimeModuleExt, WasmLoadingType,
+  BoxPlugin, ChunkUkey, Compilation, CompilationParams, CompilationRuntimeRequirementInTree,
+  CompilerThisCompilation, Plugin, PluginContext, PluginExt, RuntimeGlobals, RuntimeModuleExt,
+  WasmLoading, WasmLoadingType,
 };
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 
-use crate::AsyncWasmLoadingRuntimeModule;
+use crate::{
+  has_sync_wasm_module_in_graph, AsyncWasmLoadingRuntimeModule, WasmChunkLoadingRuntimeModule,
+};
 
-pub fn enable_wasm_loading_plugin(wasm_loading_type: WasmLoadingType) -> BoxPlugin {
+pub fn enable_wasm_loading_plugin(
+  wasm_loading_type: WasmLoadingType,
+  plugins: &mut Vec<BoxPlugin>,
+) {
   match wasm_loading_type {
-    WasmLoadingType::Fetch => FetchCompileAsyncWasmPlugin::default().boxed(),
-    WasmLoadingType::AsyncNode => ReadFileCompileAsyncWasmPlugin::new(false).boxed(),
-    WasmLoadingType::AsyncNodeModule => ReadFileCompileAsyncWasmPlugin::new(true).boxed(),
+    WasmLoadingType::Fetch => {
+      plugins.push(FetchCompileWasmPlugin::default().boxed());
+      plugins.push(FetchCompileAsyncWasmPlugin::default().boxed());
+    }
+    WasmLoadingType::AsyncNode => {
+      plugins.push(ReadFileCompileWasmPlugin::new(false).boxed());
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new(false).boxed());
+    }
+    WasmLoadingType::AsyncNodeModule => {
+      plugins.push(ReadFileCompileWasmPlugin::new(true).boxed());
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new(true).boxed());
+    }
+    WasmLoadingType::Universal => {
+      plugins.push(UniversalCompileWasmPlugin::default().boxed());
+      plugins.push(UniversalCompileAsyncWasmPlugin::default().boxed());
+    }
+    WasmLoadingType::Custom(name) => {
+      // custom types are enabled by the `CustomWasmLoadingPlugin` providing them,
+      // so no plugin provides this one
+      plugins.push(UnknownWasmLoadingPlugin::new(name).boxed());
+    }
   }
 }
 
+/// Like `is_enabled_for_chunk` of the chunk loading plugins, entries (and workers, which
+/// get `output.workerWasmLoading`) may use another wasm loading type than the output.
+fn get_wasm_loading_type<'a>(
+  compilation: &'a Compilation,
+  chunk_ukey: &ChunkUkey,
+) -> &'a WasmLoading {
+  compilation
+    .chunk_by_ukey
+    .expect_get(chunk_ukey)
+    .get_entry_options(&compilation.chunk_group_by_ukey)
+    .and_then(|options| options.wasm_loading.as_ref())
+    .unwrap_or(&compilation.options.output.wasm_loading)
+}
+
+fn is_enabled_for_chunk(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  expected: WasmLoadingType,
+) -> bool {
+  get_wasm_loading_type(compilation, chunk_ukey) == &WasmLoading::Enable(expected)
+}
+
 #[plugin]
 #[derive(Debug, Default)]
 pub struct FetchCompileAsyncWasmPlugin;
@@ -28,6 +74,10 @@ fn fetch_compile_async_wasm_plugin_runtime_requirements_in_tree(
   runtime_requirements: &RuntimeGlobals,
   runtime_requirements_mut: &mut RuntimeGlobals,
 ) -> Result<Option<()>> {
+  if !is_enabled_for_chunk(compilation, chunk_ukey, WasmLoadingType::Fetch) {
+    return Ok(None);
+  }
+
   if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
     runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
     compilation.add_runtime_module(
@@ -73,6 +123,14 @@ impl ReadFileCompileAsyncWasmPlugin {
   fn new(import: bool) -> Self {
     Self::new_inner(import)
   }
+
+  fn wasm_loading_type(&self) -> WasmLoadingType {
+    if self.import {
+      WasmLoadingType::AsyncNodeModule
+    } else {
+      WasmLoadingType::AsyncNode
+    }
+  }
 }
 
 #[plugin_hook(CompilationRuntimeRequirementInTree for ReadFileCompileAsyncWasmPlugin)]
@@ -84,6 +142,10 @@ fn read_file_compile_async_wasm_plugin_runtime_requirements_in_tree(
   runtime_requirements: &RuntimeGlobals,
   runtime_requirements_mut: &mut RuntimeGlobals,
 ) -> Result<Option<()>> {
+  if !is_enabled_for_chunk(compilation, chunk_ukey, self.wasm_loading_type()) {
+    return Ok(None);
+  }
+
   if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
     runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
     compilation.add_runtime_module(
@@ -122,3 +184,381 @@ impl Plugin for ReadFileCompileAsyncWasmPlugin {
     Ok(())
   }
 }
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct FetchCompileWasmPlugin;
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for FetchCompileWasmPlugin)]
+fn fetch_compile_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  _all_runtime_requirements: &RuntimeGlobals,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if !is_enabled_for_chunk(compilation, chunk_ukey, WasmLoadingType::Fetch) {
+    return Ok(None);
+  }
+
+  if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    && has_sync_wasm_module_in_graph(compilation, chunk_ukey)
+  {
+    runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+    runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+    compilation.add_runtime_module(
+      chunk_ukey,
+      WasmChunkLoadingRuntimeModule::new(
+        format!("fetch({} + $PATH)", RuntimeGlobals::PUBLIC_PATH),
+        true,
+        *chunk_ukey,
+      )
+      .boxed(),
+    )?;
+  }
+
+  Ok(None)
+}
+
+impl Plugin for FetchCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "FetchCompileWasmPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(fetch_compile_wasm_plugin_runtime_requirements_in_tree::new(
+        self,
+      ));
+    Ok(())
+  }
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct ReadFileCompileWasmPlugin {
+  import: bool,
+}
+
+impl ReadFileCompileWasmPlugin {
+  fn new(import: bool) -> Self {
+    Self::new_inner(import)
+  }
+
+  fn wasm_loading_type(&self) -> WasmLoadingType {
+    if self.import {
+      WasmLoadingType::AsyncNodeModule
+    } else {
+      WasmLoadingType::AsyncNode
+    }
+  }
+}
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for ReadFileCompileWasmPlugin)]
+fn read_file_compile_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  _all_runtime_requirements: &RuntimeGlobals,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if !is_enabled_for_chunk(compilation, chunk_ukey, self.wasm_loading_type()) {
+    return Ok(None);
+  }
+
+  if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    && has_sync_wasm_module_in_graph(compilation, chunk_ukey)
+  {
+    runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+    compilation.add_runtime_module(
+      chunk_ukey,
+      WasmChunkLoadingRuntimeModule::new(
+        if self.import {
+          include_str!("runtime/read_file_compile_async_wasm_with_import.js").to_string()
+        } else {
+          include_str!("runtime/read_file_compile_async_wasm.js").to_string()
+        },
+        false,
+        *chunk_ukey,
+      )
+      .boxed(),
+    )?;
+  }
+
+  Ok(None)
+}
+
+impl Plugin for ReadFileCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "ReadFileCompileWasmPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(read_file_compile_wasm_plugin_runtime_requirements_in_tree::new(self));
+    Ok(())
+  }
+}
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct UniversalCompileWasmPlugin;
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for UniversalCompileWasmPlugin)]
+fn universal_compile_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  _all_runtime_requirements: &RuntimeGlobals,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if !is_enabled_for_chunk(compilation, chunk_ukey, WasmLoadingType::Universal) {
+    return Ok(None);
+  }
+
+  if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    && has_sync_wasm_module_in_graph(compilation, chunk_ukey)
+  {
+    runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+    compilation.add_runtime_module(
+      chunk_ukey,
+      WasmChunkLoadingRuntimeModule::new(
+        include_str!("runtime/universal_compile_wasm.js").to_string(),
+        false,
+        *chunk_ukey,
+      )
+      .boxed(),
+    )?;
+  }
+
+  Ok(None)
+}
+
+impl Plugin for UniversalCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "UniversalCompileWasmPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(universal_compile_wasm_plugin_runtime_requirements_in_tree::new(self));
+    Ok(())
+  }
+}
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct UniversalCompileAsyncWasmPlugin;
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for UniversalCompileAsyncWasmPlugin)]
+fn universal_compile_async_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  _all_runtime_requirements: &RuntimeGlobals,
+  runtime_requirements: &RuntimeGlobals,
+  _runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if !is_enabled_for_chunk(compilation, chunk_ukey, WasmLoadingType::Universal) {
+    return Ok(None);
+  }
+
+  if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
+    compilation.add_runtime_module(
+      chunk_ukey,
+      AsyncWasmLoadingRuntimeModule::new(
+        include_str!("runtime/universal_compile_async_wasm.js").to_string(),
+        true,
+        *chunk_ukey,
+      )
+      .with_before_code(
+        "var useFetch = typeof document !== 'undefined' || typeof self !== 'undefined';"
+          .to_string(),
+        "if (!useFetch) {\n  return fallback();\n}".to_string(),
+      )
+      .boxed(),
+    )?;
+  }
+
+  Ok(None)
+}
+
+impl Plugin for UniversalCompileAsyncWasmPlugin {
+  fn name(&self) -> &'static str {
+    "UniversalCompileAsyncWasmPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(universal_compile_async_wasm_plugin_runtime_requirements_in_tree::new(self));
+    Ok(())
+  }
+}
+
+/// Wires a plugin provided wasm loading type, e.g. `output.wasmLoading: "my-host"`.
+///
+/// `generate_load_binary_code` is an expression resolving to a fetch like response of the wasm file,
+/// where `$PATH` is replaced with the path of the file and `$IMPORT_META_NAME` with `output.importMetaName`.
+#[plugin]
+#[derive(Debug)]
+pub struct CustomWasmLoadingPlugin {
+  wasm_loading: WasmLoading,
+  generate_load_binary_code: String,
+  supports_streaming: bool,
+}
+
+impl CustomWasmLoadingPlugin {
+  pub fn new(name: String, generate_load_binary_code: String, supports_streaming: bool) -> Self {
+    Self::new_inner(
+      WasmLoading::Enable(WasmLoadingType::Custom(name)),
+      generate_load_binary_code,
+      supports_streaming,
+    )
+  }
+}
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for CustomWasmLoadingPlugin)]
+fn custom_wasm_loading_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  _all_runtime_requirements: &RuntimeGlobals,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if get_wasm_loading_type(compilation, chunk_ukey) != &self.wasm_loading {
+    return Ok(None);
+  }
+
+  if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
+    runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+    compilation.add_runtime_module(
+      chunk_ukey,
+      AsyncWasmLoadingRuntimeModule::new(
+        self.generate_load_binary_code.clone(),
+        self.supports_streaming,
+        *chunk_ukey,
+      )
+      .boxed(),
+    )?;
+  }
+
+  if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    && has_sync_wasm_module_in_graph(compilation, chunk_ukey)
+  {
+    runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+    runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+    compilation.add_runtime_module(
+      chunk_ukey,
+      WasmChunkLoadingRuntimeModule::new(
+        self.generate_load_binary_code.clone(),
+        self.supports_streaming,
+        *chunk_ukey,
+      )
+      .boxed(),
+    )?;
+  }
+
+  Ok(None)
+}
+
+impl Plugin for CustomWasmLoadingPlugin {
+  fn name(&self) -> &'static str {
+    "CustomWasmLoadingPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(custom_wasm_loading_plugin_runtime_requirements_in_tree::new(self));
+    Ok(())
+  }
+}
+
+/// Reports a wasm loading type which no plugin provides, once for every compilation.
+///
+/// Plugins providing a custom wasm loading type enable it themselves, so only the
+/// unknown types of `output.wasmLoading`, `output.workerWasmLoading` and the entries get here.
+#[plugin]
+#[derive(Debug)]
+struct UnknownWasmLoadingPlugin {
+  name: String,
+}
+
+impl UnknownWasmLoadingPlugin {
+  fn new(name: String) -> Self {
+    Self::new_inner(name)
+  }
+}
+
+#[plugin_hook(CompilerThisCompilation for UnknownWasmLoadingPlugin)]
+async fn unknown_wasm_loading_plugin_this_compilation(
+  &self,
+  compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  compilation.push_diagnostic(Diagnostic::error(
+    "Unsupported wasm loading type".into(),
+    format!(
+      "Unsupported wasm loading type \"{}\".\nPlugins which provide custom wasm loading types must enable them with CustomWasmLoadingPlugin, otherwise use one of \"fetch\", \"async-node\", \"async-node-module\" or \"universal\".",
+      self.name
+    ),
+  ));
+  Ok(())
+}
+
+impl Plugin for UnknownWasmLoadingPlugin {
+  fn name(&self) -> &'static str {
+    "UnknownWasmLoadingPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .this_compilation
+      .tap(unknown_wasm_loading_plugin_this_compilation::new(self));
+    Ok(())
+  }
+}
@@ -1,13 +1,18 @@
 use cow_utils::CowUtils;
+use indexmap::IndexMap;
 use rspack_collections::Identifier;
 use rspack_core::rspack_sources::{BoxSource, RawSource, SourceExt};
 use rspack_core::{
-  get_filename_without_hash_length, impl_runtime_module, ChunkUkey, Compilation, PathData,
-  RuntimeModule, RuntimeModuleStage,
+  get_filename_without_hash_length, impl_runtime_module, BoxModule, ChunkUkey, Compilation,
+  DependencyType, ModuleDependency, ModuleType, PathData, RuntimeGlobals, RuntimeModule,
+  RuntimeModuleStage, SourceType, UsedName,
 };
 use rspack_util::infallible::ResultInfallibleExt as _;
 use rspack_util::itoa;
 
+use crate::dependency::WasmImportDependency;
+use crate::hash_for_source;
+
 #[impl_runtime_module]
 #[derive(Debug)]
 pub struct AsyncWasmLoadingRuntimeModule {
@@ -15,6 +20,8 @@ pub struct AsyncWasmLoadingRuntimeModule {
   generate_load_binary_code: String,
   supports_streaming: bool,
   chunk: ChunkUkey,
+  generate_before_load_binary_code: Option<String>,
+  generate_before_instantiate_streaming: Option<String>,
 }
 
 impl AsyncWasmLoadingRuntimeModule {
@@ -28,8 +35,22 @@ impl AsyncWasmLoadingRuntimeModule {
       generate_load_binary_code,
       supports_streaming,
       chunk,
+      None,
+      None,
     )
   }
+
+  /// Adds code before the binary is requested and before `WebAssembly.instantiateStreaming` is tried,
+  /// for loaders which only decide at runtime whether the response can be streamed.
+  pub fn with_before_code(
+    mut self,
+    generate_before_load_binary_code: String,
+    generate_before_instantiate_streaming: String,
+  ) -> Self {
+    self.generate_before_load_binary_code = Some(generate_before_load_binary_code);
+    self.generate_before_instantiate_streaming = Some(generate_before_instantiate_streaming);
+    self
+  }
 }
 
 impl RuntimeModule for AsyncWasmLoadingRuntimeModule {
@@ -70,6 +91,14 @@ impl RuntimeModule for AsyncWasmLoadingRuntimeModule {
             compilation.options.output.import_meta_name.as_str(),
           ),
         self.supports_streaming,
+        self
+          .generate_before_load_binary_code
+          .as_deref()
+          .unwrap_or_default(),
+        self
+          .generate_before_instantiate_streaming
+          .as_deref()
+          .unwrap_or_default(),
       ))
       .boxed(),
     )
@@ -80,36 +109,47 @@ impl RuntimeModule for AsyncWasmLoadingRuntimeModule {
   }
 }
 
-fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
+fn get_async_wasm_loading(
+  req: &str,
+  supports_streaming: bool,
+  before_load_binary_code: &str,
+  before_instantiate_streaming: &str,
+) -> String {
+  // keep the generated code unchanged for loaders without the extra code
+  let before_load_binary_code = indent_before_code(before_load_binary_code, "      ");
+  let before_instantiate_streaming = indent_before_code(before_instantiate_streaming, "        ");
+
   let fallback_code = r#"
           .then(function(x) { return x.arrayBuffer();})
           .then(function(bytes) { return WebAssembly.instantiate(bytes, importsObj);})
           .then(function(res) { return Object.assign(exports, res.instance.exports);});
 "#;
 
-  let streaming_code = r#"
-      return req.then(function(res) {
-        if (typeof WebAssembly.instantiateStreaming === "function") {
+  let streaming_code = format!(
+    r#"
+      return req.then(function(res) {{{before_instantiate_streaming}
+        if (typeof WebAssembly.instantiateStreaming === "function") {{
           return WebAssembly.instantiateStreaming(res, importsObj)
             .then(
-              function(res) { return Object.assign(exports, res.instance.exports);},
-              function(e) {
-                if(res.headers.get("Content-Type") !== "application/wasm") {
+              function(res) {{ return Object.assign(exports, res.instance.exports);}},
+              function(e) {{
+                if(res.headers.get("Content-Type") !== "application/wasm") {{
                   console.warn("`WebAssembly.instantiateStreaming` failed because your server does not serve wasm with `application/wasm` MIME type. Falling back to `WebAssembly.instantiate` which is slower. Original error:\n", e);
                   return fallback();
-                }
+                }}
                 throw e;
-              }
+              }}
             );
-        }
+        }}
         return fallback();
-      });
-"#;
+      }});
+"#
+  );
 
   if supports_streaming {
     format!(
       r#"
-    __webpack_require__.v = function(exports, wasmModuleId, wasmModuleHash, importsObj) {{
+    __webpack_require__.v = function(exports, wasmModuleId, wasmModuleHash, importsObj) {{{before_load_binary_code}
       var req = {req};
       var fallback = function() {{
         return req{fallback_code}
@@ -122,10 +162,383 @@ fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
     let req = req.trim_end_matches(';');
     format!(
       r#"
-    __webpack_require__.v = function(exports, wasmModuleId, wasmModuleHash, importsObj) {{
+    __webpack_require__.v = function(exports, wasmModuleId, wasmModuleHash, importsObj) {{{before_load_binary_code}
       return {req}{fallback_code}
     }};
       "#
     )
   }
 }
+
+fn indent_before_code(code: &str, indent: &str) -> String {
+  let mut result = String::new();
+  for line in code.lines() {
+    result.push('\n');
+    result.push_str(indent);
+    result.push_str(line);
+  }
+  result
+}
+
+/// Loads and instantiates the `webassembly/sync` modules of a chunk in `__webpack_require__.f.wasm`,
+/// so the instances are ready before the modules of the chunk are executed.
+#[impl_runtime_module]
+#[derive(Debug)]
+pub struct WasmChunkLoadingRuntimeModule {
+  id: Identifier,
+  generate_load_binary_code: String,
+  supports_streaming: bool,
+  chunk: ChunkUkey,
+}
+
+impl WasmChunkLoadingRuntimeModule {
+  pub fn new(
+    generate_load_binary_code: String,
+    supports_streaming: bool,
+    chunk: ChunkUkey,
+  ) -> Self {
+    Self::with_default(
+      Identifier::from("webpack/runtime/wasm_chunk_loading"),
+      generate_load_binary_code,
+      supports_streaming,
+      chunk,
+    )
+  }
+}
+
+impl RuntimeModule for WasmChunkLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+    let chunk = compilation.chunk_by_ukey.expect_get(&self.chunk);
+    let module_graph = compilation.get_module_graph();
+    let chunk_graph = &compilation.chunk_graph;
+
+    let mut wasm_module_map = IndexMap::<&str, Vec<&str>>::new();
+    let mut wasm_modules = IndexMap::<&str, &BoxModule>::new();
+    for chunk_ukey in get_all_wasm_chunks(compilation, &self.chunk) {
+      let Some(chunk_id) = compilation
+        .chunk_by_ukey
+        .expect_get(&chunk_ukey)
+        .id
+        .as_deref()
+      else {
+        continue;
+      };
+      let mut module_ids = chunk_graph
+        .get_chunk_modules_by_source_type(&chunk_ukey, SourceType::Wasm, &module_graph)
+        .into_iter()
+        .filter(|m| *m.module_type() == ModuleType::WasmSync)
+        .filter_map(|m| {
+          let module_id = chunk_graph.get_module_id(m.identifier())?;
+          wasm_modules.insert(module_id, m);
+          Some(module_id)
+        })
+        .collect::<Vec<_>>();
+      if module_ids.is_empty() {
+        continue;
+      }
+      module_ids.sort_unstable();
+      wasm_module_map.insert(chunk_id, module_ids);
+    }
+    wasm_modules.sort_unstable_keys();
+
+    let mut declarations = vec![];
+    let import_objects = wasm_modules
+      .iter()
+      .map(|(module_id, module)| {
+        generate_import_object(compilation, module_id, module, chunk, &mut declarations)
+      })
+      .collect::<Vec<_>>();
+    let module_hashes = wasm_modules
+      .iter()
+      .map(|(module_id, module)| {
+        let hash = module
+          .original_source()
+          .map(hash_for_source)
+          .unwrap_or_default();
+        format!(
+          "{}: {}",
+          serde_json::to_string(module_id).expect("should be valid json"),
+          serde_json::to_string(&hash).expect("should be valid json")
+        )
+      })
+      .collect::<Vec<_>>();
+
+    let (fake_filename, hash_len_map) =
+      get_filename_without_hash_length(&compilation.options.output.webassembly_module_filename);
+    let hash = match hash_len_map
+      .get("[contenthash]")
+      .or(hash_len_map.get("[hash]"))
+    {
+      Some(hash_len) => format!(
+        "\" + wasmModuleHashes[wasmModuleId].slice(0, {}) + \"",
+        itoa!(*hash_len)
+      ),
+      None => "\" + wasmModuleHashes[wasmModuleId] + \"".to_string(),
+    };
+    let path = compilation
+      .get_path(
+        &fake_filename,
+        PathData::default()
+          .hash(&hash)
+          .content_hash(&hash)
+          .id("\" + wasmModuleId + \"")
+          .runtime(&chunk.runtime),
+      )
+      .always_ok();
+    let load_binary_code = self
+      .generate_load_binary_code
+      .cow_replace("$PATH", &format!("\"{}\"", path))
+      .cow_replace(
+        "$IMPORT_META_NAME",
+        compilation.options.output.import_meta_name.as_str(),
+      )
+      .into_owned();
+
+    let instantiate_code = if self.supports_streaming {
+      r#"if(importObject && typeof importObject.then === 'function' && typeof WebAssembly.compileStreaming === 'function') {
+        promise = Promise.all([WebAssembly.compileStreaming(req), importObject]).then(function(items) {
+          return WebAssembly.instantiate(items[0], items[1]);
+        });
+      } else if(typeof WebAssembly.instantiateStreaming === 'function') {
+        promise = WebAssembly.instantiateStreaming(req, importObject);
+      } else {
+        var bytesPromise = req.then(function(x) { return x.arrayBuffer(); });
+        promise = bytesPromise.then(function(bytes) {
+          return WebAssembly.instantiate(bytes, importObject);
+        });
+      }"#
+    } else {
+      r#"var bytesPromise = req.then(function(x) { return x.arrayBuffer(); });
+      if(importObject && typeof importObject.then === 'function') {
+        promise = Promise.all([bytesPromise.then(function(bytes) { return WebAssembly.compile(bytes); }), importObject]).then(function(items) {
+          return WebAssembly.instantiate(items[0], items[1]);
+        });
+      } else {
+        promise = bytesPromise.then(function(bytes) {
+          return WebAssembly.instantiate(bytes, importObject);
+        });
+      }"#
+    };
+
+    let wasm_module_map = wasm_module_map
+      .into_iter()
+      .map(|(chunk_id, module_ids)| {
+        format!(
+          "{}: {}",
+          serde_json::to_string(chunk_id).expect("should be valid json"),
+          serde_json::to_string(&module_ids).expect("should be valid json")
+        )
+      })
+      .collect::<Vec<_>>();
+
+    Ok(
+      RawSource::from(format!(
+        r#"
+// object to store loaded and loading wasm modules
+var installedWasmModules = {{}};
+
+function promiseResolve() {{ return Promise.resolve(); }}
+
+{declarations}
+var wasmImportObjects = {{
+{import_objects}
+}};
+
+var wasmModuleMap = {{
+{wasm_module_map}
+}};
+
+var wasmModuleHashes = {{
+{module_hashes}
+}};
+
+// object with all WebAssembly.instance exports
+{wasm_instances} = {{}};
+
+// Fetch + compile chunk loading for webassembly
+{ensure_chunk_handlers}.wasm = function(chunkId, promises) {{
+  var wasmModules = wasmModuleMap[chunkId] || [];
+  wasmModules.forEach(function(wasmModuleId, idx) {{
+    var installedWasmModuleData = installedWasmModules[wasmModuleId];
+    // a Promise means "currently loading" or "already loaded".
+    if(installedWasmModuleData)
+      promises.push(installedWasmModuleData);
+    else {{
+      var importObject = wasmImportObjects[wasmModuleId]();
+      var req = {load_binary_code};
+      var promise;
+      {instantiate_code}
+      promises.push(installedWasmModules[wasmModuleId] = promise.then(function(res) {{
+        return {wasm_instances}[wasmModuleId] = (res.instance || res).exports;
+      }}));
+    }}
+  }});
+}};
+"#,
+        declarations = declarations.join("\n"),
+        import_objects = import_objects.join(",\n"),
+        wasm_module_map = wasm_module_map.join(",\n"),
+        module_hashes = module_hashes.join(",\n"),
+        wasm_instances = RuntimeGlobals::WASM_INSTANCES,
+        ensure_chunk_handlers = RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+        load_binary_code = load_binary_code.trim_end_matches(';'),
+      ))
+      .boxed(),
+    )
+  }
+
+  fn stage(&self) -> RuntimeModuleStage {
+    RuntimeModuleStage::Attach
+  }
+}
+
+/// The chunk itself and all async chunks reachable from it.
+fn get_all_wasm_chunks(compilation: &Compilation, chunk_ukey: &ChunkUkey) -> Vec<ChunkUkey> {
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  std::iter::once(*chunk_ukey)
+    .chain(chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey))
+    .collect()
+}
+
+/// Whether the chunk or any of its async chunks contains a `webassembly/sync` module.
+pub(crate) fn has_sync_wasm_module_in_graph(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+) -> bool {
+  let module_graph = compilation.get_module_graph();
+  get_all_wasm_chunks(compilation, chunk_ukey)
+    .iter()
+    .any(|chunk_ukey| {
+      compilation
+        .chunk_graph
+        .get_chunk_modules_by_source_type(chunk_ukey, SourceType::Wasm, &module_graph)
+        .iter()
+        .any(|m| *m.module_type() == ModuleType::WasmSync)
+    })
+}
+
+/// Generate the factory of the import object passed to `WebAssembly.instantiate` for the module.
+///
+/// Imports from javascript modules are wrapped in functions which read the exports lazily,
+/// imports which need the instance of another wasm module wait for that instance.
+fn generate_import_object(
+  compilation: &Compilation,
+  module_id: &str,
+  module: &BoxModule,
+  chunk: &rspack_core::Chunk,
+  declarations: &mut Vec<String>,
+) -> String {
+  let module_graph = compilation.get_module_graph();
+  let chunk_graph = &compilation.chunk_graph;
+
+  let mut wait_for_instances = IndexMap::<String, &str>::new();
+  let mut properties_by_request = IndexMap::<&str, Vec<String>>::new();
+  for dep in module
+    .get_dependencies()
+    .iter()
+    .filter_map(|id| module_graph.dependency_by_id(id))
+    .filter(|dep| dep.dependency_type() == &DependencyType::WasmImport)
+  {
+    let Some(imported_module) = module_graph.get_module_by_dependency_id(dep.id()) else {
+      continue;
+    };
+    let Some(dep) = dep.as_any().downcast_ref::<WasmImportDependency>() else {
+      continue;
+    };
+    let Some(imported_module_id) = chunk_graph.get_module_id(imported_module.identifier()) else {
+      continue;
+    };
+    let used_name = match module_graph
+      .get_exports_info(&imported_module.identifier())
+      .get_used_name(
+        &module_graph,
+        Some(&chunk.runtime),
+        UsedName::Str(dep.name().into()),
+      ) {
+      Some(UsedName::Str(used_name)) => used_name.to_string(),
+      _ => dep.name().to_string(),
+    };
+    let used_name = serde_json::to_string(&used_name).expect("should be valid json");
+
+    let value = if dep.only_direct_import() {
+      let instance_var = format!("m{}", itoa!(wait_for_instances.len()));
+      let value = format!("{instance_var}[{used_name}]");
+      wait_for_instances.insert(instance_var, imported_module_id);
+      value
+    } else {
+      let module_cache = format!(
+        "{}[{}]",
+        RuntimeGlobals::MODULE_CACHE,
+        serde_json::to_string(imported_module_id).expect("should be valid json")
+      );
+      let cache = format!("wasmImportedFuncCache{}", itoa!(declarations.len()));
+      declarations.push(format!("var {cache};"));
+      let direct_export = if imported_module.module_type().is_wasm_like() {
+        format!("{module_cache} ? {module_cache}.exports[{used_name}] : ")
+      } else {
+        String::new()
+      };
+      format!(
+        r#"{direct_export}function() {{
+      if({cache} === undefined) {cache} = {module_cache}.exports;
+      return {cache}[{used_name}].apply({cache}, arguments);
+    }}"#
+      )
+    };
+    properties_by_request
+      .entry(dep.request())
+      .or_default()
+      .push(format!(
+        "{}: {value}",
+        serde_json::to_string(dep.name()).expect("should be valid json")
+      ));
+  }
+
+  let import_object = properties_by_request
+    .into_iter()
+    .map(|(request, properties)| {
+      format!(
+        "{}: {{\n    {}\n  }}",
+        serde_json::to_string(request).expect("should be valid json"),
+        properties.join(",\n    ")
+      )
+    })
+    .collect::<Vec<_>>()
+    .join(",\n");
+  let module_id = serde_json::to_string(module_id).expect("should be valid json");
+
+  if wait_for_instances.is_empty() {
+    format!("{module_id}: function() {{\n  return {{\n{import_object}\n  }};\n}}")
+  } else {
+    let promises = wait_for_instances
+      .values()
+      .map(|id| {
+        format!(
+          "installedWasmModules[{}]",
+          serde_json::to_string(id).expect("should be valid json")
+        )
+      })
+      .collect::<Vec<_>>()
+      .join(", ");
+    let variables = wait_for_instances
+      .keys()
+      .enumerate()
+      .map(|(i, name)| format!("{name} = array[{}]", itoa!(i)))
+      .collect::<Vec<_>>()
+      .join(", ");
+    format!(
+      r#"{module_id}: function() {{
+  return promiseResolve().then(function() {{ return Promise.all([{promises}]); }}).then(function(array) {{
+    var {variables};
+    return {{
+{import_object}
+    }};
+  }});
+}}"#
+    )
+  }
+}
@@ -0,0 +1,208 @@
+use std::borrow::Cow;
+
+use indexmap::IndexMap;
+use rspack_core::rspack_sources::{BoxSource, RawSource, Source, SourceExt};
+use rspack_core::DependencyType::WasmImport;
+use rspack_core::{
+  BoxDependency, BuildMetaExportsType, GenerateContext, Module, ModuleIdentifier, ParseContext,
+  ParseResult, ParserAndGenerator, RuntimeGlobals, SourceType, StaticExportsDependency,
+  StaticExportsSpec,
+};
+use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_util::itoa;
+use swc_core::atoms::Atom;
+use wasmparser::{Import, Parser, Payload, TypeRef};
+
+use crate::dependency::WasmImportDependency;
+use crate::{
+  hash_for_source, render_import_stmt, render_wasm_name, ModuleIdToFileName, WASM_SOURCE_TYPE,
+};
+
+/// Parser and generator for `webassembly/sync` modules.
+///
+/// The wasm instance is created by the chunk loading runtime before the chunk is executed,
+/// so the generated javascript only picks up the instance exports from `__webpack_require__.w`.
+#[derive(Debug)]
+pub struct SyncWasmParserAndGenerator {
+  pub(crate) module_id_to_filename: ModuleIdToFileName,
+}
+
+impl ParserAndGenerator for SyncWasmParserAndGenerator {
+  fn source_types(&self) -> &[SourceType] {
+    WASM_SOURCE_TYPE
+  }
+
+  fn parse(&mut self, parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
+    parse_context.build_info.strict = true;
+    parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
+
+    let source = parse_context.source;
+
+    let mut exports = Vec::with_capacity(1);
+    let mut dependencies: Vec<BoxDependency> = Vec::with_capacity(1);
+    let mut diagnostic = Vec::with_capacity(1);
+
+    for payload in Parser::new(0).parse_all(&source.buffer()) {
+      match payload {
+        Ok(payload) => match payload {
+          Payload::ExportSection(s) => {
+            for export in s {
+              match export {
+                Ok(export) => exports.push(export.name.to_string()),
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Export Parse Error".into(),
+                  err.to_string(),
+                )),
+              };
+            }
+          }
+          Payload::ImportSection(s) => {
+            for import in s {
+              match import {
+                Ok(Import { module, name, ty }) => {
+                  dependencies.push(Box::new(WasmImportDependency::new(
+                    module.into(),
+                    name.into(),
+                    ty,
+                    only_direct_import(&ty),
+                  )));
+                }
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Import Parse Error".into(),
+                  err.to_string(),
+                )),
+              }
+            }
+          }
+          _ => {}
+        },
+        Err(err) => {
+          diagnostic.push(Diagnostic::error(
+            "Wasm Parse Error".into(),
+            err.to_string(),
+          ));
+        }
+      }
+    }
+
+    dependencies.push(Box::new(StaticExportsDependency::new(
+      StaticExportsSpec::Array(exports.iter().cloned().map(Atom::from).collect::<Vec<_>>()),
+      false,
+    )));
+
+    Ok(
+      ParseResult {
+        dependencies,
+        blocks: vec![],
+        presentational_dependencies: vec![],
+        code_generation_dependencies: vec![],
+        source,
+        side_effects_bailout: None,
+      }
+      .with_diagnostic(diagnostic),
+    )
+  }
+
+  fn size(&self, module: &dyn Module, source_type: Option<&SourceType>) -> f64 {
+    match source_type.unwrap_or(&SourceType::Wasm) {
+      SourceType::JavaScript => {
+        95.0 + module.get_dependencies().len().saturating_sub(1) as f64 * 20.0
+      }
+      SourceType::Wasm => module.original_source().map_or(0, |source| source.size()) as f64,
+      _ => 0.0,
+    }
+  }
+
+  fn generate(
+    &self,
+    source: &BoxSource,
+    module: &dyn Module,
+    generate_context: &mut GenerateContext,
+  ) -> Result<BoxSource> {
+    let compilation = generate_context.compilation;
+    let wasm_filename_template = &compilation.options.output.webassembly_module_filename;
+    let hash = hash_for_source(source.as_ref());
+    let normal_module = module
+      .as_normal_module()
+      .expect("module should be a NormalModule in SyncWasmParserAndGenerator::generate");
+    let wasm_path_with_info =
+      render_wasm_name(compilation, normal_module, wasm_filename_template, &hash);
+
+    self
+      .module_id_to_filename
+      .insert(module.identifier(), wasm_path_with_info);
+
+    match generate_context.requested_source_type {
+      SourceType::JavaScript => {
+        let runtime_requirements = &mut generate_context.runtime_requirements;
+        runtime_requirements.insert(RuntimeGlobals::MODULE);
+        runtime_requirements.insert(RuntimeGlobals::MODULE_ID);
+        runtime_requirements.insert(RuntimeGlobals::WASM_INSTANCES);
+
+        let module_graph = &compilation.get_module_graph();
+        let chunk_graph = &compilation.chunk_graph;
+
+        // the imported modules are required after the instance is picked up, so the
+        // execution order of the esm graph is kept
+        let mut dep_modules = IndexMap::<ModuleIdentifier, (String, &str)>::new();
+        for dep in module
+          .get_dependencies()
+          .iter()
+          .filter_map(|id| module_graph.dependency_by_id(id))
+          .filter(|dep| dep.dependency_type() == &WasmImport)
+        {
+          if let Some(mgm) = module_graph.module_graph_module_by_dependency_id(dep.id())
+            && !dep_modules.contains_key(&mgm.module_identifier)
+          {
+            let import_var = format!("WEBPACK_IMPORTED_MODULE_{}", itoa!(dep_modules.len()));
+            dep_modules.insert(mgm.module_identifier, (import_var, mgm.id(chunk_graph)));
+          }
+        }
+
+        let imports_code = dep_modules
+          .values()
+          .map(|(import_var, module_id)| render_import_stmt(import_var, module_id))
+          .collect::<Vec<_>>()
+          .join("");
+
+        Ok(
+          RawSource::from(format!(
+            r#""use strict";
+// Instantiate WebAssembly module
+var wasmExports = {}[module.id];
+
+// export exports from WebAssembly module
+module.exports = wasmExports;
+
+// exec imports from WebAssembly module (for esm order)
+{imports_code}"#,
+            RuntimeGlobals::WASM_INSTANCES
+          ))
+          .boxed(),
+        )
+      }
+      _ => Ok(source.clone()),
+    }
+  }
+
+  fn get_concatenation_bailout_reason(
+    &self,
+    _module: &dyn Module,
+    _mg: &rspack_core::ModuleGraph,
+    _cg: &rspack_core::ChunkGraph,
+  ) -> Option<Cow<'static, str>> {
+    Some("Module Concatenation is not implemented for SyncWasmParserAndGenerator".into())
+  }
+}
+
+/// The instance is created before any javascript module of the chunk is executed,
+/// so only functions can be imported lazily from javascript modules.
+fn only_direct_import(ty: &TypeRef) -> Option<String> {
+  match ty {
+    TypeRef::Func(_) => None,
+    TypeRef::Memory(_) => Some("Memory".to_string()),
+    TypeRef::Table(_) => Some("Table".to_string()),
+    TypeRef::Global(_) => Some("Global".to_string()),
+    TypeRef::Tag(_) => Some("Tag".to_string()),
+  }
+}
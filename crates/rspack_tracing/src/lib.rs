@@ -13,6 +13,55 @@ use tracing_subscriber::{EnvFilter, Layer};
 
 pub mod chrome {
   pub use tracing_chrome::FlushGuard;
+
+  /// An async event of the Chrome trace event format, which can be loaded by `chrome://tracing`
+  /// or Perfetto.
+  ///
+  /// Events are matched by their id instead of being nested by thread, as events recorded by
+  /// concurrent tasks may overlap without nesting.
+  #[derive(Debug, Clone)]
+  pub struct TraceEvent {
+    pub id: u64,
+    pub name: String,
+    pub category: String,
+    /// the start timestamp in microseconds
+    pub ts: f64,
+    /// the duration in microseconds
+    pub dur: f64,
+    pub args: serde_json::Map<String, serde_json::Value>,
+  }
+
+  /// Serializes the events to the JSON object format of the Chrome trace event format,
+  /// every event is written as a pair of begin and end events.
+  pub fn to_trace_json(events: &[TraceEvent]) -> String {
+    let events = events
+      .iter()
+      .flat_map(|event| {
+        [
+          serde_json::json!({
+            "name": event.name,
+            "cat": event.category,
+            "ph": "b",
+            "id": event.id,
+            "ts": event.ts,
+            "pid": 1,
+            "tid": 1,
+            "args": event.args,
+          }),
+          serde_json::json!({
+            "name": event.name,
+            "cat": event.category,
+            "ph": "e",
+            "id": event.id,
+            "ts": event.ts + event.dur,
+            "pid": 1,
+            "tid": 1,
+          }),
+        ]
+      })
+      .collect::<Vec<_>>();
+    serde_json::json!({ "traceEvents": events }).to_string()
+  }
 }
 
 static IS_TRACING_ENABLED: AtomicBool = AtomicBool::new(false);
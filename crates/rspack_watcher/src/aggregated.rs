@@ -0,0 +1,55 @@
+use std::path::PathBuf;
+
+use rustc_hash::FxHashSet as HashSet;
+
+/// The watched paths changed or removed during one aggregation.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FsEventsAggregated {
+  pub changed: HashSet<PathBuf>,
+  pub removed: HashSet<PathBuf>,
+}
+
+impl FsEventsAggregated {
+  pub fn is_empty(&self) -> bool {
+    self.changed.is_empty() && self.removed.is_empty()
+  }
+
+  /// Merges later changes into self, a path is only kept in the set it was seen in last.
+  pub fn extend(&mut self, later: FsEventsAggregated) {
+    for path in later.changed {
+      self.removed.remove(&path);
+      self.changed.insert(path);
+    }
+    for path in later.removed {
+      self.changed.remove(&path);
+      self.removed.insert(path);
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_extend() {
+    let mut aggregated = FsEventsAggregated::default();
+    aggregated.changed.insert(PathBuf::from("/a.js"));
+    aggregated.removed.insert(PathBuf::from("/b.js"));
+
+    let mut later = FsEventsAggregated::default();
+    later.changed.insert(PathBuf::from("/b.js"));
+    later.removed.insert(PathBuf::from("/a.js"));
+    later.removed.insert(PathBuf::from("/c.js"));
+    aggregated.extend(later);
+
+    assert_eq!(
+      aggregated.changed,
+      HashSet::from_iter([PathBuf::from("/b.js")])
+    );
+    assert_eq!(
+      aggregated.removed,
+      HashSet::from_iter([PathBuf::from("/a.js"), PathBuf::from("/c.js")])
+    );
+  }
+}
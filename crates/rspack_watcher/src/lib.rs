@@ -0,0 +1,7 @@
+mod aggregated;
+mod options;
+mod watcher;
+
+pub use aggregated::FsEventsAggregated;
+pub use options::FsWatcherOptions;
+pub use watcher::FsWatcher;
@@ -0,0 +1,25 @@
+use std::time::Duration;
+
+use rspack_regex::RspackRegex;
+
+/// Options of the [crate::FsWatcher], the same as `watchOptions` of webpack.
+#[derive(Debug, Clone)]
+pub struct FsWatcherOptions {
+  /// Changes are aggregated until no change happened for this long.
+  pub aggregate_timeout: Duration,
+  /// Paths matching this regex are neither watched nor reported.
+  pub ignored: Option<RspackRegex>,
+  /// Poll the file system with this interval instead of using native events,
+  /// e.g. for network file systems.
+  pub poll_interval: Option<Duration>,
+}
+
+impl Default for FsWatcherOptions {
+  fn default() -> Self {
+    Self {
+      aggregate_timeout: Duration::from_millis(20),
+      ignored: None,
+      poll_interval: None,
+    }
+  }
+}
@@ -0,0 +1,355 @@
+use std::{
+  path::{Path, PathBuf},
+  sync::{mpsc, Arc, Mutex, Weak},
+  thread,
+};
+
+use notify::{Config, Event, EventKind, PollWatcher, RecommendedWatcher, RecursiveMode, Watcher};
+use rspack_error::{error, Result};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+use crate::{FsEventsAggregated, FsWatcherOptions};
+
+#[derive(Debug, Default)]
+struct WatchedPaths {
+  files: HashSet<PathBuf>,
+  directories: HashSet<PathBuf>,
+  missing: HashSet<PathBuf>,
+}
+
+impl WatchedPaths {
+  /// The directories to subscribe to.
+  ///
+  /// Files are watched through their parent directory, so replacing a file by renaming,
+  /// as many editors do, is still noticed. Missing paths are watched through their
+  /// nearest existing ancestor.
+  fn watch_targets(&self) -> HashMap<PathBuf, RecursiveMode> {
+    let mut targets = HashMap::default();
+    let mut add_target = |path: &Path, mode: RecursiveMode| {
+      let Some(directory) = path.ancestors().find(|ancestor| ancestor.is_dir()) else {
+        return;
+      };
+      let mode = if directory == path {
+        mode
+      } else {
+        RecursiveMode::NonRecursive
+      };
+      let target = targets
+        .entry(directory.to_path_buf())
+        .or_insert(RecursiveMode::NonRecursive);
+      if mode == RecursiveMode::Recursive {
+        *target = mode;
+      }
+    };
+
+    let parents = self
+      .files
+      .iter()
+      .chain(&self.missing)
+      .filter_map(|path| path.parent())
+      .collect::<HashSet<_>>();
+    for parent in parents {
+      add_target(parent, RecursiveMode::NonRecursive);
+    }
+    for directory in &self.directories {
+      add_target(directory, RecursiveMode::Recursive);
+    }
+    targets
+  }
+
+  /// Collects the watched paths affected by a change of `path`.
+  fn collect_affected(&self, path: &Path, affected: &mut HashSet<PathBuf>) {
+    if self.files.contains(path) || self.missing.contains(path) {
+      affected.insert(path.to_path_buf());
+    }
+    for directory in &self.directories {
+      if path.starts_with(directory) {
+        affected.insert(directory.clone());
+      }
+    }
+    // an ancestor of a missing path is created
+    for missing in &self.missing {
+      if missing.starts_with(path) && missing.exists() {
+        affected.insert(missing.clone());
+      }
+    }
+  }
+
+  fn all(&self) -> impl Iterator<Item = &PathBuf> {
+    self
+      .files
+      .iter()
+      .chain(&self.directories)
+      .chain(&self.missing)
+  }
+}
+
+struct State {
+  watcher: Box<dyn Watcher + Send>,
+  paths: WatchedPaths,
+  targets: HashMap<PathBuf, RecursiveMode>,
+}
+
+impl State {
+  fn update_targets(&mut self) -> Result<()> {
+    let mut targets = self.paths.watch_targets();
+    for (path, mode) in &self.targets {
+      if targets.get(path) != Some(mode) {
+        // the directory may be removed already
+        let _ = self.watcher.unwatch(path);
+      }
+    }
+
+    let mut result = Ok(());
+    targets.retain(|path, mode| {
+      if self.targets.get(path) == Some(mode) {
+        return true;
+      }
+      match self.watcher.watch(path, *mode) {
+        Ok(()) => true,
+        Err(err) => {
+          result = Err(error!("Failed to watch {}: {err}", path.display()));
+          false
+        }
+      }
+    });
+    self.targets = targets;
+    result
+  }
+}
+
+/// Watches files, directories and missing paths, and reports their changes aggregated
+/// by `aggregate_timeout`.
+///
+/// Native file system events are used by default, e.g. inotify on linux.
+pub struct FsWatcher {
+  options: FsWatcherOptions,
+  state: Arc<Mutex<State>>,
+  receiver: UnboundedReceiver<FsEventsAggregated>,
+}
+
+impl std::fmt::Debug for FsWatcher {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("FsWatcher")
+      .field("options", &self.options)
+      .finish()
+  }
+}
+
+impl FsWatcher {
+  pub fn new(options: FsWatcherOptions) -> Result<Self> {
+    let (event_sender, event_receiver) = mpsc::channel();
+    let handler = move |event: notify::Result<Event>| {
+      let _ = event_sender.send(event);
+    };
+    let watcher: Box<dyn Watcher + Send> = match options.poll_interval {
+      Some(interval) => Box::new(
+        PollWatcher::new(handler, Config::default().with_poll_interval(interval))
+          .map_err(|err| error!("Failed to create watcher: {err}"))?,
+      ),
+      None => Box::new(
+        RecommendedWatcher::new(handler, Config::default())
+          .map_err(|err| error!("Failed to create watcher: {err}"))?,
+      ),
+    };
+
+    let state = Arc::new(Mutex::new(State {
+      watcher,
+      paths: Default::default(),
+      targets: Default::default(),
+    }));
+    let (sender, receiver) = unbounded_channel();
+    let aggregator = Aggregator {
+      options: options.clone(),
+      state: Arc::downgrade(&state),
+      sender,
+    };
+    thread::spawn(move || aggregator.run(event_receiver));
+
+    Ok(Self {
+      options,
+      state,
+      receiver,
+    })
+  }
+
+  /// Watches the given paths, replacing the paths of the last call.
+  ///
+  /// Changes of `directories` are reported as changes of the directory itself,
+  /// `missing` paths are reported once they are created.
+  pub fn watch(
+    &mut self,
+    files: impl IntoIterator<Item = PathBuf>,
+    directories: impl IntoIterator<Item = PathBuf>,
+    missing: impl IntoIterator<Item = PathBuf>,
+  ) -> Result<()> {
+    let is_watched = |path: &PathBuf| !is_ignored(&self.options, path);
+    let paths = WatchedPaths {
+      files: files.into_iter().filter(is_watched).collect(),
+      directories: directories.into_iter().filter(is_watched).collect(),
+      missing: missing.into_iter().filter(is_watched).collect(),
+    };
+    let mut state = self.state.lock().expect("should lock watcher state");
+    state.paths = paths;
+    state.update_targets()
+  }
+
+  /// Waits for the next changes of the watched paths.
+  ///
+  /// Changes that are already queued, e.g. during a rebuild, are merged into one result.
+  /// Returns `None` once the watcher can no longer receive events.
+  pub async fn wait(&mut self) -> Option<FsEventsAggregated> {
+    let mut aggregated = self.receiver.recv().await?;
+    while let Ok(later) = self.receiver.try_recv() {
+      aggregated.extend(later);
+    }
+    Some(aggregated)
+  }
+}
+
+fn is_ignored(options: &FsWatcherOptions, path: &Path) -> bool {
+  options
+    .ignored
+    .as_ref()
+    .is_some_and(|ignored| ignored.test(&path.to_string_lossy()))
+}
+
+struct Aggregator {
+  options: FsWatcherOptions,
+  state: Weak<Mutex<State>>,
+  sender: UnboundedSender<FsEventsAggregated>,
+}
+
+impl Aggregator {
+  fn run(self, event_receiver: mpsc::Receiver<notify::Result<Event>>) {
+    // the receiver is disconnected once the watcher is dropped
+    while let Ok(event) = event_receiver.recv() {
+      let mut events = vec![event];
+      loop {
+        match event_receiver.recv_timeout(self.options.aggregate_timeout) {
+          Ok(event) => events.push(event),
+          Err(mpsc::RecvTimeoutError::Timeout) => break,
+          Err(mpsc::RecvTimeoutError::Disconnected) => return,
+        }
+      }
+
+      let Some(state) = self.state.upgrade() else {
+        return;
+      };
+      let aggregated = {
+        let mut state = state.lock().expect("should lock watcher state");
+        let aggregated = self.aggregate(&state.paths, events);
+        // directories may be created or removed, e.g. an ancestor of a missing path
+        if let Err(err) = state.update_targets() {
+          tracing::warn!("{err}");
+        }
+        aggregated
+      };
+      if !aggregated.is_empty() && self.sender.send(aggregated).is_err() {
+        return;
+      }
+    }
+  }
+
+  fn aggregate(
+    &self,
+    paths: &WatchedPaths,
+    events: Vec<notify::Result<Event>>,
+  ) -> FsEventsAggregated {
+    let mut affected = HashSet::default();
+    for event in events {
+      match event {
+        Ok(event) if event.need_rescan() => affected.extend(paths.all().cloned()),
+        Ok(event) => {
+          if matches!(event.kind, EventKind::Access(_)) {
+            continue;
+          }
+          for path in &event.paths {
+            if !is_ignored(&self.options, path) {
+              paths.collect_affected(path, &mut affected);
+            }
+          }
+        }
+        Err(err) => {
+          // events may be lost, check all the watched paths
+          tracing::warn!("watcher error: {err}");
+          affected.extend(paths.all().cloned());
+        }
+      }
+    }
+
+    let mut aggregated = FsEventsAggregated::default();
+    for path in affected {
+      if path.exists() {
+        aggregated.changed.insert(path);
+      } else if !paths.missing.contains(&path) {
+        // a missing path is only reported once it is created
+        aggregated.removed.insert(path);
+      }
+    }
+    aggregated
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::{fs, time::Duration};
+
+  use super::*;
+
+  #[tokio::test]
+  async fn test_watch_changes() {
+    let dir = std::env::temp_dir().join(format!("rspack_watcher_test_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("context")).expect("should create dir");
+    let file = dir.join("a.js");
+    let removed_file = dir.join("b.js");
+    let missing = dir.join("missing/c.js");
+    fs::write(&file, "a").expect("should write file");
+    fs::write(&removed_file, "b").expect("should write file");
+
+    let mut watcher = FsWatcher::new(Default::default()).expect("should create watcher");
+    watcher
+      .watch(
+        [file.clone(), removed_file.clone()],
+        [dir.join("context")],
+        [missing.clone()],
+      )
+      .expect("should watch");
+
+    fs::write(&file, "aa").expect("should write file");
+    fs::remove_file(&removed_file).expect("should remove file");
+    fs::write(dir.join("context/d.js"), "d").expect("should write file");
+    fs::write(dir.join("unwatched.js"), "e").expect("should write file");
+
+    let mut aggregated = FsEventsAggregated::default();
+    while aggregated.changed.len() < 2 || aggregated.removed.is_empty() {
+      let later = tokio::time::timeout(Duration::from_secs(5), watcher.wait())
+        .await
+        .expect("should receive changes")
+        .expect("should watch");
+      aggregated.extend(later);
+    }
+    assert_eq!(
+      aggregated.changed,
+      HashSet::from_iter([file.clone(), dir.join("context")])
+    );
+    assert_eq!(aggregated.removed, HashSet::from_iter([removed_file]));
+
+    // the missing path is watched through its ancestor, even when the parent is created later
+    fs::create_dir(dir.join("missing")).expect("should create dir");
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    fs::write(&missing, "c").expect("should write file");
+    let mut aggregated = FsEventsAggregated::default();
+    while !aggregated.changed.contains(&missing) {
+      let later = tokio::time::timeout(Duration::from_secs(5), watcher.wait())
+        .await
+        .expect("should receive changes")
+        .expect("should watch");
+      aggregated.extend(later);
+    }
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+}
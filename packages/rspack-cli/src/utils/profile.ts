@@ -25,6 +25,10 @@ The full syntax, remember update this when you change something in this file.
 
 `RSPACK_PROFILE=LOGGING rspack build`: only enable stats.logging, and use default options for stats.logging
 
+`RSPACK_PROFILE='HOOKS=output=./rspack.hooks' rspack build`: only enable the hook profiling, the tap calls are written in the chrome trace format
+
+`RSPACK_PROFILE=HOOKS rspack build`: only enable the hook profiling, and use default options for the hook profiling
+
 `RSPACK_PROFILE=ALL rspack build`: enable all, and use default options
 
 `RSPACK_PROFILE=[rspack_node,rspack_core] rspack build`: enable all, but customize trace filter
@@ -57,10 +61,15 @@ type LoggingOutputOptions = string;
 type LoggingOptions = {
 	output: LoggingOutputOptions;
 };
+type HooksOutputOptions = string;
+type HooksOptions = {
+	output: HooksOutputOptions;
+};
 type ProfileOptions = {
 	TRACE?: RustTraceOptions;
 	JSCPU?: JSCPUProfileOptions;
 	LOGGING?: LoggingOptions;
+	HOOKS?: HooksOptions;
 };
 
 const timestamp = Date.now();
@@ -79,6 +88,7 @@ const defaultRustTraceLoggerOutput = "stdout";
 const defaultRustTraceFilter = "trace";
 const defaultRustTraceLayer = "chrome";
 const defaultLoggingOutput = path.join(defaultOutputDirname, "./logging.json");
+const defaultHooksOutput = path.join(defaultOutputDirname, "./hooks.json");
 
 function resolveProfile(value: string): ProfileOptions {
 	if (value.toUpperCase() === "ALL") {
@@ -89,14 +99,16 @@ function resolveProfile(value: string): ProfileOptions {
 				output: defaultRustTraceChromeOutput
 			},
 			JSCPU: { output: defaultJSCPUProfileOutput },
-			LOGGING: { output: defaultLoggingOutput }
+			LOGGING: { output: defaultLoggingOutput },
+			HOOKS: { output: defaultHooksOutput }
 		};
 	}
 	if (value.startsWith("[") && value.endsWith("]")) {
 		return {
 			TRACE: resolveRustTraceOptions(value.slice(1, value.length - 1)),
 			JSCPU: { output: defaultJSCPUProfileOutput },
-			LOGGING: { output: defaultLoggingOutput }
+			LOGGING: { output: defaultLoggingOutput },
+			HOOKS: { output: defaultHooksOutput }
 		};
 	}
 	return value.split("|").reduce<ProfileOptions>((acc, cur) => {
@@ -107,6 +119,8 @@ function resolveProfile(value: string): ProfileOptions {
 			acc.JSCPU = resolveJSCPUProfileOptions(cur.slice(6));
 		} else if (upperCur.startsWith("LOGGING")) {
 			acc.LOGGING = resolveLoggingOptions(cur.slice(8));
+		} else if (upperCur.startsWith("HOOKS")) {
+			acc.HOOKS = resolveHooksOptions(cur.slice(6));
 		}
 		return acc;
 	}, {});
@@ -164,6 +178,17 @@ function resolveLoggingOptions(value: string): LoggingOptions {
 	return { output: value || defaultLoggingOutput };
 }
 
+// HOOKS=value
+function resolveHooksOptions(value: string): HooksOptions {
+	// output=filepath
+	if (value.includes("=")) {
+		const parsed = new URLSearchParams(value);
+		return { output: parsed.get("output") || defaultHooksOutput };
+	}
+	// filepath
+	return { output: value || defaultHooksOutput };
+}
+
 class RspackProfileJSCPUProfilePlugin {
 	constructor(private output: string) {}
 
@@ -209,6 +234,21 @@ class RspackProfileLoggingPlugin {
 	}
 }
 
+class RspackProfileHooksPlugin {
+	constructor(private output: string) {}
+
+	apply(compiler: Compiler) {
+		compiler.hooks.done.tapAsync(
+			RspackProfileHooksPlugin.name,
+			(stats, callback) => {
+				if (compiler.watchMode) return callback();
+				fs.writeFileSync(this.output, stats.compilation.getHookProfileTrace());
+				return callback();
+			}
+		);
+	}
+}
+
 export async function applyProfile(profileValue: string, item: RspackOptions) {
 	const { default: exitHook } = await dynamicImport("exit-hook");
 	const entries = Object.entries(resolveProfile(profileValue));
@@ -229,6 +269,10 @@ export async function applyProfile(profileValue: string, item: RspackOptions) {
 			);
 		} else if (kind === "LOGGING") {
 			(item.plugins ??= []).push(new RspackProfileLoggingPlugin(value.output));
+		} else if (kind === "HOOKS") {
+			// the tap calls are only recorded when profile is enabled
+			item.profile = true;
+			(item.plugins ??= []).push(new RspackProfileHooksPlugin(value.output));
 		}
 	}
 }
@@ -5,6 +5,7 @@ import { run } from "../../utils/test-utils";
 const defaultTracePath = "./trace.json";
 const defaultJSCPUPath = "./jscpuprofile.json";
 const defaultLoggingPath = "./logging.json";
+const defaultHooksPath = "./hooks.json";
 const customTracePath = "./custom/trace";
 const customJSCPUPath = "./custom/jscpuprofile";
 const customLoggingPath = "./custom/logging";
@@ -47,6 +48,7 @@ describe("profile", () => {
 		expect(fs.existsSync(resolve(dirname, defaultTracePath))).toBeTruthy();
 		expect(fs.existsSync(resolve(dirname, defaultJSCPUPath))).toBeTruthy();
 		expect(fs.existsSync(resolve(dirname, defaultLoggingPath))).toBeTruthy();
+		expect(fs.existsSync(resolve(dirname, defaultHooksPath))).toBeTruthy();
 	});
 
 	it("should store js cpu profile file when RSPACK_PROFILE=JSCPU enabled", async () => {
@@ -85,6 +87,21 @@ describe("profile", () => {
 		expect(fs.existsSync(resolve(dirname, defaultLoggingPath))).toBeTruthy();
 	});
 
+	it("should store hook profile file when RSPACK_PROFILE=HOOKS enabled", async () => {
+		const { exitCode } = await run(
+			__dirname,
+			[],
+			{},
+			{ RSPACK_PROFILE: "HOOKS" }
+		);
+		expect(exitCode).toBe(0);
+		const dirname = findDefaultOutputDirname();
+		const hooks = resolve(dirname, defaultHooksPath);
+		expect(fs.existsSync(hooks)).toBeTruthy();
+		const { traceEvents } = JSON.parse(fs.readFileSync(hooks, "utf-8"));
+		expect(traceEvents.length).toBeGreaterThan(0);
+	});
+
 	it("should filter trace event when use RSPACK_PROFILE=[crate1,crate2]", async () => {
 		const { exitCode } = await run(
 			__dirname,
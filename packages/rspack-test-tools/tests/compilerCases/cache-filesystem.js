@@ -0,0 +1,60 @@
+const fs = require("fs");
+const rimraf = require("rimraf");
+const { rspack } = require("@rspack/core");
+
+function options(context) {
+	return {
+		context: context.getSource(),
+		entry: "./d",
+		mode: "development",
+		devtool: false,
+		output: {
+			path: context.getDist(),
+			filename: "main.js"
+		},
+		cache: {
+			type: "filesystem",
+			cacheDirectory: context.getDist(".cache")
+		},
+		module: {
+			rules: [
+				{
+					test: /d\.js$/,
+					use: [{ loader: context.getSource("count-loader.js") }]
+				}
+			]
+		}
+	};
+}
+
+function run(compiler) {
+	return new Promise((resolve, reject) => {
+		compiler.run(err => {
+			if (err) {
+				return reject(err);
+			}
+			compiler.close(() => resolve());
+		});
+	});
+}
+
+/** @type {import('../..').TCompilerCaseConfig} */
+module.exports = {
+	description: "should restore loader results from the cache directory in a new compiler",
+	options,
+	async build(context, compiler) {
+		rimraf.sync(context.getDist());
+		await run(compiler);
+		// the second compiler has an empty memory, it can only read the cache directory
+		await run(rspack(options(context)));
+	},
+	async check(context) {
+		expect(
+			fs.existsSync(context.getDist(".cache/default/build-module.pack"))
+		).toBe(true);
+		// `count-loader` returns 1 when it runs again
+		expect(fs.readFileSync(context.getDist("main.js"), "utf-8")).toContain(
+			"module.exports = 0;"
+		);
+	}
+};
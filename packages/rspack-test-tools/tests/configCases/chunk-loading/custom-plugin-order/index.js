@@ -0,0 +1,17 @@
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should load async chunks with a custom chunk loading type", async () => {
+	const { default: value } = await import(
+		/* webpackChunkName: "async" */ "./async"
+	);
+	expect(value).toBe("async");
+});
+
+it("should use the runtime of the custom chunk loading type", () => {
+	const source = fs.readFileSync(path.join(__dirname, "bundle0.js"), "utf-8");
+	// split the strings to not match the test code itself
+	const type = ["custom", "host"].join("-");
+	expect(source).toContain(`.f["${type}"] = function`);
+	expect(source).not.toContain(".f." + "require = function");
+});
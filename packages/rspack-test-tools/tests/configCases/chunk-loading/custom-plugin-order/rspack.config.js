@@ -0,0 +1,27 @@
+const { javascript } = require("@rspack/core");
+
+class CustomHostPlugin {
+	apply(compiler) {
+		// enable the type before the plugin providing it is applied
+		new javascript.EnableChunkLoadingPlugin("custom-host").apply(compiler);
+		new javascript.CustomChunkLoadingPlugin({
+			type: "custom-host",
+			loadChunk: `function (chunkId, filename) {
+				return Promise.resolve(require("./" + filename));
+			}`
+		}).apply(compiler);
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	node: {
+		__dirname: false
+	},
+	output: {
+		chunkLoading: "custom-host",
+		chunkFormat: "commonjs"
+	},
+	plugins: [new CustomHostPlugin()]
+};
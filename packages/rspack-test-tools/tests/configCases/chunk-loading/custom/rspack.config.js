@@ -0,0 +1,21 @@
+const { javascript } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	node: {
+		__dirname: false
+	},
+	output: {
+		chunkLoading: "custom-host",
+		chunkFormat: "commonjs"
+	},
+	plugins: [
+		new javascript.CustomChunkLoadingPlugin({
+			type: "custom-host",
+			loadChunk: `function (chunkId, filename) {
+				return Promise.resolve(require("./" + filename));
+			}`
+		})
+	]
+};
@@ -0,0 +1,39 @@
+it("should load the blocks nested in a require.ensure", done => {
+	require.ensure(
+		[],
+		function () {
+			const name = "a";
+			Promise.all([
+				import(/* webpackChunkName: "dynamic" */ "./dynamic"),
+				import(/* webpackChunkName: "context-[request]" */ `./dir/${name}`)
+			]).then(([dynamic, context]) => {
+				expect(dynamic.default).toBe("dynamic");
+				expect(context.default).toBe("a");
+				done();
+			}, done);
+		},
+		"ensure"
+	);
+});
+
+it("should create the chunk groups of nested blocks as children of the outer block", () => {
+	const { chunks } = __STATS__;
+	const byName = name => chunks.find(chunk => chunk.names.includes(name));
+	const main = byName("main");
+	const ensure = byName("ensure");
+	const dynamic = byName("dynamic");
+	const contextChunks = chunks.filter(chunk =>
+		chunk.names.some(name => name.startsWith("context-"))
+	);
+
+	expect(main.children).toEqual([ensure.id]);
+	expect(ensure.parents).toEqual([main.id]);
+	expect(dynamic.parents).toEqual([ensure.id]);
+	expect(contextChunks).toHaveLength(2);
+	for (const chunk of contextChunks) {
+		expect(chunk.parents).toEqual([ensure.id]);
+	}
+	expect(ensure.children.sort()).toEqual(
+		[dynamic.id, ...contextChunks.map(chunk => chunk.id)].sort()
+	);
+});
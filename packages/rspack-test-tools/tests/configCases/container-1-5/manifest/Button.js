@@ -0,0 +1,5 @@
+import React from "react";
+
+export default () => {
+	return `Button rendered with [${React()}]`;
+};
@@ -0,0 +1,33 @@
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+const readJson = file =>
+	JSON.parse(fs.readFileSync(path.resolve(__dirname, file), "utf-8"));
+
+it("should load the exposed component", () => {
+	return import("./Button").then(({ default: Button }) => {
+		expect(Button()).toBe("Button rendered with [This is react 0.1.2]");
+	});
+});
+
+it("should emit mf-manifest.json", () => {
+	const manifest = readJson("mf-manifest.json");
+	expect(manifest.name).toBe("container");
+	expect(manifest.metaData.remoteEntry.name).toBe("container.js");
+	expect(manifest.exposes).toHaveLength(1);
+	expect(manifest.exposes[0].name).toBe("Button");
+	expect(manifest.exposes[0].path).toBe("./Button");
+	expect(manifest.exposes[0].assets.js.sync.length).toBeGreaterThan(0);
+	expect(manifest.exposes[0].file).toBeUndefined();
+	expect(manifest.shared).toHaveLength(1);
+	expect(manifest.shared[0].name).toBe("react");
+	expect(manifest.shared[0].version).toBe("0.1.2");
+	expect(manifest.shared[0].singleton).toBe(true);
+	expect(manifest.shared[0].usedIn).toBeUndefined();
+});
+
+it("should emit mf-stats.json", () => {
+	const stats = readJson("mf-stats.json");
+	expect(stats.exposes[0].file).toEqual(["./Button"]);
+	expect(stats.shared[0].usedIn).toContain("./Button.js");
+});
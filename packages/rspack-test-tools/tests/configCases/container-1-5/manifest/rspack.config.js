@@ -0,0 +1,26 @@
+const { ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		filename: "[name].js",
+		uniqueName: "manifest"
+	},
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "container",
+			filename: "container.js",
+			library: { type: "commonjs-module" },
+			exposes: {
+				"./Button": "./Button"
+			},
+			shared: {
+				react: {
+					version: "0.1.2",
+					singleton: true
+				}
+			},
+			manifest: true
+		})
+	]
+};
@@ -0,0 +1,10 @@
+require("./a.css");
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should ignore a cyclic @import with conditions", () => {
+	const css = fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+	expect(css.match(/\.a \{/g)).toHaveLength(1);
+	expect(css.match(/\.b \{/g)).toHaveLength(1);
+	expect(css).toMatch(/@layer x \{\s*\.b \{/);
+});
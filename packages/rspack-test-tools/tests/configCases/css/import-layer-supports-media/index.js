@@ -0,0 +1,39 @@
+require("./style.css");
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+const readCss = () =>
+	fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+
+it("should wrap imported css in @layer", () => {
+	const css = readCss();
+	expect(css).toMatch(/@layer base \{\s*\.layer \{/);
+	expect(css).toMatch(/@layer \{\s*\.anonymous-layer \{/);
+});
+
+it("should wrap imported css in @supports", () => {
+	expect(readCss()).toMatch(/@supports \(display: grid\) \{\s*\.supports \{/);
+});
+
+it("should wrap imported css in @media", () => {
+	expect(readCss()).toMatch(
+		/@media screen and \(min-width: 400px\) \{\s*\.media \{/
+	);
+});
+
+it("should nest layer, supports and media of one @import", () => {
+	expect(readCss()).toMatch(
+		/@layer all \{\s*@supports \(display: grid\) \{\s*@media print \{\s*\.all \{/
+	);
+});
+
+it("should create a module for every distinct @import chain", () => {
+	const css = readCss();
+	expect(css.match(/\.shared \{/g)).toHaveLength(3);
+	expect(css).toMatch(/@layer first \{\s*\.shared \{/);
+	expect(css).toMatch(/@media print \{\s*\.shared \{/);
+	expect(css).toMatch(
+		/@layer outer \{\s*@supports \(display: flex\) \{\s*\.shared \{/
+	);
+	expect(css).toMatch(/@layer outer \{\s*\.nested \{/);
+});
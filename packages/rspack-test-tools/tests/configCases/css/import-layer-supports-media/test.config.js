@@ -0,0 +1,6 @@
+module.exports = {
+	documentType: "fake",
+	findBundle() {
+		return ["bundle0.css", "bundle0.js"];
+	}
+};
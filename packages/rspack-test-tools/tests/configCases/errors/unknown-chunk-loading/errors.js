@@ -0,0 +1 @@
+module.exports = [[/Unsupported chunk loading type "unknown-host"/]];
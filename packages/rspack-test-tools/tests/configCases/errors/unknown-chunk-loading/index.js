@@ -0,0 +1 @@
+it("should compile with an unknown chunk loading type", () => {});
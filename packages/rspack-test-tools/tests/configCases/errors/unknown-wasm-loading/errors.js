@@ -0,0 +1 @@
+module.exports = [[/Unsupported wasm loading type "unknown-host"/]];
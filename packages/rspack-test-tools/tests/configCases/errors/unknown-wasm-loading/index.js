@@ -0,0 +1 @@
+it("should compile with an unknown wasm loading type", () => {});
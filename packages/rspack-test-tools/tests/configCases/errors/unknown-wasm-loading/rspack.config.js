@@ -0,0 +1,13 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: {
+			import: "./index.js",
+			wasmLoading: "unknown-host"
+		}
+	},
+	output: {
+		wasmLoading: "unknown-host",
+		workerWasmLoading: "unknown-host"
+	}
+};
@@ -0,0 +1,3 @@
+it("should build with the hook profiling enabled", () => {
+	expect(1).toBe(1);
+});
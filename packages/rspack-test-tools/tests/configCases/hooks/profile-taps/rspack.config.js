@@ -0,0 +1,42 @@
+const flatten = entries =>
+	entries.flatMap(entry => [entry, ...flatten(entry.children || [])]);
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	profile: true,
+	plugins: [
+		{
+			apply(compiler) {
+				compiler.hooks.make.tap("TestMakePlugin", () => {});
+				compiler.hooks.make.tap("OtherMakePlugin", () => {});
+				compiler.hooks.done.tap("Test", stats => {
+					const { logging } = stats.toJson({ all: false, logging: "verbose" });
+					const messages = flatten(logging["rspack.HookProfile"].entries).map(
+						entry => entry.message
+					);
+					expect(messages[0]).toMatch(/^\d+ taps$/);
+					// every JS plugin is timed on its own
+					for (const plugin of ["TestMakePlugin", "OtherMakePlugin"]) {
+						expect(messages).toContainEqual(
+							expect.stringMatching(
+								new RegExp(
+									`^${plugin} CompilerMake \\(stage -?\\d+\\): \\d+\\.\\d{3} ms, 1 call\\(s\\)$`
+								)
+							)
+						);
+					}
+
+					const { traceEvents } = JSON.parse(
+						stats.compilation.getHookProfileTrace()
+					);
+					expect(traceEvents).toContainEqual(
+						expect.objectContaining({
+							name: "TestMakePlugin::CompilerMake",
+							ph: "b"
+						})
+					);
+				});
+			}
+		}
+	]
+};
@@ -0,0 +1,3 @@
+it("should still build the module with a loader error", () => {
+	expect(() => require("./a.js")).toThrow(/loader error/);
+});
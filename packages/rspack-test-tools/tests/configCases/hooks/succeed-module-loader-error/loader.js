@@ -0,0 +1,3 @@
+module.exports = function () {
+	throw new Error("loader error");
+};
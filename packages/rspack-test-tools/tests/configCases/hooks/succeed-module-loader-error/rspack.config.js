@@ -0,0 +1,29 @@
+const path = require("path");
+
+class SucceedModulePlugin {
+	apply(compiler) {
+		const succeeded = new Set();
+		compiler.hooks.compilation.tap("SucceedModulePlugin", compilation => {
+			compilation.hooks.succeedModule.tap("SucceedModulePlugin", module => {
+				succeeded.add(module.resource);
+			});
+		});
+		compiler.hooks.done.tap("SucceedModulePlugin", () => {
+			expect(succeeded).toContain(path.resolve(__dirname, "a.js"));
+			expect(succeeded).toContain(path.resolve(__dirname, "index.js"));
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /a\.js$/,
+				use: [{ loader: "./loader.js" }]
+			}
+		]
+	},
+	plugins: [new SucceedModulePlugin()]
+};
@@ -0,0 +1,6 @@
+import a, { digest } from "./a";
+
+it("should digest with the configured hash function in base58 in loaders", () => {
+	expect(a).toBe("a");
+	expect(digest).toMatch(/^[1-9A-HJ-NP-Za-km-z]+$/);
+});
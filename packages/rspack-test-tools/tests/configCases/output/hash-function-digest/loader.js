@@ -0,0 +1,4 @@
+module.exports = function (source) {
+	const digest = this.utils.createHash().update(source).digest("base58");
+	return `${source}\nexport const digest = ${JSON.stringify(digest)};`;
+};
@@ -0,0 +1,39 @@
+const { util } = require("@rspack/core");
+
+class ChunkHashPlugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap("ChunkHashPlugin", compilation => {
+			compilation.hooks.chunkHash.tap("ChunkHashPlugin", (chunk, hash) => {
+				hash.update("ChunkHashPlugin");
+			});
+		});
+		compiler.hooks.done.tap("ChunkHashPlugin", () => {
+			const { hashFunction, hashDigest } = compiler.options.output;
+			const digest = () =>
+				util.createHash(hashFunction).update("rspack").digest(hashDigest);
+			expect(digest()).toMatch(/^[1-9A-HJ-NP-Za-km-z]+$/);
+			expect(digest()).toBe(digest());
+			// a 128 bits hash
+			expect(
+				util.createHash(hashFunction).update("rspack").digest("hex")
+			).toHaveLength(32);
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		hashFunction: "xxhash3-128",
+		hashDigest: "base58"
+	},
+	module: {
+		rules: [
+			{
+				test: /a\.js$/,
+				use: [{ loader: "./loader.js" }]
+			}
+		]
+	},
+	plugins: [new ChunkHashPlugin()]
+};
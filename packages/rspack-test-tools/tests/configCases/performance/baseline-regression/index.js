@@ -0,0 +1,5 @@
+it("should report size regressions compared to the baseline", () => {
+	expect(__STATS__.warnings.map(warning => warning.message)).toContainEqual(
+		expect.stringContaining("size regression")
+	);
+});
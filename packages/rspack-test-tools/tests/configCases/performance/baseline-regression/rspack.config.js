@@ -0,0 +1,37 @@
+const fs = require("fs");
+const path = require("path");
+
+const baseline = path.resolve(__dirname, "baseline.json");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	performance: {
+		hints: "warning",
+		maxAssetSize: 100,
+		maxEntrypointSize: 100,
+		baseline
+	},
+	plugins: [
+		{
+			apply(compiler) {
+				compiler.hooks.done.tap("Test", stats => {
+					const snapshot = JSON.parse(stats.toSnapshot());
+					expect(snapshot.assets["bundle0.js"]).toBeGreaterThan(100);
+					expect(snapshot.modules["./index.js"].chunks).toEqual(["main"]);
+
+					const diff = stats.diff(fs.readFileSync(baseline, "utf-8"));
+					expect(diff.assets).toEqual([
+						{
+							name: "bundle0.js",
+							before: 10,
+							after: snapshot.assets["bundle0.js"],
+							delta: snapshot.assets["bundle0.js"] - 10
+						}
+					]);
+					expect(diff.addedModules).toContain("./index.js");
+					expect(diff.removedModules).toEqual([]);
+				});
+			}
+		}
+	]
+};
@@ -0,0 +1,5 @@
+it("should compare the gzip sizes with the gzip sizes of the baseline", () => {
+	expect(__STATS__.warnings.map(warning => warning.message)).toContainEqual(
+		expect.stringContaining("size regression")
+	);
+});
@@ -0,0 +1,24 @@
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	performance: {
+		hints: "warning",
+		compression: "gzip",
+		maxAssetSize: 100,
+		maxEntrypointSize: 100,
+		baseline: path.resolve(__dirname, "baseline.json")
+	},
+	plugins: [
+		{
+			apply(compiler) {
+				compiler.hooks.done.tap("Test", stats => {
+					const snapshot = JSON.parse(stats.toSnapshot());
+					expect(snapshot.compressed.gzip.assets["bundle0.js"]).toBeLessThan(
+						snapshot.assets["bundle0.js"]
+					);
+				});
+			}
+		}
+	]
+};
@@ -0,0 +1,8 @@
+module.exports = [
+	[/asset size limit/],
+	[/entrypoint size limit/],
+	[/Rspack performance recommendations/],
+	[
+		/size regression: The following asset\(s\) and entrypoint\(s\) exceed the recommended size limit and grew compared to the baseline\.\nAssets:\n  bundle0\.js \(.+, \+.+\)\nEntrypoints:\n  main \(.+, \+.+\)/
+	]
+];
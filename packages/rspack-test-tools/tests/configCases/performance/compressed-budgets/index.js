@@ -0,0 +1,5 @@
+it("should check the compressed sizes against the budgets", () => {
+	expect(__STATS__.warnings.map(warning => warning.message)).toContainEqual(
+		expect.stringContaining("entrypoint size limit")
+	);
+});
@@ -0,0 +1,27 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	performance: {
+		hints: "warning",
+		compression: "gzip",
+		maxAssetSize: 100000,
+		maxEntrypointSize: 100000,
+		budgets: [
+			{ asset: "bundle*.js", maxSize: 100 },
+			{ entrypoint: "main", maxSize: 100 }
+		]
+	},
+	plugins: [
+		{
+			apply(compiler) {
+				compiler.hooks.done.tap("Test", stats => {
+					const { assets } = stats.toJson({ assets: true, performance: true });
+					const bundle = assets.find(asset => asset.name === "bundle0.js");
+					expect(bundle.isOverSizeLimit).toBe(true);
+					expect(bundle.gzipSize).toBeGreaterThan(100);
+					expect(bundle.gzipSize).toBeLessThan(bundle.size);
+					expect(bundle.brotliSize).toBeUndefined();
+				});
+			}
+		}
+	]
+};
@@ -0,0 +1,9 @@
+module.exports = [
+	[
+		/asset size limit: The following asset\(s\) exceed the recommended size limit \(97\.656 KiB gzip\)\. This can impact web performance\.\nAssets:\n  bundle0\.js \(.+ gzip, .+, budget 100\.000 bytes gzip\)/
+	],
+	[
+		/entrypoint size limit: The following entrypoint\(s\) combined asset size exceeds the recommended limit \(97\.656 KiB gzip\)\. This can impact web performance\.\nEntrypoints:\n  main \(.+ gzip, limit 100\.000 bytes gzip\)\n      Asset +Size +gzip\n      bundle0\.js +.+\n      Total +.+/
+	],
+	[/Rspack performance recommendations/]
+];
@@ -0,0 +1,27 @@
+const fs = require("fs");
+const path = require("path");
+const zlib = require("zlib");
+
+export const content = "compress me please ".repeat(200);
+
+it("should emit precompressed siblings of the asset", () => {
+	const original = fs.readFileSync(path.resolve(__dirname, "bundle0.js"));
+	expect(
+		zlib.gunzipSync(fs.readFileSync(path.resolve(__dirname, "bundle0.js.gz")))
+	).toEqual(original);
+	expect(
+		zlib.brotliDecompressSync(
+			fs.readFileSync(path.resolve(__dirname, "bundle0.js.br"))
+		)
+	).toEqual(original);
+	const zstd = fs.readFileSync(path.resolve(__dirname, "bundle0.js.zst"));
+	expect(zstd.length).toBeLessThan(original.length);
+	if (zlib.zstdDecompressSync) {
+		expect(zlib.zstdDecompressSync(zstd)).toEqual(original);
+	}
+});
+
+it("should not compress assets smaller than the threshold", () => {
+	expect(fs.existsSync(path.resolve(__dirname, "small.js"))).toBe(true);
+	expect(fs.existsSync(path.resolve(__dirname, "small.js.gz"))).toBe(false);
+});
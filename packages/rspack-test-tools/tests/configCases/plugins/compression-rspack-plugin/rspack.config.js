@@ -0,0 +1,40 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		bundle0: "./index.js",
+		small: "./small.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	plugins: [
+		new rspack.CompressionRspackPlugin({
+			test: /\.js$/,
+			algorithm: ["gzip", "brotliCompress", "zstdCompress"],
+			threshold: 1024
+		}),
+		compiler => {
+			const { Compilation } = compiler.webpack;
+			compiler.hooks.thisCompilation.tap("test case", compilation => {
+				compilation.hooks.processAssets.tap(
+					{
+						name: "test case",
+						stage: Compilation.PROCESS_ASSETS_STAGE_REPORT
+					},
+					() => {
+						expect(compilation.getAsset("bundle0.js").info.related).toEqual({
+							gzipped: "bundle0.js.gz",
+							brotliCompressed: "bundle0.js.br",
+							zstdCompressed: "bundle0.js.zst"
+						});
+						// smaller than the threshold
+						expect(compilation.getAsset("small.js.gz")).toBeUndefined();
+						expect(compilation.getAsset("small.js").info.related).toEqual({});
+					}
+				);
+			});
+		}
+	]
+};
@@ -0,0 +1,36 @@
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+const crypto = __non_webpack_require__("crypto");
+
+function integrity(file) {
+	const content = fs.readFileSync(path.resolve(__dirname, file));
+	return ["sha256", "sha384"]
+		.map(
+			algorithm =>
+				`${algorithm}-${crypto.createHash(algorithm).update(content).digest("base64")}`
+		)
+		.join(" ");
+}
+
+it("should set integrity on the elements loading async chunks", async () => {
+	const promise = import(/* webpackChunkName: "async" */ "./async");
+	const script = document.head._children.find(
+		element => element._type === "script"
+	);
+	const link = document.head._children.find(
+		element => element._type === "link"
+	);
+	expect(script.src).toBe("https://test.cases/path/async.js");
+	expect(script.integrity).toBe(integrity("async.js"));
+	expect(script.crossOrigin).toBe("anonymous");
+	expect(link.href).toBe("https://test.cases/path/async.css");
+	expect(link.integrity).toBe(integrity("async.css"));
+	expect(link.crossOrigin).toBe("anonymous");
+	expect((await promise).default).toBe("async");
+});
+
+it("should replace all placeholders of the integrity map", () => {
+	const content = fs.readFileSync(__filename, "utf-8");
+	expect(content).not.toContain("CHUNK-SRI-HASH");
+	expect(content).toContain(integrity("async.js"));
+});
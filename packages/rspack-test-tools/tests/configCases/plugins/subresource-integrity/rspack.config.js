@@ -0,0 +1,20 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	node: false,
+	output: {
+		chunkFilename: "[name].js",
+		cssChunkFilename: "[name].css",
+		crossOriginLoading: "anonymous"
+	},
+	experiments: {
+		css: true
+	},
+	plugins: [
+		new rspack.SubresourceIntegrityRspackPlugin({
+			hashFuncNames: ["sha256", "sha384"]
+		})
+	]
+};
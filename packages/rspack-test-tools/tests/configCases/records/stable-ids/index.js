@@ -0,0 +1,9 @@
+import value from "./shared";
+
+it("should load the async chunks", async () => {
+	expect(value).toBe("shared");
+	const named = await import(/* webpackChunkName: "named" */ "./named");
+	expect(named.default).toBe("named");
+	const unnamed = await import("./unnamed");
+	expect(unnamed.default).toBe("unnamed");
+});
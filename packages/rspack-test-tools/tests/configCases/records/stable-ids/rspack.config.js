@@ -0,0 +1,66 @@
+const path = require("path");
+
+const ids = {};
+
+const getIds = stats => {
+	const { modules, chunks } = stats.toJson({
+		all: false,
+		modules: true,
+		chunks: true,
+		chunkModules: true,
+		ids: true
+	});
+	return {
+		modules: Object.fromEntries(modules.map(m => [m.name, m.id])),
+		chunks: Object.fromEntries(
+			// unnamed chunks are identified by their modules
+			chunks.map(c => [
+				c.names.length > 0
+					? c.names.join(",")
+					: c.modules
+							.map(m => m.name)
+							.sort()
+							.join(","),
+				c.id
+			])
+		)
+	};
+};
+
+/** @type {function(any, any): import("@rspack/core").Configuration[]} */
+module.exports = (env, { testPath }) =>
+	["first", "second"].map((name, index) => ({
+		name,
+		dependencies: index === 0 ? [] : ["first"],
+		// the extra module shifts the natural ids of the second build
+		entry: index === 0 ? "./index.js" : ["./extra.js", "./index.js"],
+		output: {
+			chunkFilename: `${name}-[id].js`
+		},
+		recordsPath: path.join(testPath, "records.json"),
+		optimization: {
+			moduleIds: "natural",
+			chunkIds: "natural",
+			concatenateModules: false
+		},
+		plugins: [
+			{
+				apply(compiler) {
+					compiler.hooks.done.tap("Test", stats => {
+						ids[name] = getIds(stats);
+						if (name !== "second") return;
+						const { first, second } = ids;
+						for (const [module, id] of Object.entries(first.modules)) {
+							expect(second.modules[module]).toBe(id);
+						}
+						for (const [chunk, id] of Object.entries(first.chunks)) {
+							expect(second.chunks[chunk]).toBe(id);
+						}
+						expect(Object.values(first.modules)).not.toContain(
+							second.modules["./extra.js"]
+						);
+					});
+				}
+			}
+		]
+	}));
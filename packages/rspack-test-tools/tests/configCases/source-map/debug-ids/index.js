@@ -0,0 +1,22 @@
+import lib from "lib";
+
+it("should inject the same debug id into the bundle and its source map", () => {
+	const fs = require("fs");
+	expect(lib).toBe("lib");
+	const source = fs.readFileSync(__filename, "utf-8");
+	const map = JSON.parse(fs.readFileSync(__filename + ".map", "utf-8"));
+	const match = /\/\/# debugId=([0-9a-f-]+)\n\/\/# sourceMappingURL=bundle0\.js\.map$/.exec(source);
+	expect(match).toBeTruthy();
+	expect(map.debugId).toBe(match[1]);
+	expect(map.debugId).toMatch(
+		/^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$/
+	);
+});
+
+it("should add node_modules sources to the ignore list", () => {
+	const fs = require("fs");
+	const map = JSON.parse(fs.readFileSync(__filename + ".map", "utf-8"));
+	expect(map.ignoreList.map(index => map.sources[index])).toEqual([
+		"webpack:///./node_modules/lib/index.js"
+	]);
+});
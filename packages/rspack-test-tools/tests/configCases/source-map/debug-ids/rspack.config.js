@@ -0,0 +1,13 @@
+const rspack = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	devtool: false,
+	plugins: [
+		new rspack.SourceMapDevToolPlugin({
+			filename: "[file].map",
+			debugIds: true,
+			ignoreList: /node_modules/
+		})
+	]
+};
@@ -0,0 +1,44 @@
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+import * as styles from "./style.module.css";
+
+it("should map renamed local idents and rewritten urls to the original css", async () => {
+	const sourceMap = require("source-map");
+	const source = fs.readFileSync(
+		path.resolve(__dirname, "bundle0.css.map"),
+		"utf-8"
+	);
+	const map = JSON.parse(source);
+	expect(map.sources).toEqual(["webpack:///./style.module.css"]);
+	expect(map.file).toEqual("bundle0.css");
+	const generated = fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+	expect(generated).toContain("/*# sourceMappingURL=bundle0.css.map*/");
+
+	const consumer = await new sourceMap.SourceMapConsumer(map);
+	const container = consumer.originalPositionFor(
+		positionFor(generated, `.${styles.container}`, 1)
+	);
+	expect(container.line).toBe(1);
+	expect(container.column).toBe(1);
+	expect(container.name).toBe("container");
+
+	const logo = consumer.originalPositionFor(
+		positionFor(generated, `.${styles.logo}`, 1)
+	);
+	expect(logo.line).toBe(5);
+	expect(logo.column).toBe(1);
+	expect(logo.name).toBe("logo");
+
+	const url = consumer.originalPositionFor(positionFor(generated, "background:"));
+	expect(url.line).toBe(6);
+});
+
+const positionFor = (content, text, offset = 0) => {
+	let lines = content.split(/\r?\n/);
+	for (let i = 0; i < lines.length; i++) {
+		const column = lines[i].indexOf(text);
+		if (column >= 0) return { line: i + 1, column: column + offset };
+	}
+	return null;
+};
@@ -0,0 +1,21 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	node: false,
+	module: {
+		generator: {
+			"css/module": {
+				exportsOnly: false
+			}
+		},
+		rules: [
+			{
+				test: /\.png$/i,
+				type: "asset/resource"
+			}
+		]
+	},
+	devtool: "source-map",
+	externals: ["source-map"],
+	externalsType: "commonjs"
+};
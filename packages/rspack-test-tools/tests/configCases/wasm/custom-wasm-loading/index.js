@@ -0,0 +1,13 @@
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should load a WebAssembly module with the plugin provided wasm loading", async () => {
+	const wasm = await import("./wasm.wat");
+	expect(wasm.add(wasm.getNumber(), 2)).toBe(42);
+});
+
+it("should use the load binary code of the plugin", () => {
+	const source = fs.readFileSync(path.join(__dirname, "bundle0.js"), "utf-8");
+	expect(source).toContain("readFileSync(file)");
+	expect(source).not.toContain("fetch(");
+});
@@ -0,0 +1,4 @@
+it("should load a WebAssembly module with the wasm loading of the entry", async () => {
+	const wasm = await import("./wasm.wat");
+	expect(wasm.add(wasm.getNumber(), 2)).toBe(42);
+});
@@ -0,0 +1,9 @@
+it("should load a WebAssembly module with the wasm loading of the entry", async () => {
+	const wasm = await import("./wasm.wat");
+	expect(wasm.add(wasm.getNumber(), 2)).toBe(42);
+});
+
+it("should not add the wasm loading of the output to the entry", () => {
+	const content = require("fs").readFileSync(__filename, "utf-8");
+	expect(content).not.toContain("fetch(" + "__webpack_require__.p");
+});
@@ -0,0 +1,39 @@
+const { wasm } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	entry: {
+		main: {
+			import: "./index.js",
+			wasmLoading: "custom-host"
+		}
+	},
+	output: {
+		filename: "bundle0.js",
+		wasmLoading: "fetch"
+	},
+	module: {
+		rules: [
+			{
+				test: /\.wat$/,
+				use: "wast-loader",
+				type: "webassembly/async"
+			}
+		]
+	},
+	experiments: {
+		asyncWebAssembly: true
+	},
+	plugins: [
+		new wasm.CustomWasmLoadingPlugin({
+			type: "custom-host",
+			generateLoadBinaryCode: `new Promise(function (resolve) {
+				var file = require("path").join(__dirname, $PATH);
+				resolve({
+					arrayBuffer: function () { return require("fs").readFileSync(file); }
+				});
+			})`
+		})
+	]
+};
@@ -0,0 +1,9 @@
+it("should instantiate a sync WebAssembly module with imports", async () => {
+	const wasm = await import("./wasm.wat");
+	expect(wasm.addNumber(3)).toBe(11);
+});
+
+it("should provide the exports of a sync WebAssembly module to javascript modules", async () => {
+	const { run } = await import("./reexport");
+	expect(run()).toBe(50);
+});
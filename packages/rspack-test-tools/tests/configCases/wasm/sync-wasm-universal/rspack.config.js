@@ -0,0 +1,25 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	output: {
+		filename: "[name].mjs",
+		chunkFilename: "[name].mjs",
+		module: true,
+		chunkFormat: "module",
+		chunkLoading: "import",
+		wasmLoading: "universal"
+	},
+	module: {
+		rules: [
+			{
+				test: /\.wat$/,
+				use: "wast-loader",
+				type: "webassembly/sync"
+			}
+		]
+	},
+	experiments: {
+		outputModule: true,
+		syncWebAssembly: true
+	}
+};
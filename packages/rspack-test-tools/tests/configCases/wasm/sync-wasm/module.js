@@ -0,0 +1,3 @@
+export function getNumber() {
+	return 8;
+}
@@ -0,0 +1,5 @@
+import { addNumber, getFortyTwo } from "./wasm.wat";
+
+export function run() {
+	return addNumber(getFortyTwo());
+}
@@ -0,0 +1,16 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	module: {
+		rules: [
+			{
+				test: /\.wat$/,
+				use: "wast-loader",
+				type: "webassembly/sync"
+			}
+		]
+	},
+	experiments: {
+		syncWebAssembly: true
+	}
+};
@@ -0,0 +1,17 @@
+/** @type {import('../../..').TDefaultsCaseConfig} */
+module.exports = {
+	description: "cache with max generations",
+	options: () => ({ cache: { type: "memory", maxGenerations: 5 } }),
+	diff: e =>
+		e.toMatchInlineSnapshot(`
+		- Expected
+		+ Received
+
+		@@ ... @@
+		-   "cache": false,
+		+   "cache": Object {
+		+     "maxGenerations": 5,
+		+     "type": "memory",
+		+   },
+	`)
+};
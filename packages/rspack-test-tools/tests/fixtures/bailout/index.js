@@ -0,0 +1,5 @@
+import * as ns from "./ns";
+import { value } from "./reexport";
+const cjs = require("./cjs");
+
+console.log(ns, value, cjs);
@@ -243,7 +243,10 @@ module.exports = {
 		    moduleType: javascript/auto,
 		    name: ./fixtures/esm/c.js,
 		    nameForCondition: <ROOT>/tests/fixtures/esm/c.js,
-		    optimizationBailout: Array [],
+		    optimizationBailout: Array [
+		    	Used exports bailout: the exports object is referenced by ./fixtures/esm/abc.js (esm import specifier),
+		    	Mangle exports bailout: exports are used in an unknown way, export names are kept,
+		    ],
 		    optional: false,
 		    orphan: true,
 		    postOrderIndex: 2,
@@ -0,0 +1,51 @@
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should have optimization bailout reasons",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: {
+				main: "./fixtures/bailout/index"
+			},
+			mode: "production"
+		};
+	},
+	async check(stats) {
+		const statsJson = stats?.toJson({
+			all: false,
+			modules: true,
+			nestedModules: true,
+			orphanModules: true,
+			optimizationBailout: true
+		});
+		const bailouts = {};
+		const collect = modules => {
+			for (const module of modules || []) {
+				if (module.name && !module.name.includes(" + ")) {
+					bailouts[module.name] = [
+						...(bailouts[module.name] || []),
+						...(module.optimizationBailout || [])
+					];
+				}
+				collect(module.modules);
+			}
+		};
+		collect(statsJson.modules);
+		expect(bailouts["./fixtures/bailout/cjs.js"]).toContain(
+			"ModuleConcatenation bailout: Module is not an ECMAScript module"
+		);
+		expect(bailouts["./fixtures/bailout/ns.js"]).toEqual(
+			expect.arrayContaining([
+				"Used exports bailout: the exports object is referenced by ./fixtures/bailout/index.js (esm import specifier)",
+				"Mangle exports bailout: exports are used in an unknown way, export names are kept"
+			])
+		);
+		expect(bailouts["./fixtures/bailout/reexport.js"]).toEqual(
+			expect.arrayContaining([
+				expect.stringMatching(
+					/^Dependency \(.+\) with side effects at \.\/fixtures\/bailout\/reexport\.js:1:\d+-\d+$/
+				)
+			])
+		);
+	}
+};
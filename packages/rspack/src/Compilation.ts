@@ -1090,6 +1090,14 @@ BREAKING CHANGE: Asset processing hooks in Compilation has been merged into a si
 		return new Stats(this);
 	}
 
+	/**
+	 * Get the tap calls of the hooks in the Chrome trace event format, which can be loaded by
+	 * `chrome://tracing` or Perfetto. The calls are only recorded when `profile` is enabled.
+	 */
+	getHookProfileTrace(): string {
+		return this.#inner.getHookProfileTrace();
+	}
+
 	createChildCompiler(
 		name: string,
 		outputOptions: OutputNormalized,
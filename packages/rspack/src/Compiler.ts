@@ -83,6 +83,68 @@ export interface AssetEmittedInfo {
 
 const COMPILATION_WEAK_MAP = new WeakMap<binding.JsCompilation, Compilation>();
 
+/**
+ * Names of the plugins tapped in the stage range, which are reported by the hook profiling.
+ */
+function getTapNames(
+	hooks: Iterable<liteTapable.Hook<any, any, any>>,
+	[from, to]: readonly [number, number]
+): string[] {
+	const names = new Set<string>();
+	for (const hook of hooks) {
+		for (const tap of hook.taps) {
+			const stage = tap.stage ?? 0;
+			if (stage >= from && stage < to) names.add(tap.name);
+		}
+	}
+	return [...names];
+}
+
+/**
+ * A copy of the queried hook which only calls the given taps, the call and done interceptors
+ * only run for the first and the last copy of the stage range.
+ */
+function withTapsInRange<T, R, A>(
+	queried: liteTapable.QueriedHook<T, R, A>,
+	tapsInRange: typeof queried.tapsInRange,
+	isFirst: boolean,
+	isLast: boolean
+): liteTapable.QueriedHook<T, R, A> {
+	const [from, to] = queried.stageRange;
+	const copy = Object.create(Object.getPrototypeOf(queried));
+	return Object.assign(copy, queried, {
+		stageRange: [
+			isFirst ? from : liteTapable.safeStage(from + 1),
+			isLast ? to : liteTapable.safeStage(to - 1)
+		] as const,
+		tapsInRange
+	});
+}
+
+/**
+ * Splits the queried hook into one queried hook per run of taps of the same plugin,
+ * so that the hook profiling times every JavaScript plugin on its own.
+ */
+function splitQueriedHook<T, R, A>(
+	queried: liteTapable.QueriedHook<T, R, A>
+): [string, liteTapable.QueriedHook<T, R, A>][] {
+	const groups: [string, typeof queried.tapsInRange][] = [];
+	for (const tap of queried.tapsInRange) {
+		const last = groups[groups.length - 1];
+		if (last && last[0] === tap.name) last[1].push(tap);
+		else groups.push([tap.name, [tap]]);
+	}
+	return groups.map(([name, tapsInRange], index) => [
+		name,
+		withTapsInRange(
+			queried,
+			tapsInRange,
+			index === 0,
+			index === groups.length - 1
+		)
+	]);
+}
+
 class Compiler {
 	#instance?: binding.Rspack;
 	#initial: boolean;
@@ -250,11 +312,11 @@ class Compiler {
 	}
 
 	get recordsInputPath() {
-		return unsupported("Compiler.recordsInputPath");
+		return this.options.recordsInputPath || null;
 	}
 
 	get recordsOutputPath() {
-		return unsupported("Compiler.recordsOutputPath");
+		return this.options.recordsOutputPath || null;
 	}
 
 	get managedPaths() {
@@ -1374,9 +1436,21 @@ class Compiler {
 				const stageRange = [from, to] as const;
 				const queried = hook.queryStageRange(stageRange);
 				if (!queried.isUsed()) continue;
+				if (this.options.profile && queried.tapsInRange.length > 1) {
+					// one tap per plugin, so each of them is timed by the hook profiling
+					for (const [name, split] of splitQueriedHook(queried)) {
+						jsTaps.push({
+							function: createTap(split),
+							stage: liteTapable.safeStage(from + 1),
+							name
+						});
+					}
+					continue;
+				}
 				jsTaps.push({
 					function: createTap(queried),
-					stage: liteTapable.safeStage(from + 1)
+					stage: liteTapable.safeStage(from + 1),
+					name: getTapNames([hook], stageRange).join(", ")
 				});
 			}
 			this.#decorateJsTaps(jsTaps);
@@ -1407,9 +1481,33 @@ class Compiler {
 				const stageRange = [from, to] as const;
 				const queried = map.queryStageRange(stageRange);
 				if (!queried.isUsed()) continue;
+				const names = getTapNames(map._map.values(), stageRange);
+				if (this.options.profile && names.length > 1) {
+					// one tap per plugin, so each of them is timed by the hook profiling
+					names.forEach((name, index) => {
+						const split: liteTapable.QueriedHookMap<H> =
+							Object.create(queried);
+						split.for = key => {
+							const hook = queried.for(key);
+							return withTapsInRange(
+								hook,
+								hook.tapsInRange.filter(tap => tap.name === name),
+								index === 0,
+								index === names.length - 1
+							);
+						};
+						jsTaps.push({
+							function: createTap(split),
+							stage: liteTapable.safeStage(from + 1),
+							name
+						});
+					});
+					continue;
+				}
 				jsTaps.push({
 					function: createTap(queried),
-					stage: liteTapable.safeStage(from + 1)
+					stage: liteTapable.safeStage(from + 1),
+					name: names.join(", ")
 				});
 			}
 			this.#decorateJsTaps(jsTaps);
@@ -9,8 +9,9 @@
  */
 import type * as binding from "@rspack/binding";
 
-import type { Compilation } from "./Compilation";
+import type { Compilation, NormalizedStatsOptions } from "./Compilation";
 import type { StatsOptions, StatsValue } from "./config";
+import { getNativeFilters } from "./stats/DefaultStatsPresetPlugin";
 import type { StatsCompilation } from "./stats/statsFactoryUtils";
 
 export type {
@@ -21,6 +22,36 @@ export type {
 	StatsModule
 } from "./stats/statsFactoryUtils";
 
+export type StatsSizeDelta = {
+	name: string;
+	before: number | null;
+	after: number | null;
+	delta: number;
+};
+
+export type StatsDiff = {
+	addedModules: string[];
+	removedModules: string[];
+	assets: StatsSizeDelta[];
+	renamedAssets: {
+		name: string;
+		before: string;
+		after: string;
+	}[];
+	entrypoints: StatsSizeDelta[];
+	chunks: StatsSizeDelta[];
+	modules: StatsSizeDelta[];
+	chunkMembership: {
+		module: string;
+		addedChunks: string[];
+		removedChunks: string[];
+	}[];
+	duplicatedModules: {
+		module: string;
+		chunks: string[];
+	}[];
+};
+
 export class Stats {
 	#inner: binding.JsStats;
 	compilation: Compilation;
@@ -62,12 +93,27 @@ export class Stats {
 		return this.#inner.hasWarnings();
 	}
 
+	/**
+	 * Serialize the sizes and chunk membership of the build, which can be compared with a later build by `diff`.
+	 */
+	toSnapshot(): string {
+		return this.#inner.getSnapshot();
+	}
+
+	/**
+	 * Compare the build with a snapshot of a previous build, generated by `toSnapshot`.
+	 */
+	diff(baseline: string): StatsDiff {
+		return JSON.parse(this.#inner.diff(baseline));
+	}
+
 	toJson(opts?: StatsValue, forToString?: boolean): StatsCompilation {
 		const options = this.compilation.createStatsOptions(opts, {
 			forToString
 		});
 
 		const statsFactory = this.compilation.createStatsFactory(options);
+		const nativeOptions = getNativeStatsOptions(options, !!forToString);
 
 		const statsCompilationMap = new Map<
 			Compilation,
@@ -89,7 +135,7 @@ export class Stats {
 						return statsCompilationMap.get(compilation)!;
 					}
 					const innerStats = this.#getInnerByCompilation(compilation);
-					const innerStatsCompilation = innerStats.toJson(options);
+					const innerStatsCompilation = innerStats.toJson(nativeOptions);
 					statsCompilationMap.set(compilation, innerStatsCompilation);
 					return innerStatsCompilation;
 				},
@@ -132,7 +178,7 @@ export class Stats {
 						return statsCompilationMap.get(compilation)!;
 					}
 					const innerStats = this.#getInnerByCompilation(compilation);
-					const innerStatsCompilation = innerStats.toJson(options);
+					const innerStatsCompilation = innerStats.toJson(nativeOptions);
 					statsCompilationMap.set(compilation, innerStatsCompilation);
 					return innerStatsCompilation;
 				},
@@ -155,6 +201,41 @@ export class Stats {
 	}
 }
 
+/**
+ * Resolves the options of the native stats, which applies the string, RegExp and boolean exclude
+ * filters. The modules are only space limited natively when the stats factory does not group or
+ * filter them, as the groups take the space of the modules.
+ */
+const getNativeStatsOptions = (
+	options: NormalizedStatsOptions,
+	forToString: boolean
+): binding.JsStatsOptions => {
+	const excludeModules = getNativeFilters(options.excludeModules);
+	const modulesFlat =
+		!options.groupModulesByType &&
+		!options.groupModulesByCacheStatus &&
+		!options.groupModulesByLayer &&
+		!options.groupModulesByAttributes &&
+		!options.groupModulesByPath &&
+		!options.groupModulesByExtension &&
+		options.cachedModules &&
+		options.orphanModules &&
+		options.dependentModules &&
+		options.runtimeModules &&
+		options.modulesSort === "depth" &&
+		excludeModules.length === options.excludeModules.length;
+	return {
+		...options,
+		forToString,
+		excludeModules,
+		excludeAssets: getNativeFilters(options.excludeAssets),
+		modulesSpace:
+			modulesFlat && Number.isFinite(options.modulesSpace)
+				? options.modulesSpace
+				: undefined
+	};
+};
+
 export function normalizeStatsPreset(options?: StatsValue): StatsOptions {
 	if (typeof options === "boolean" || typeof options === "string")
 		return presetToOptions(options);
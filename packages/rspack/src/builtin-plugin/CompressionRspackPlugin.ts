@@ -0,0 +1,61 @@
+import {
+	BuiltinPluginName,
+	type RawCompressionRspackPluginOptions
+} from "@rspack/binding";
+import { z } from "zod";
+
+import type { AssetConditions } from "../util/assetCondition";
+import { validate } from "../util/validate";
+import { create } from "./base";
+
+export type CompressionAlgorithm = "gzip" | "brotliCompress" | "zstdCompress";
+
+export type CompressionRspackPluginOptions = {
+	test?: AssetConditions;
+	include?: AssetConditions;
+	exclude?: AssetConditions;
+	/** The algorithms used to compress the assets, defaults to `"gzip"`. */
+	algorithm?: CompressionAlgorithm | CompressionAlgorithm[];
+	/** Only assets whose size in bytes is not less than this are compressed, defaults to `0`. */
+	threshold?: number;
+	/** Only compressed assets whose compression ratio is less than this are emitted, defaults to `0.8`. */
+	minRatio?: number;
+};
+
+const assetConditions = z.union([
+	z.string(),
+	z.instanceof(RegExp),
+	z.array(z.union([z.string(), z.instanceof(RegExp)]))
+]);
+const compressionAlgorithm = z.enum(["gzip", "brotliCompress", "zstdCompress"]);
+
+const compressionRspackPluginOptions = z.strictObject({
+	test: assetConditions.optional(),
+	include: assetConditions.optional(),
+	exclude: assetConditions.optional(),
+	algorithm: z
+		.union([compressionAlgorithm, z.array(compressionAlgorithm)])
+		.optional(),
+	threshold: z.number().nonnegative().optional(),
+	minRatio: z.number().positive().optional()
+}) satisfies z.ZodType<CompressionRspackPluginOptions>;
+
+export const CompressionRspackPlugin = create(
+	BuiltinPluginName.CompressionRspackPlugin,
+	(
+		options: CompressionRspackPluginOptions = {}
+	): RawCompressionRspackPluginOptions => {
+		validate(options, compressionRspackPluginOptions);
+
+		const algorithm = options.algorithm ?? "gzip";
+		return {
+			test: options.test,
+			include: options.include,
+			exclude: options.exclude,
+			algorithms: Array.isArray(algorithm) ? algorithm : [algorithm],
+			threshold: options.threshold ?? 0,
+			minRatio: options.minRatio ?? 0.8
+		};
+	},
+	"compilation"
+);
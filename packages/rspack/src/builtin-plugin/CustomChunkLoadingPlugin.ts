@@ -0,0 +1,36 @@
+import {
+	type BuiltinPlugin,
+	BuiltinPluginName,
+	type RawCustomChunkLoadingPluginOptions
+} from "@rspack/binding";
+
+import type { Compiler } from "../Compiler";
+import { EnableChunkLoadingPlugin } from "./EnableChunkLoadingPlugin";
+import { RspackBuiltinPlugin, createBuiltinPlugin } from "./base";
+
+export type CustomChunkLoadingPluginOptions = {
+	/** The chunk loading type to provide, used in `output.chunkLoading`. */
+	type: string;
+	/**
+	 * Runtime code of a function called with the chunk id and the chunk filename,
+	 * returning the chunk in the `commonjs` chunk format or a promise of it.
+	 */
+	loadChunk: string;
+};
+
+export class CustomChunkLoadingPlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.CustomChunkLoadingPlugin;
+
+	constructor(private options: CustomChunkLoadingPluginOptions) {
+		super();
+	}
+
+	raw(compiler: Compiler): BuiltinPlugin {
+		EnableChunkLoadingPlugin.setEnabled(compiler, this.options.type);
+		const options: RawCustomChunkLoadingPluginOptions = {
+			type: this.options.type,
+			loadChunk: this.options.loadChunk
+		};
+		return createBuiltinPlugin(this.name, options);
+	}
+}
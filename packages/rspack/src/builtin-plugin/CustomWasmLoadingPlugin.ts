@@ -0,0 +1,39 @@
+import {
+	type BuiltinPlugin,
+	BuiltinPluginName,
+	type RawCustomWasmLoadingPluginOptions
+} from "@rspack/binding";
+
+import type { Compiler } from "../Compiler";
+import { EnableWasmLoadingPlugin } from "./EnableWasmLoadingPlugin";
+import { RspackBuiltinPlugin, createBuiltinPlugin } from "./base";
+
+export type CustomWasmLoadingPluginOptions = {
+	/** The wasm loading type to provide, used in `output.wasmLoading`. */
+	type: string;
+	/**
+	 * Runtime code of an expression loading the binary, where `$PATH` is replaced with the path of the wasm file.
+	 * It must evaluate to a response like object, or a promise of it, with an `arrayBuffer` method.
+	 */
+	generateLoadBinaryCode: string;
+	/** Whether the response can be passed to `WebAssembly.instantiateStreaming`. */
+	supportsStreaming?: boolean;
+};
+
+export class CustomWasmLoadingPlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.CustomWasmLoadingPlugin;
+
+	constructor(private options: CustomWasmLoadingPluginOptions) {
+		super();
+	}
+
+	raw(compiler: Compiler): BuiltinPlugin {
+		EnableWasmLoadingPlugin.setEnabled(compiler, this.options.type);
+		const options: RawCustomWasmLoadingPluginOptions = {
+			type: this.options.type,
+			generateLoadBinaryCode: this.options.generateLoadBinaryCode,
+			supportsStreaming: this.options.supportsStreaming
+		};
+		return createBuiltinPlugin(this.name, options);
+	}
+}
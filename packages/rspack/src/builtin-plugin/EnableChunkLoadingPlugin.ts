@@ -1,8 +1,87 @@
-import { BuiltinPluginName } from "@rspack/binding";
+import { type BuiltinPlugin, BuiltinPluginName } from "@rspack/binding";
 
-import { create } from "./base";
+import type { ChunkLoadingType, Compiler } from "..";
+import WebpackError from "../lib/WebpackError";
+import { RspackBuiltinPlugin, createBuiltinPlugin } from "./base";
 
-export const EnableChunkLoadingPlugin = create(
-	BuiltinPluginName.EnableChunkLoadingPlugin,
-	(type: string): string => type
-);
+const BUILTIN_TYPES = [
+	"jsonp",
+	"import-scripts",
+	"require",
+	"async-node",
+	"import"
+];
+
+const enabledTypes = new WeakMap<Compiler, Set<ChunkLoadingType>>();
+/** Custom types provided by plugins, e.g. `CustomChunkLoadingPlugin` */
+const customTypes = new WeakMap<Compiler, Set<ChunkLoadingType>>();
+
+const getTypes = (
+	types: WeakMap<Compiler, Set<ChunkLoadingType>>,
+	compiler: Compiler
+) => {
+	let set = types.get(compiler);
+	if (set === undefined) {
+		set = new Set();
+		types.set(compiler, set);
+	}
+	return set;
+};
+
+const getEnabledTypes = (compiler: Compiler) =>
+	getTypes(enabledTypes, compiler);
+
+const getCustomTypes = (compiler: Compiler) => getTypes(customTypes, compiler);
+
+export class EnableChunkLoadingPlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.EnableChunkLoadingPlugin;
+
+	constructor(private type: ChunkLoadingType) {
+		super();
+	}
+
+	/**
+	 * Plugins which provide a custom chunk loading type must call this,
+	 * otherwise the type is reported as unsupported.
+	 */
+	static setEnabled(compiler: Compiler, type: ChunkLoadingType) {
+		getEnabledTypes(compiler).add(type);
+		getCustomTypes(compiler).add(type);
+	}
+
+	static checkEnabled(compiler: Compiler, type: ChunkLoadingType) {
+		if (!getEnabledTypes(compiler).has(type)) {
+			throw new Error(
+				`Chunk loading type "${type}" is not enabled. EnableChunkLoadingPlugin need to be used to enable this type of chunk loading. This usually happens through the "output.enabledChunkLoadingTypes" option. If you are using a function as entry which sets "chunkLoading", you need to add all potential chunk loading types to "output.enabledChunkLoadingTypes". These types are enabled: ${Array.from(getEnabledTypes(compiler)).join(", ")}`
+			);
+		}
+	}
+
+	raw(compiler: Compiler): BuiltinPlugin | undefined {
+		const { type } = this;
+
+		// Only enable once, unknown types are reported once as well
+		const enabled = getEnabledTypes(compiler);
+		if (enabled.has(type)) return;
+		enabled.add(type);
+
+		if (!BUILTIN_TYPES.includes(type)) {
+			// the plugin providing a custom type may be applied later,
+			// so it's checked once all plugins are applied
+			compiler.hooks.thisCompilation.tap(
+				"EnableChunkLoadingPlugin",
+				compilation => {
+					if (getCustomTypes(compiler).has(type)) return;
+					const error = new WebpackError(
+						`Unsupported chunk loading type "${type}".\nPlugins which provide custom chunk loading types must enable them with CustomChunkLoadingPlugin, otherwise use one of ${BUILTIN_TYPES.map(type => `"${type}"`).join(", ")}.`
+					);
+					error.name = "UnsupportedChunkLoadingTypeError";
+					compilation.errors.push(error);
+				}
+			);
+			return;
+		}
+
+		return createBuiltinPlugin(this.name, type);
+	}
+}
@@ -1,8 +1,50 @@
-import { BuiltinPluginName } from "@rspack/binding";
+import { type BuiltinPlugin, BuiltinPluginName } from "@rspack/binding";
 
-import { create } from "./base";
+import type { Compiler, WasmLoadingType } from "..";
+import { RspackBuiltinPlugin, createBuiltinPlugin } from "./base";
 
-export const EnableWasmLoadingPlugin = create(
-	BuiltinPluginName.EnableWasmLoadingPlugin,
-	(type: string): string => type
-);
+const enabledTypes = new WeakMap();
+
+const getEnabledTypes = (compiler: Compiler) => {
+	let set = enabledTypes.get(compiler);
+	if (set === undefined) {
+		set = new Set();
+		enabledTypes.set(compiler, set);
+	}
+	return set;
+};
+
+export class EnableWasmLoadingPlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.EnableWasmLoadingPlugin;
+
+	constructor(private type: WasmLoadingType) {
+		super();
+	}
+
+	/**
+	 * Plugins which provide a custom wasm loading type must call this,
+	 * otherwise the type is reported as unsupported.
+	 */
+	static setEnabled(compiler: Compiler, type: WasmLoadingType) {
+		getEnabledTypes(compiler).add(type);
+	}
+
+	static checkEnabled(compiler: Compiler, type: WasmLoadingType) {
+		if (!getEnabledTypes(compiler).has(type)) {
+			throw new Error(
+				`Wasm loading type "${type}" is not enabled. EnableWasmLoadingPlugin need to be used to enable this type of wasm loading. This usually happens through the "output.enabledWasmLoadingTypes" option. If you are using a function as entry which sets "wasmLoading", you need to add all potential wasm loading types to "output.enabledWasmLoadingTypes". These types are enabled: ${Array.from(getEnabledTypes(compiler)).join(", ")}`
+			);
+		}
+	}
+
+	raw(compiler: Compiler): BuiltinPlugin | undefined {
+		const { type } = this;
+
+		// Only enable once, unknown types are reported once as well
+		const enabled = getEnabledTypes(compiler);
+		if (enabled.has(type)) return;
+		enabled.add(type);
+
+		return createBuiltinPlugin(this.name, type);
+	}
+}
@@ -18,11 +18,15 @@ type Rule = RegExp | string;
 export interface SourceMapDevToolPluginOptions
 	extends Omit<
 		RawSourceMapDevToolPluginOptions,
-		"test" | "include" | "exclude"
+		"test" | "include" | "exclude" | "ignoreList"
 	> {
 	exclude?: Rules;
 	include?: Rules;
 	test?: Rules;
+	/**
+	 * Mark the sources matching these rules in the `ignoreList` of the source map, e.g. `/node_modules/`.
+	 */
+	ignoreList?: Rules;
 }
 
 export const SourceMapDevToolPlugin = create(
@@ -30,7 +34,7 @@ export const SourceMapDevToolPlugin = create(
 	(
 		options: SourceMapDevToolPluginOptions
 	): RawSourceMapDevToolPluginOptions => {
-		const { test, include, exclude, ...rest } = options;
+		const { test, include, exclude, ignoreList, ...rest } = options;
 
 		const rawOptions: RawSourceMapDevToolPluginOptions = rest;
 
@@ -38,6 +42,10 @@ export const SourceMapDevToolPlugin = create(
 			rawOptions.test = text => matchObject({ test, include, exclude }, text);
 		}
 
+		if (ignoreList) {
+			rawOptions.ignoreList = text => matchObject({ test: ignoreList }, text);
+		}
+
 		return rawOptions;
 	},
 	"compilation"
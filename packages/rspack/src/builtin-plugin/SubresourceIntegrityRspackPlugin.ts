@@ -0,0 +1,34 @@
+import {
+	BuiltinPluginName,
+	type RawSubresourceIntegrityRspackPluginOptions
+} from "@rspack/binding";
+import { z } from "zod";
+
+import { validate } from "../util/validate";
+import { create } from "./base";
+
+export type SubresourceIntegrityRspackPluginOptions = {
+	/** The hash functions used to compute the integrity of the chunks, defaults to `["sha384"]`. */
+	hashFuncNames?: RawSubresourceIntegrityRspackPluginOptions["hashFuncNames"];
+};
+
+const subresourceIntegrityRspackPluginOptions = z.strictObject({
+	hashFuncNames: z
+		.array(z.enum(["sha256", "sha384", "sha512"]))
+		.nonempty()
+		.optional()
+}) satisfies z.ZodType<SubresourceIntegrityRspackPluginOptions>;
+
+export const SubresourceIntegrityRspackPlugin = create(
+	BuiltinPluginName.SubresourceIntegrityRspackPlugin,
+	(
+		options: SubresourceIntegrityRspackPluginOptions = {}
+	): RawSubresourceIntegrityRspackPluginOptions => {
+		validate(options, subresourceIntegrityRspackPluginOptions);
+
+		return {
+			hashFuncNames: options.hashFuncNames ?? ["sha384"]
+		};
+	},
+	"compilation"
+);
@@ -8,9 +8,11 @@ export * from "./BannerPlugin";
 export * from "./BundlerInfoRspackPlugin";
 export * from "./ChunkPrefetchPreloadPlugin";
 export * from "./CommonJsChunkFormatPlugin";
+export * from "./CompressionRspackPlugin";
 export * from "./CopyRspackPlugin";
 export * from "./css-extract/index";
 export * from "./CssModulesPlugin";
+export * from "./CustomChunkLoadingPlugin";
 export * from "./DataUriPlugin";
 export * from "./DefinePlugin";
 export * from "./DeterministicChunkIdsPlugin";
@@ -57,13 +59,16 @@ export * from "./SideEffectsFlagPlugin";
 export * from "./SizeLimitsPlugin";
 export * from "./SourceMapDevToolPlugin";
 export * from "./SplitChunksPlugin";
+export * from "./SubresourceIntegrityRspackPlugin";
 export * from "./LightningCssMinimizerRspackPlugin";
 export * from "./RemoveDuplicateModulesPlugin";
 export * from "./LightningCssMinimizerRspackPlugin";
 export * from "./SwcJsMinimizerPlugin";
 export * from "./WarnCaseSensitiveModulesPlugin";
+export * from "./WebAssemblyModulesPlugin";
 export * from "./WebWorkerTemplatePlugin";
 export * from "./WorkerPlugin";
 export * from "./FetchCompileAsyncWasmPlugin";
+export * from "./CustomWasmLoadingPlugin";
 export * from "./NoEmitOnErrorsPlugin";
 export * from "./ContextReplacementPlugin";
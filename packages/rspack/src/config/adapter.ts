@@ -51,6 +51,7 @@ import type {
 	AssetParserDataUrl,
 	AssetParserOptions,
 	AssetResourceGeneratorOptions,
+	CacheOptions,
 	ChunkLoading,
 	CrossOriginLoading,
 	CssAutoGeneratorOptions,
@@ -105,24 +106,15 @@ export const getRawOptions = (
 		optimization: getRawOptimization(options.optimization),
 		stats: getRawStats(options.stats),
 		snapshot: getRawSnapshotOptions(options.snapshot),
-		cache: {
-			type: options.cache ? "memory" : "disable",
-			// TODO: implement below cache options
-			maxGenerations: 0,
-			maxAge: 0,
-			profile: false,
-			buildDependencies: [],
-			cacheDirectory: "",
-			cacheLocation: "",
-			name: "",
-			version: ""
-		},
+		cache: getRawCacheOptions(options.cache),
 		experiments,
 		node: getRawNode(options.node),
 		// SAFETY: applied default value in `applyRspackOptionsDefaults`.
 		profile: options.profile!,
 		// SAFETY: applied default value in `applyRspackOptionsDefaults`.
 		bail: options.bail!,
+		recordsInputPath: options.recordsInputPath || undefined,
+		recordsOutputPath: options.recordsOutputPath || undefined,
 		__references: {}
 	};
 };
@@ -875,9 +867,54 @@ function getRawOptimization(
 }
 
 function getRawSnapshotOptions(
-	_snapshot: SnapshotOptions
+	snapshot: SnapshotOptions
 ): RawOptions["snapshot"] {
-	return {};
+	const { managedPaths, immutablePaths, buildDependencies, module, resolve } =
+		snapshot;
+	return {
+		managedPaths,
+		immutablePaths,
+		buildDependencies,
+		module,
+		resolve
+	};
+}
+
+function getRawCacheOptions(
+	cache: CacheOptions | undefined
+): RawOptions["cache"] {
+	const raw: RawOptions["cache"] = {
+		type: cache ? "memory" : "disable",
+		// 0 keeps unused entries forever
+		maxGenerations: 0,
+		maxAge: 0,
+		profile: false,
+		buildDependencies: [],
+		cacheDirectory: "",
+		cacheLocation: "",
+		name: "",
+		version: ""
+	};
+	if (typeof cache !== "object") {
+		return raw;
+	}
+	if (cache.type === "filesystem") {
+		return {
+			...raw,
+			type: "filesystem",
+			maxAge: cache.maxAge ?? 0,
+			profile: cache.profile ?? false,
+			buildDependencies: Object.values(cache.buildDependencies ?? {}).flat(),
+			cacheDirectory: cache.cacheDirectory ?? "",
+			cacheLocation: cache.cacheLocation ?? "",
+			name: cache.name ?? "",
+			version: cache.version ?? ""
+		};
+	}
+	return {
+		...raw,
+		maxGenerations: cache.maxGenerations ?? 0
+	};
 }
 
 function getRawExperiments(
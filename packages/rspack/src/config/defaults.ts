@@ -83,6 +83,8 @@ export const applyRspackOptionsDefaults = (
 	D(options, "profile", false);
 	// IGNORE(bail): bail is default to false in webpack, but it's set in `Compilation`
 	D(options, "bail", false);
+	D(options, "recordsInputPath", false);
+	D(options, "recordsOutputPath", false);
 
 	// IGNORE(cache): cache is default to { type: "memory" } in webpack when the mode is development,
 	// but Rspack currently does not support this option
@@ -94,6 +96,7 @@ export const applyRspackOptionsDefaults = (
 
 	applyModuleDefaults(options.module, {
 		asyncWebAssembly: options.experiments.asyncWebAssembly!,
+		syncWebAssembly: options.experiments.syncWebAssembly,
 		css: options.experiments.css,
 		targetProperties
 	});
@@ -269,10 +272,12 @@ const applyModuleDefaults = (
 	module: ModuleOptions,
 	{
 		asyncWebAssembly,
+		syncWebAssembly,
 		css,
 		targetProperties
 	}: {
 		asyncWebAssembly: boolean;
+		syncWebAssembly?: boolean;
 		css?: boolean;
 		targetProperties: any;
 	}
@@ -427,6 +432,28 @@ const applyModuleDefaults = (
 				mimetype: "application/wasm",
 				...wasm
 			});
+		} else if (syncWebAssembly) {
+			const wasm = {
+				type: "webassembly/sync",
+				rules: [
+					{
+						descriptionData: {
+							type: "module"
+						},
+						resolve: {
+							fullySpecified: true
+						}
+					}
+				]
+			};
+			rules.push({
+				test: /\.wasm$/i,
+				...wasm
+			});
+			rules.push({
+				mimetype: "application/wasm",
+				...wasm
+			});
 		}
 
 		if (css) {
@@ -759,11 +786,11 @@ const applyOutputDefaults = (
 		if (output.workerWasmLoading) {
 			enabledWasmLoadingTypes.add(output.workerWasmLoading);
 		}
-		// forEachEntry(desc => {
-		// 	if (desc.wasmLoading) {
-		// 		enabledWasmLoadingTypes.add(desc.wasmLoading);
-		// 	}
-		// });
+		forEachEntry(desc => {
+			if (desc.wasmLoading) {
+				enabledWasmLoadingTypes.add(desc.wasmLoading);
+			}
+		});
 		return Array.from(enabledWasmLoadingTypes);
 	});
 
@@ -70,6 +70,8 @@ import type {
 	Plugins,
 	Profile,
 	PublicPath,
+	RecordsInputPath,
+	RecordsOutputPath,
 	Resolve,
 	RspackFutureOptions,
 	RspackOptions,
@@ -263,7 +265,20 @@ export const getNormalizedRspackOptions = (
 				}
 		),
 		loader: cloneObject(config.loader),
-		snapshot: nestedConfig(config.snapshot, _snapshot => ({})),
+		snapshot: nestedConfig(config.snapshot, snapshot => ({
+			managedPaths: optionalNestedArray(snapshot.managedPaths, p => [...p]),
+			immutablePaths: optionalNestedArray(snapshot.immutablePaths, p => [...p]),
+			buildDependencies: optionalNestedConfig(
+				snapshot.buildDependencies,
+				strategy => ({ ...strategy })
+			),
+			module: optionalNestedConfig(snapshot.module, strategy => ({
+				...strategy
+			})),
+			resolve: optionalNestedConfig(snapshot.resolve, strategy => ({
+				...strategy
+			}))
+		})),
 		cache: optionalNestedConfig(config.cache, cache => cache),
 		stats: nestedConfig(config.stats, stats => {
 			if (stats === false) {
@@ -332,7 +347,15 @@ export const getNormalizedRspackOptions = (
 		watchOptions: cloneObject(config.watchOptions),
 		devServer: config.devServer,
 		profile: config.profile,
-		bail: config.bail
+		bail: config.bail,
+		recordsInputPath:
+			config.recordsInputPath !== undefined
+				? config.recordsInputPath
+				: config.recordsPath,
+		recordsOutputPath:
+			config.recordsOutputPath !== undefined
+				? config.recordsOutputPath
+				: config.recordsPath
 	};
 };
 
@@ -370,6 +393,7 @@ const getNormalizedEntryStatic = (entry: EntryStatic) => {
 				baseUri: value.baseUri,
 				chunkLoading: value.chunkLoading,
 				asyncChunks: value.asyncChunks,
+				wasmLoading: value.wasmLoading,
 				filename: value.filename,
 				library: value.library,
 				layer: value.layer,
@@ -464,6 +488,7 @@ export interface EntryDescriptionNormalized {
 	runtime?: EntryRuntime;
 	chunkLoading?: ChunkLoading;
 	asyncChunks?: AsyncChunks;
+	wasmLoading?: WasmLoading;
 	publicPath?: PublicPath;
 	baseUri?: BaseUri;
 	filename?: EntryFilename;
@@ -534,6 +559,7 @@ export interface ModuleOptionsNormalized {
 export interface ExperimentsNormalized {
 	lazyCompilation?: false | LazyCompilationOptions;
 	asyncWebAssembly?: boolean;
+	syncWebAssembly?: boolean;
 	outputModule?: boolean;
 	topLevelAwait?: boolean;
 	css?: boolean;
@@ -585,4 +611,6 @@ export interface RspackOptionsNormalized {
 	performance?: Performance;
 	profile?: Profile;
 	bail?: Bail;
+	recordsInputPath?: RecordsInputPath;
+	recordsOutputPath?: RecordsOutputPath;
 }
@@ -68,7 +68,9 @@ export type WasmLoadingType =
 	| string
 	| "fetch-streaming"
 	| "fetch"
-	| "async-node";
+	| "async-node"
+	| "async-node-module"
+	| "universal";
 
 /** Option to set the method of loading WebAssembly Modules. */
 export type WasmLoading = false | WasmLoadingType;
@@ -314,7 +316,13 @@ export type HashDigest = string;
 export type HashDigestLength = number;
 
 /** The hashing algorithm to use. */
-export type HashFunction = "md4" | "xxhash64";
+export type HashFunction =
+	| "md4"
+	| "md5"
+	| "sha256"
+	| "sha512"
+	| "xxhash64"
+	| "xxhash3-128";
 
 /** An optional salt to update the hash. */
 export type HashSalt = string;
@@ -1547,10 +1555,115 @@ export type Loader = Record<string, any>;
 //#endregion
 
 //#region Snapshot
-export type SnapshotOptions = {};
+/**
+ * How a file is compared with its previous state.
+ * */
+export type SnapshotStrategy = {
+	/** Compare the content hash of the file. */
+	hash?: boolean;
+
+	/** Compare the modified time of the file. */
+	timestamp?: boolean;
+};
+
+/**
+ * Options for snapshotting the dependencies of the build.
+ * */
+export type SnapshotOptions = {
+	/**
+	 * Paths managed by a package manager, files in them are snapshotted by package version.
+	 * @default [/[\\/]node_modules[\\/]/]
+	 * */
+	managedPaths?: (string | RegExp)[];
+
+	/**
+	 * Paths that never change, files in them are never checked again.
+	 * @default []
+	 * */
+	immutablePaths?: (string | RegExp)[];
+
+	/**
+	 * Snapshot strategy for build dependencies of the persistent cache.
+	 * @default { hash: true, timestamp: true }
+	 * */
+	buildDependencies?: SnapshotStrategy;
+
+	/**
+	 * Snapshot strategy for files that modules depend on.
+	 * @default { timestamp: true }
+	 * */
+	module?: SnapshotStrategy;
+
+	/**
+	 * Snapshot strategy for files and directories touched by resolving.
+	 * @default { timestamp: true }
+	 * */
+	resolve?: SnapshotStrategy;
+};
 //#endregion
 
 //#region Cache
+/**
+ * Options for the memory cache.
+ */
+export type MemoryCacheOptions = {
+	type: "memory";
+
+	/**
+	 * Number of generations unused cache entries stay in the memory cache.
+	 * A generation is a rebuild, by default the entries are never evicted.
+	 */
+	maxGenerations?: number;
+};
+
+/**
+ * Options for the persistent filesystem cache.
+ */
+export type FileSystemCacheOptions = {
+	type: "filesystem";
+
+	/**
+	 * Base directory for the cache.
+	 * @default "node_modules/.cache/rspack"
+	 */
+	cacheDirectory?: string;
+
+	/**
+	 * Location of the cache, takes precedence over `cacheDirectory` and `name`.
+	 * @default path.resolve(cacheDirectory, name)
+	 */
+	cacheLocation?: string;
+
+	/**
+	 * Name of the cache, different names lead to coexisting caches.
+	 * @default "default"
+	 */
+	name?: string;
+
+	/**
+	 * Version of the cache data, a different version invalidates the whole cache.
+	 */
+	version?: string;
+
+	/**
+	 * Time in milliseconds unused cache entries stay in the filesystem cache.
+	 * @default 2592000000 (one month)
+	 */
+	maxAge?: number;
+
+	/**
+	 * Log timing information of restoring and storing the cache.
+	 */
+	profile?: boolean;
+
+	/**
+	 * Additional code dependencies of the build, the cache is invalidated when they change.
+	 * @example
+	 * buildDependencies: { config: [__filename] }
+	 */
+	buildDependencies?: Record<string, string[]>;
+};
+
 /**
  * Options for caching snapshots and intermediate products during the build process.
  * @description Controls whether caching is enabled or disabled.
@@ -1561,8 +1674,17 @@ export type SnapshotOptions = {};
  *
  * // Disable caching
  * cache: false
+ *
+ * // Evict entries unused for 5 rebuilds
+ * cache: { type: "memory", maxGenerations: 5 }
+ *
+ * // Persist the cache to disk
+ * cache: { type: "filesystem", buildDependencies: { config: [__filename] } }
  */
-export type CacheOptions = boolean;
+export type CacheOptions =
+	| boolean
+	| MemoryCacheOptions
+	| FileSystemCacheOptions;
 //#endregion
 
 //#region Stats
@@ -1632,6 +1754,16 @@ export type StatsOptions = {
 	 * @default true
 	 */
 	warningsCount?: boolean;
+	/**
+	 * Enables or disables the display of advices, like performance suggestions and deprecation notes.
+	 * @default true
+	 */
+	advices?: boolean;
+	/**
+	 * Enables or disables the display of advice counts.
+	 * @default true
+	 */
+	advicesCount?: boolean;
 	/**
 	 * Enables or disables the display of error stats.
 	 * @default true
@@ -2436,6 +2568,11 @@ export type Experiments = {
 	 * Support the new WebAssembly according to the [updated specification](https://github.com/WebAssembly/esm-integration), it makes a WebAssembly module an async module.
 	 */
 	asyncWebAssembly?: boolean;
+	/**
+	 * Enable sync WebAssembly.
+	 * Support the old WebAssembly like in webpack 4, the wasm module is instantiated when its chunk is loaded, so it must not be in an initial chunk.
+	 */
+	syncWebAssembly?: boolean;
 	/**
 	 * Enable output as ES module.
 	 */
@@ -2548,6 +2685,27 @@ export type Profile = boolean;
 export type Bail = boolean;
 //#endregion
 
+//#region Records
+/**
+ * Store and load the module and chunk ids from a JSON file, so ids stay stable across builds.
+ * It's used for `recordsInputPath` and `recordsOutputPath` if they are left undefined.
+ * An absolute path is expected.
+ * */
+export type RecordsPath = string | false;
+
+/**
+ * Load the module and chunk ids of the last build from a JSON file.
+ * An absolute path is expected.
+ * */
+export type RecordsInputPath = string | false;
+
+/**
+ * Store the module and chunk ids to a JSON file.
+ * An absolute path is expected.
+ * */
+export type RecordsOutputPath = string | false;
+//#endregion
+
 //#region Performance
 /** Options to control how Rspack notifies you of assets and entry points that exceed a specific file limit.   */
 export type Performance =
@@ -2571,6 +2729,34 @@ export type Performance =
 			 * @default 250000
 			 */
 			maxEntrypointSize?: number;
+			/**
+			 * Path of a stats snapshot of a previous build, generated by `stats.toSnapshot()`.
+			 * Assets and entry points exceeding the limit which are new or grew compared to it are reported as size regressions.
+			 */
+			baseline?: string;
+			/**
+			 * Compare the gzip or brotli compressed sizes of the assets with the limits instead of the raw sizes.
+			 */
+			compression?: "gzip" | "brotli";
+			/**
+			 * Limits of the assets or entry points matching the glob, which override `maxAssetSize` or `maxEntrypointSize`.
+			 * The first matching budget is used.
+			 */
+			budgets?: PerformanceBudget[];
+	  };
+
+export type PerformanceBudget =
+	| {
+			/** Glob of the asset filenames. */
+			asset: string;
+			/** Size limit (in bytes). */
+			maxSize: number;
+	  }
+	| {
+			/** Glob of the entry point names. */
+			entrypoint: string;
+			/** Size limit (in bytes). */
+			maxSize: number;
 	  };
 //#endregion
 
@@ -2691,6 +2877,18 @@ export type RspackOptions = {
 	 * Whether to fail on the first error.
 	 */
 	bail?: Bail;
+	/**
+	 * The JSON file to store and load the module and chunk ids.
+	 */
+	recordsPath?: RecordsPath;
+	/**
+	 * The JSON file to load the module and chunk ids from.
+	 */
+	recordsInputPath?: RecordsInputPath;
+	/**
+	 * The JSON file to store the module and chunk ids to.
+	 */
+	recordsOutputPath?: RecordsOutputPath;
 	/**
 	 * Performance optimization options.
 	 */
@@ -69,7 +69,13 @@ const chunkLoading = z
 const asyncChunks = z.boolean() satisfies z.ZodType<t.AsyncChunks>;
 
 const wasmLoadingType = z
-	.enum(["fetch-streaming", "fetch", "async-node"])
+	.enum([
+		"fetch-streaming",
+		"fetch",
+		"async-node",
+		"async-node-module",
+		"universal"
+	])
 	.or(z.string()) satisfies z.ZodType<t.WasmLoadingType>;
 
 const wasmLoading = z
@@ -277,7 +283,11 @@ const hashDigestLength = z.number() satisfies z.ZodType<t.HashDigestLength>;
 
 const hashFunction = z.enum([
 	"md4",
-	"xxhash64"
+	"md5",
+	"sha256",
+	"sha512",
+	"xxhash64",
+	"xxhash3-128"
 ]) satisfies z.ZodType<t.HashFunction>;
 
 const hashSalt = z.string() satisfies z.ZodType<t.HashSalt>;
@@ -942,13 +952,41 @@ const loader = z.record(z.string(), z.any()) satisfies z.ZodType<t.Loader>;
 //#endregion
 
 //#region Snapshot
-const snapshotOptions = z.strictObject(
-	{}
-) satisfies z.ZodType<t.SnapshotOptions>;
+const snapshotStrategy = z.strictObject({
+	hash: z.boolean().optional(),
+	timestamp: z.boolean().optional()
+}) satisfies z.ZodType<t.SnapshotStrategy>;
+
+const snapshotOptions = z.strictObject({
+	managedPaths: z.string().or(z.instanceof(RegExp)).array().optional(),
+	immutablePaths: z.string().or(z.instanceof(RegExp)).array().optional(),
+	buildDependencies: snapshotStrategy.optional(),
+	module: snapshotStrategy.optional(),
+	resolve: snapshotStrategy.optional()
+}) satisfies z.ZodType<t.SnapshotOptions>;
 //#endregion
 
 //#region Cache
-const cacheOptions = z.boolean() satisfies z.ZodType<t.CacheOptions>;
+const memoryCacheOptions = z.strictObject({
+	type: z.literal("memory"),
+	maxGenerations: z.number().int().positive().optional()
+}) satisfies z.ZodType<t.MemoryCacheOptions>;
+
+const fileSystemCacheOptions = z.strictObject({
+	type: z.literal("filesystem"),
+	cacheDirectory: z.string().optional(),
+	cacheLocation: z.string().optional(),
+	name: z.string().optional(),
+	version: z.string().optional(),
+	maxAge: z.number().int().nonnegative().optional(),
+	profile: z.boolean().optional(),
+	buildDependencies: z.record(z.array(z.string())).optional()
+}) satisfies z.ZodType<t.FileSystemCacheOptions>;
+
+const cacheOptions = z
+	.boolean()
+	.or(memoryCacheOptions)
+	.or(fileSystemCacheOptions) satisfies z.ZodType<t.CacheOptions>;
 //#endregion
 
 //#region Stats
@@ -972,6 +1010,8 @@ const statsOptions = z.strictObject({
 	chunkGroups: z.boolean().optional(),
 	warnings: z.boolean().optional(),
 	warningsCount: z.boolean().optional(),
+	advices: z.boolean().optional(),
+	advicesCount: z.boolean().optional(),
 	errors: z.boolean().optional(),
 	errorsCount: z.boolean().optional(),
 	colors: z.boolean().optional(),
@@ -1253,6 +1293,7 @@ const incremental = z.strictObject({
 const experiments = z.strictObject({
 	lazyCompilation: z.boolean().optional().or(lazyCompilationOptions),
 	asyncWebAssembly: z.boolean().optional(),
+	syncWebAssembly: z.boolean().optional(),
 	outputModule: z.boolean().optional(),
 	topLevelAwait: z.boolean().optional(),
 	css: z.boolean().optional(),
@@ -1306,13 +1347,42 @@ const profile = z.boolean() satisfies z.ZodType<t.Profile>;
 const bail = z.boolean() satisfies z.ZodType<t.Bail>;
 //#endregion
 
+//#region Records
+const recordsPath = z
+	.literal(false)
+	.or(z.string()) satisfies z.ZodType<t.RecordsPath>;
+
+const recordsInputPath = z
+	.literal(false)
+	.or(z.string()) satisfies z.ZodType<t.RecordsInputPath>;
+
+const recordsOutputPath = z
+	.literal(false)
+	.or(z.string()) satisfies z.ZodType<t.RecordsOutputPath>;
+//#endregion
+
 //#region Performance
+const performanceBudget = z
+	.strictObject({
+		asset: z.string(),
+		maxSize: z.number()
+	})
+	.or(
+		z.strictObject({
+			entrypoint: z.string(),
+			maxSize: z.number()
+		})
+	) satisfies z.ZodType<t.PerformanceBudget>;
+
 const performance = z
 	.strictObject({
 		assetFilter: z.function().args(z.string()).returns(z.boolean()).optional(),
 		hints: z.enum(["error", "warning"]).or(z.literal(false)).optional(),
 		maxAssetSize: z.number().optional(),
-		maxEntrypointSize: z.number().optional()
+		maxEntrypointSize: z.number().optional(),
+		baseline: z.string().optional(),
+		compression: z.enum(["gzip", "brotli"]).optional(),
+		budgets: z.array(performanceBudget).optional()
 	})
 	.or(z.literal(false)) satisfies z.ZodType<t.Performance>;
 //#endregion
@@ -1347,5 +1417,8 @@ export const rspackOptions = z.strictObject({
 	module: moduleOptions.optional(),
 	profile: profile.optional(),
 	bail: bail.optional(),
+	recordsPath: recordsPath.optional(),
+	recordsInputPath: recordsInputPath.optional(),
+	recordsOutputPath: recordsOutputPath.optional(),
 	performance: performance.optional()
 }) satisfies z.ZodType<t.RspackOptions>;
@@ -0,0 +1,17 @@
+import {
+	BuiltinPluginName,
+	type RawModuleFederationManifestPluginOptions
+} from "@rspack/binding";
+
+import { create } from "../builtin-plugin/base";
+
+export type ModuleFederationManifestPluginOptions =
+	RawModuleFederationManifestPluginOptions;
+
+export const ModuleFederationManifestPlugin = create(
+	BuiltinPluginName.ModuleFederationManifestPlugin,
+	(
+		options: ModuleFederationManifestPluginOptions
+	): RawModuleFederationManifestPluginOptions => options,
+	"compilation"
+);
@@ -1,6 +1,7 @@
 import type { Compiler } from "../Compiler";
 import { type ExternalsType, externalsType } from "../config";
 import { isValidate } from "../util/validate";
+import { ModuleFederationManifestPlugin } from "./ModuleFederationManifestPlugin";
 import type { ModuleFederationPluginV1Options } from "./ModuleFederationPluginV1";
 import { ModuleFederationRuntimePlugin } from "./ModuleFederationRuntimePlugin";
 import { parseOptions } from "./options";
@@ -10,8 +11,18 @@ export interface ModuleFederationPluginOptions
 	runtimePlugins?: RuntimePlugins;
 	implementation?: string;
 	shareStrategy?: "version-first" | "loaded-first";
+	/**
+	 * Emit `mf-manifest.json` and `mf-stats.json` describing the exposes, shared modules and remotes.
+	 */
+	manifest?: boolean | ManifestOptions;
 }
 export type RuntimePlugins = string[];
+export interface ManifestOptions {
+	/** The directory of the manifest files relative to the output path. */
+	filePath?: string;
+	/** The filename of the manifest, defaults to `mf-manifest.json`. */
+	fileName?: string;
+}
 
 export class ModuleFederationPlugin {
 	constructor(private _options: ModuleFederationPluginOptions) {}
@@ -32,6 +43,15 @@ export class ModuleFederationPlugin {
 			).apply(compiler);
 		});
 		new ModuleFederationRuntimePlugin().apply(compiler);
+		if (this._options.manifest) {
+			const manifestOptions =
+				this._options.manifest === true ? {} : this._options.manifest;
+			new ModuleFederationManifestPlugin({
+				name: this._options.name,
+				filePath: manifestOptions.filePath,
+				fileName: manifestOptions.fileName
+			}).apply(compiler);
+		}
 		new webpack.container.ModuleFederationPluginV1({
 			...this._options,
 			enhanced: true
@@ -36,6 +36,7 @@ export type {
 	StatsAsset,
 	StatsChunk,
 	StatsCompilation,
+	StatsDiff,
 	StatsError,
 	StatsModule
 } from "./Stats";
@@ -142,21 +143,31 @@ interface Library {
 }
 export const library: Library = { EnableLibraryPlugin };
 
-import { EnableWasmLoadingPlugin } from "./builtin-plugin";
+import {
+	CustomWasmLoadingPlugin,
+	EnableWasmLoadingPlugin
+} from "./builtin-plugin";
 interface Wasm {
 	EnableWasmLoadingPlugin: typeof EnableWasmLoadingPlugin;
+	CustomWasmLoadingPlugin: typeof CustomWasmLoadingPlugin;
 }
-export const wasm: Wasm = { EnableWasmLoadingPlugin };
+export const wasm: Wasm = {
+	EnableWasmLoadingPlugin,
+	CustomWasmLoadingPlugin
+};
 
 import {
+	CustomChunkLoadingPlugin,
 	EnableChunkLoadingPlugin,
 	JavascriptModulesPlugin
 } from "./builtin-plugin";
 interface JavaScript {
+	CustomChunkLoadingPlugin: typeof CustomChunkLoadingPlugin;
 	EnableChunkLoadingPlugin: typeof EnableChunkLoadingPlugin;
 	JavascriptModulesPlugin: typeof JavascriptModulesPlugin;
 }
 export const javascript: JavaScript = {
+	CustomChunkLoadingPlugin,
 	EnableChunkLoadingPlugin,
 	JavascriptModulesPlugin
 };
@@ -249,6 +260,8 @@ export type { LightningCssMinimizerRspackPluginOptions } from "./builtin-plugin"
 export type { CopyRspackPluginOptions } from "./builtin-plugin";
 export type { SourceMapDevToolPluginOptions } from "./builtin-plugin";
 export type { EvalDevToolModulePluginOptions } from "./builtin-plugin";
+export type { SubresourceIntegrityRspackPluginOptions } from "./builtin-plugin";
+export type { CompressionRspackPluginOptions } from "./builtin-plugin";
 export type {
 	CssExtractRspackLoaderOptions,
 	CssExtractRspackPluginOptions
@@ -262,6 +275,8 @@ export { EvalSourceMapDevToolPlugin } from "./builtin-plugin";
 export { EvalDevToolModulePlugin } from "./builtin-plugin";
 export { CssExtractRspackPlugin } from "./builtin-plugin";
 export { ContextReplacementPlugin } from "./builtin-plugin";
+export { SubresourceIntegrityRspackPlugin } from "./builtin-plugin";
+export { CompressionRspackPlugin } from "./builtin-plugin";
 
 ///// Rspack Postfixed Internal Loaders /////
 export type {
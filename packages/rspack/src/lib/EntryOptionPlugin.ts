@@ -11,7 +11,12 @@
 import assert from "node:assert";
 
 import type { Compiler, EntryDescriptionNormalized, EntryNormalized } from "..";
-import { DynamicEntryPlugin, EntryPlugin } from "../builtin-plugin";
+import {
+	DynamicEntryPlugin,
+	EnableChunkLoadingPlugin,
+	EnableWasmLoadingPlugin,
+	EntryPlugin
+} from "../builtin-plugin";
 import type { EntryOptions } from "../builtin-plugin";
 
 export class EntryOptionPlugin {
@@ -79,7 +84,7 @@ export class EntryOptionPlugin {
 			publicPath: desc.publicPath,
 			chunkLoading: desc.chunkLoading,
 			asyncChunks: desc.asyncChunks,
-			// wasmLoading: desc.wasmLoading,
+			wasmLoading: desc.wasmLoading,
 			library: desc.library
 		};
 		if (desc.layer !== undefined && !compiler.options.experiments.layers) {
@@ -87,14 +92,12 @@ export class EntryOptionPlugin {
 				"'entryOptions.layer' is only allowed when 'experiments.layers' is enabled"
 			);
 		}
-		// if (desc.chunkLoading) {
-		// 	const EnableChunkLoadingPlugin = require("./javascript/EnableChunkLoadingPlugin");
-		// 	EnableChunkLoadingPlugin.checkEnabled(compiler, desc.chunkLoading);
-		// }
-		// if (desc.wasmLoading) {
-		// 	const EnableWasmLoadingPlugin = require("./wasm/EnableWasmLoadingPlugin");
-		// 	EnableWasmLoadingPlugin.checkEnabled(compiler, desc.wasmLoading);
-		// }
+		if (desc.chunkLoading) {
+			EnableChunkLoadingPlugin.checkEnabled(compiler, desc.chunkLoading);
+		}
+		if (desc.wasmLoading) {
+			EnableWasmLoadingPlugin.checkEnabled(compiler, desc.wasmLoading);
+		}
 		// if (desc.library) {
 		// 	const EnableLibraryPlugin = require("./library/EnableLibraryPlugin");
 		// 	EnableLibraryPlugin.checkEnabled(compiler, desc.library.type);
@@ -65,6 +65,7 @@ import {
 	SourceMapDevToolPlugin,
 	SplitChunksPlugin,
 	WarnCaseSensitiveModulesPlugin,
+	WebAssemblyModulesPlugin,
 	WorkerPlugin
 } from "./builtin-plugin";
 import EntryOptionPlugin from "./lib/EntryOptionPlugin";
@@ -216,6 +217,9 @@ export class RspackOptionsApply {
 		if (options.experiments.asyncWebAssembly) {
 			new AsyncWebAssemblyModulesPlugin().apply(compiler);
 		}
+		if (options.experiments.syncWebAssembly) {
+			new WebAssemblyModulesPlugin().apply(compiler);
+		}
 		if (options.experiments.css) {
 			new CssModulesPlugin().apply(compiler);
 		}
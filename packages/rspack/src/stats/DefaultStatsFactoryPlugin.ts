@@ -76,6 +76,7 @@ const ITEM_NAMES: Record<string, string> = {
 	"compilation.namedChunkGroups[]": "chunkGroup",
 	"compilation.errors[]": "error",
 	"compilation.warnings[]": "warning",
+	"compilation.advices[]": "warning",
 	"chunk.modules[]": "module",
 	"chunk.rootModules[]": "module",
 	"chunk.origins[]": "chunkOrigin",
@@ -915,6 +916,14 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 					// compilationAuxiliaryFileToChunks
 				}
 			);
+			const excludedAssets = statsCompilation.excludedAssets;
+			if (excludedAssets) {
+				groupedAssets.push({
+					type: "hidden assets",
+					filteredChildren: excludedAssets.count,
+					size: excludedAssets.size
+				});
+			}
 			const limited = spaceLimited(
 				groupedAssets,
 				options.assetsSpace ?? Number.POSITIVE_INFINITY
@@ -949,9 +958,27 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			const statsCompilation = getStatsCompilation(compilation);
 			const array = statsCompilation.modules;
 			const groupedModules = factory.create(`${type}.modules`, array, context);
+			const excludedModules = statsCompilation.excludedModules;
+			if (excludedModules) {
+				groupedModules.push({
+					type: "hidden modules",
+					filteredChildren: excludedModules.count,
+					size: excludedModules.size,
+					sizes: Object.fromEntries(
+						excludedModules.sizes.map(({ sourceType, size }) => [
+							sourceType,
+							size
+						])
+					)
+				});
+			}
 			const limited = spaceLimited(groupedModules, options.modulesSpace!);
 			object.modules = limited.children;
-			object.filteredModules = limited.filteredChildren;
+			// add the modules left out by the native modulesSpace
+			const nativeFilteredModules = statsCompilation.filteredModules;
+			object.filteredModules = nativeFilteredModules
+				? (limited.filteredChildren ?? 0) + nativeFilteredModules
+				: limited.filteredChildren;
 		},
 		entrypoints: (
 			object,
@@ -1102,6 +1129,30 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 				return cachedGetWarnings!(c);
 			});
 		},
+		advices: (
+			object,
+			compilation,
+			context: KnownStatsFactoryContext,
+			_options,
+			factory
+		) => {
+			const { type, getStatsCompilation } = context;
+			const advices = getStatsCompilation(compilation).advices;
+			// advices are only shown when there are any, to keep the stats of most builds unchanged
+			if (advices.length > 0) {
+				object.advices = factory.create(`${type}.advices`, advices, context);
+			}
+		},
+		advicesCount: (object, compilation, context: KnownStatsFactoryContext) => {
+			const { getStatsCompilation } = context;
+			const advicesCount = countWithChildren(
+				compilation,
+				c => getStatsCompilation(c).advices
+			);
+			if (advicesCount > 0) {
+				object.advicesCount = advicesCount;
+			}
+		},
 		children: (object, compilation, context, _options, factory) => {
 			const { type } = context;
 
@@ -1160,6 +1211,8 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 		},
 		performance: (object, asset) => {
 			object.isOverSizeLimit = asset.info.isOverSizeLimit;
+			object.gzipSize = asset.gzipSize;
+			object.brotliSize = asset.brotliSize;
 		}
 	},
 	chunkGroup: {
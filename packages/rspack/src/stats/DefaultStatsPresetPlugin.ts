@@ -236,6 +236,8 @@ const DEFAULTS: StatsDefault = {
 	errorStack: OFF_FOR_TO_STRING,
 	warnings: NORMAL_ON,
 	warningsCount: NORMAL_ON,
+	advices: NORMAL_ON,
+	advicesCount: NORMAL_ON,
 	publicPath: OFF_FOR_TO_STRING,
 	logging: ({ all }, { forToString }) =>
 		forToString && all !== false ? "info" : false,
@@ -252,14 +254,28 @@ const DEFAULTS: StatsDefault = {
 	colors: () => false
 };
 
+type NativeFilter = boolean | string | RegExp;
+
+type NormalizedFilter = ((ident: string) => void) & {
+	// the raw filter, set when it can be applied by the native stats
+	nativeFilter?: NativeFilter;
+};
+
 const normalizeFilter: (
 	item: unknown
-) => ((ident: string) => void) | undefined = item => {
+) => NormalizedFilter | undefined = item => {
 	if (typeof item === "string") {
 		const regExp = new RegExp(
 			`[\\\\/]${item.replace(/[-[\]{}()*+?.\\^$|]/g, "\\$&")}([\\\\/]|$|!|\\?)`
 		);
-		return ident => regExp.test(ident);
+		return Object.assign((ident: string) => regExp.test(ident), {
+			nativeFilter: item
+		});
+	}
+	if (item instanceof RegExp) {
+		return Object.assign((ident: string) => item.test(ident), {
+			nativeFilter: item
+		});
 	}
 	if (
 		item &&
@@ -274,8 +290,21 @@ const normalizeFilter: (
 		return item as (ident: string) => void;
 	}
 	if (typeof item === "boolean") {
-		return () => item;
+		return Object.assign(() => item, { nativeFilter: item });
+	}
+};
+
+/**
+ * Returns the raw values of the normalized `excludeModules` or `excludeAssets` filters which can
+ * be applied by the native stats.
+ */
+export const getNativeFilters = (filters: unknown[]): NativeFilter[] => {
+	const nativeFilters: NativeFilter[] = [];
+	for (const filter of filters) {
+		const nativeFilter = (filter as NormalizedFilter).nativeFilter;
+		if (nativeFilter !== undefined) nativeFilters.push(nativeFilter);
 	}
+	return nativeFilters;
 };
 
 const NORMALIZER = {
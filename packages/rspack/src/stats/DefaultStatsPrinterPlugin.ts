@@ -291,6 +291,12 @@ const SIMPLE_PRINTERS: Record<
 		size,
 		{ asset: { isOverSizeLimit }, yellow, green, formatSize }
 	) => (isOverSizeLimit ? yellow(formatSize(size)) : formatSize(size)),
+	"asset.gzipSize": (gzipSize, { formatSize }) =>
+		typeof gzipSize === "number" ? `(${formatSize(gzipSize)} gzip)` : undefined,
+	"asset.brotliSize": (brotliSize, { formatSize }) =>
+		typeof brotliSize === "number"
+			? `(${formatSize(brotliSize)} brotli)`
+			: undefined,
 	"asset.emitted": (emitted, { green, formatFlag }) =>
 		emitted ? green(formatFlag("emitted")) : undefined,
 	"asset.comparedForEmit": (comparedForEmit, { yellow, formatFlag }) =>
@@ -679,6 +685,7 @@ const ITEM_NAMES: Record<
 	"compilation.namedChunkGroups[]": "chunkGroup",
 	"compilation.errors[]": "error",
 	"compilation.warnings[]": "error",
+	"compilation.advices[]": "error",
 	"compilation.logging[]": "loggingGroup",
 	"compilation.children[]": "compilation",
 	"asset.related[]": "asset",
@@ -749,6 +756,7 @@ const PREFERRED_ORDERS: Record<string, string[]> = {
 		"filteredModules",
 		"children",
 		"logging",
+		"advices",
 		"warnings",
 		"warningsInChildren!",
 		"filteredWarningDetailsCount",
@@ -762,6 +770,8 @@ const PREFERRED_ORDERS: Record<string, string[]> = {
 		"type",
 		"name",
 		"size",
+		"gzipSize",
+		"brotliSize",
 		"chunks",
 		"auxiliaryChunks",
 		"emitted",
@@ -944,6 +954,7 @@ const SIMPLE_ITEMS_JOINER: Record<
 			.join(" "),
 	"compilation.errors": itemsJoinMoreSpacing,
 	"compilation.warnings": itemsJoinMoreSpacing,
+	"compilation.advices": itemsJoinMoreSpacing,
 	"compilation.logging": itemsJoinMoreSpacing,
 	"compilation.children": items => indent(itemsJoinMoreSpacing(items), "  "),
 	"moduleTraceItem.dependencies": itemsJoinOneLine,
@@ -92,6 +92,8 @@ export type KnownStatsAsset = {
 	auxiliaryChunkIdHints?: (string | number)[];
 	filteredRelated?: number;
 	isOverSizeLimit?: boolean;
+	gzipSize?: number;
+	brotliSize?: number;
 };
 
 export type StatsAsset = KnownStatsAsset & Record<string, any>;
@@ -238,6 +240,8 @@ export type KnownStatsCompilation = {
 	errorsCount?: number;
 	warnings?: StatsError[];
 	warningsCount?: number;
+	advices?: StatsError[];
+	advicesCount?: number;
 	filteredModules?: number;
 	children?: StatsCompilation[];
 	logging?: Record<string, StatsLogging>;
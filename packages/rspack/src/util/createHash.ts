@@ -11,7 +11,9 @@
 import crypto from "node:crypto";
 import Hash from "./hash";
 import BatchedHash from "./hash/BatchedHash";
+import { encodeBase58 } from "./hash/base58";
 import createMd4 from "./hash/md4";
+import NativeHash from "./hash/native";
 import createXXHash64 from "./hash/xxhash64";
 
 const BULK_SIZE = 2000;
@@ -78,6 +80,9 @@ class BulkUpdateDecorator extends Hash {
 	 * @returns digest
 	 */
 	digest(encoding?: string): string | Buffer {
+		if (encoding === "base58") {
+			return encodeBase58(Buffer.from(this.digest("hex") as string, "hex"));
+		}
 		let digestCache: Map<string, string> | undefined;
 		const buffer = this.buffer;
 		if (this.hash === undefined) {
@@ -157,6 +162,7 @@ export const createHash = (
 	algorithm:
 		| "debug"
 		| "xxhash64"
+		| "xxhash3-128"
 		| "md4"
 		| "native-md4"
 		| (string & {})
@@ -171,6 +177,11 @@ export const createHash = (
 			return new DebugHash();
 		case "xxhash64":
 			return new BatchedHash(createXXHash64());
+		case "xxhash3-128":
+			return new BulkUpdateDecorator(
+				() => new NativeHash("xxhash3-128"),
+				"xxhash3-128"
+			);
 		case "md4":
 			return new BatchedHash(createMd4());
 		case "native-md4":
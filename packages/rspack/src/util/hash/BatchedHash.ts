@@ -8,6 +8,7 @@
  * https://github.com/webpack/webpack/blob/main/LICENSE
  */
 import Hash from ".";
+import { encodeBase58 } from "./base58";
 import { MAX_SHORT_STRING } from "./wasm-hash";
 
 export default class BatchedHash extends Hash {
@@ -64,6 +65,9 @@ export default class BatchedHash extends Hash {
 	 * @returns digest
 	 */
 	digest(encoding?: string): string | Buffer {
+		if (encoding === "base58") {
+			return encodeBase58(Buffer.from(this.digest("hex") as string, "hex"));
+		}
 		if (this.string !== undefined) {
 			this.hash.update(this.string, this.encoding);
 		}
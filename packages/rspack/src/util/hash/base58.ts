@@ -0,0 +1,32 @@
+const BASE58_ALPHABET =
+	"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/**
+ * Encode bytes with the bitcoin base58 alphabet, leading zero bytes are encoded as `1`,
+ * the same as the `base58` digest of `output.hashDigest` in Rust.
+ * @param input bytes to encode
+ * @returns encoded string
+ */
+export const encodeBase58 = (input: Buffer): string => {
+	let zeros = 0;
+	while (zeros < input.length && input[zeros] === 0) zeros++;
+	// little endian digits in base 58
+	const digits: number[] = [];
+	for (let i = zeros; i < input.length; i++) {
+		let carry = input[i];
+		for (let j = 0; j < digits.length; j++) {
+			carry += digits[j] << 8;
+			digits[j] = carry % 58;
+			carry = (carry / 58) | 0;
+		}
+		while (carry > 0) {
+			digits.push(carry % 58);
+			carry = (carry / 58) | 0;
+		}
+	}
+	let result = BASE58_ALPHABET[0].repeat(zeros);
+	for (let i = digits.length - 1; i >= 0; i--) {
+		result += BASE58_ALPHABET[digits[i]];
+	}
+	return result;
+};
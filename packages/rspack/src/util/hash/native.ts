@@ -0,0 +1,44 @@
+import * as binding from "@rspack/binding";
+import Hash from ".";
+
+/**
+ * Hash of the native binding, for the hash functions Node.js doesn't support, e.g. `xxhash3-128`
+ */
+export default class NativeHash extends Hash {
+	hash: binding.JsHash;
+
+	/**
+	 * @param hashFunction the hash function of `output.hashFunction`
+	 */
+	constructor(hashFunction: string) {
+		super();
+		this.hash = new binding.JsHash(hashFunction);
+	}
+
+	/**
+	 * Update hash {@link https://nodejs.org/api/crypto.html#crypto_hash_update_data_inputencoding}
+	 * @param data data
+	 * @param inputEncoding data encoding
+	 * @returns updated hash
+	 */
+	update(data: string | Buffer, inputEncoding?: BufferEncoding): this {
+		this.hash.update(
+			typeof data === "string" && inputEncoding
+				? Buffer.from(data, inputEncoding)
+				: data
+		);
+		return this;
+	}
+
+	/**
+	 * Calculates the digest {@link https://nodejs.org/api/crypto.html#crypto_hash_digest_encoding}
+	 * @param encoding encoding of the return value
+	 * @returns digest
+	 */
+	digest(encoding?: BufferEncoding): string | Buffer {
+		const hex = this.hash.digest();
+		if (encoding === "hex") return hex;
+		if (encoding === "binary" || !encoding) return Buffer.from(hex, "hex");
+		return Buffer.from(hex, "hex").toString(encoding);
+	}
+}